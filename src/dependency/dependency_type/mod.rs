@@ -1,12 +1,15 @@
 //! This module contains the `DependencyType` enum and related types.
 
+use std::num::NonZeroU32;
+use std::time::Duration;
 use thiserror::Error;
 
 mod display;
+mod parse;
 mod validate;
 
 /// Sbatch dependency type
-/// 
+///
 /// The `DependencyType` enum is used to represent the different types of dependencies that can be used in a Slurm job script.
 /// For more details on the different types of dependencies, see the Slurm documentation: <https://slurm.schedmd.com/sbatch.html>.
 ///
@@ -19,6 +22,7 @@ mod validate;
 /// - `AfterOk(String)`: This job can begin execution after the specified jobs have successfully executed (ran to completion with an exit code of zero).
 /// - `Singleton`: This job can begin execution after any previously launched jobs sharing the same job name and user have terminated. In other words, only one job by that name and owned by that user can be running or suspended at any point in time.
 #[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DependencyType {
     /// Maps to the `after:<job_id>` dependency type
     After(String),
@@ -38,6 +42,192 @@ pub enum DependencyType {
     Singleton,
 }
 
+impl DependencyType {
+    /// Returns the job id the dependency refers to, or `None` for `Singleton`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::DependencyType;
+    ///
+    /// let dependency_type = DependencyType::AfterOk("123".to_string());
+    /// assert_eq!(dependency_type.job_id(), Some("123"));
+    /// assert_eq!(DependencyType::Singleton.job_id(), None);
+    /// ```
+    pub fn job_id(&self) -> Option<&str> {
+        match self {
+            DependencyType::After(job_id)
+            | DependencyType::AfterTimeDelay(job_id, _)
+            | DependencyType::AfterAny(job_id)
+            | DependencyType::AfterBurstBuffer(job_id)
+            | DependencyType::AfterCorr(job_id)
+            | DependencyType::AfterNotOk(job_id)
+            | DependencyType::AfterOk(job_id) => Some(job_id),
+            DependencyType::Singleton => None,
+        }
+    }
+
+    /// Returns the time delay of an `AfterTimeDelay` dependency, or `None` for the other variants.
+    pub fn time_delay(&self) -> Option<&str> {
+        match self {
+            DependencyType::AfterTimeDelay(_, time_delay) => Some(time_delay),
+            _ => None,
+        }
+    }
+
+    /// Returns the job id as a number, or `None` if it is not a plain positive integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::DependencyType;
+    ///
+    /// let dependency_type = DependencyType::AfterOk("123".to_string());
+    /// assert_eq!(dependency_type.job_id_number().map(|id| id.get()), Some(123));
+    ///
+    /// let dependency_type = DependencyType::AfterOk("$JOB_ID".to_string());
+    /// assert_eq!(dependency_type.job_id_number(), None);
+    /// ```
+    pub fn job_id_number(&self) -> Option<NonZeroU32> {
+        self.job_id()
+            .filter(|job_id| job_id.chars().all(|c| c.is_ascii_digit()))?
+            .parse()
+            .ok()
+    }
+
+    /// Returns the name of the shell variable the job id refers to, such as `JOB_ID` for `$JOB_ID`,
+    /// `${JOB_ID}`, or `${JOB_ID:-1}`, or `None` if the job id is not a single variable reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::DependencyType;
+    ///
+    /// let dependency_type = DependencyType::AfterOk("${STEP:-1}".to_string());
+    /// assert_eq!(dependency_type.job_id_variable(), Some("STEP"));
+    ///
+    /// let dependency_type = DependencyType::AfterOk("123".to_string());
+    /// assert_eq!(dependency_type.job_id_variable(), None);
+    /// ```
+    pub fn job_id_variable(&self) -> Option<&str> {
+        let job_id = self.job_id()?.strip_prefix('$')?;
+        let name = match job_id.strip_prefix('{') {
+            Some(braced) => {
+                // The brace opened after `$` must be the one closed at the end
+                let mut depth = 1usize;
+                let close = braced.char_indices().find_map(|(index, c)| {
+                    match c {
+                        '{' => depth += 1,
+                        '}' => depth -= 1,
+                        _ => {}
+                    }
+                    (depth == 0).then_some(index)
+                })?;
+                if close != braced.len() - 1 {
+                    return None;
+                }
+                let inner = &braced[..close];
+                let end = inner
+                    .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                    .unwrap_or(inner.len());
+                if end < inner.len() && !inner[end..].starts_with(':') {
+                    return None;
+                }
+                &inner[..end]
+            }
+            None => job_id,
+        };
+        let is_name = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        is_name.then_some(name)
+    }
+
+    /// Returns the time delay as a number of minutes, or `None` if there is no time delay or it is not a
+    /// plain positive integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::DependencyType;
+    ///
+    /// let dependency_type = DependencyType::AfterTimeDelay("123".to_string(), "10".to_string());
+    /// assert_eq!(dependency_type.time_delay_minutes().map(|minutes| minutes.get()), Some(10));
+    ///
+    /// let dependency_type = DependencyType::AfterTimeDelay("123".to_string(), "$DELAY".to_string());
+    /// assert_eq!(dependency_type.time_delay_minutes(), None);
+    /// ```
+    pub fn time_delay_minutes(&self) -> Option<NonZeroU32> {
+        self.time_delay()
+            .filter(|time_delay| time_delay.chars().all(|c| c.is_ascii_digit()))?
+            .parse()
+            .ok()
+    }
+
+    /// Returns the time delay as a `Duration`, or `None` if it is not a plain number of minutes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use sbatch_rs::DependencyType;
+    ///
+    /// let dependency_type = DependencyType::AfterTimeDelay("123".to_string(), "10".to_string());
+    /// assert_eq!(dependency_type.time_delay_duration(), Some(Duration::from_secs(600)));
+    /// ```
+    pub fn time_delay_duration(&self) -> Option<Duration> {
+        self.time_delay_minutes()
+            .map(|minutes| Duration::from_secs(u64::from(minutes.get()) * 60))
+    }
+
+    /// Returns the dependency with its time delay extended by a number of minutes.
+    ///
+    /// An `After` dependency has no time delay, so it becomes an `AfterTimeDelay` dependency.
+    ///
+    /// # Arguments
+    ///
+    /// * `minutes` - The number of minutes to add.
+    ///
+    /// # Returns
+    ///
+    /// This function returns the shifted dependency, or `None` if the dependency is neither `After` nor
+    /// `AfterTimeDelay`, its time delay is not a plain number of minutes, or the sum overflows a `u32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::DependencyType;
+    ///
+    /// let dependency_type = DependencyType::AfterTimeDelay("123".to_string(), "10".to_string());
+    /// assert_eq!(
+    ///     dependency_type.checked_add_time_delay(5),
+    ///     Some(DependencyType::AfterTimeDelay("123".to_string(), "15".to_string()))
+    /// );
+    ///
+    /// let dependency_type = DependencyType::After("123".to_string());
+    /// assert_eq!(
+    ///     dependency_type.checked_add_time_delay(5),
+    ///     Some(DependencyType::AfterTimeDelay("123".to_string(), "5".to_string()))
+    /// );
+    ///
+    /// assert_eq!(dependency_type.checked_add_time_delay(u32::MAX).unwrap().checked_add_time_delay(1), None);
+    /// ```
+    pub fn checked_add_time_delay(&self, minutes: u32) -> Option<DependencyType> {
+        let (job_id, time_delay) = match self {
+            DependencyType::After(job_id) if minutes == 0 => {
+                return Some(DependencyType::After(job_id.clone()));
+            }
+            DependencyType::After(job_id) => (job_id, 0),
+            DependencyType::AfterTimeDelay(job_id, _) => (job_id, self.time_delay_minutes()?.get()),
+            _ => return None,
+        };
+        let time_delay = time_delay.checked_add(minutes)?;
+        Some(DependencyType::AfterTimeDelay(
+            job_id.clone(),
+            time_delay.to_string(),
+        ))
+    }
+}
+
 /// Represents an error that can occur when validating a `DependencyType` value.
 /// This error is used to indicate that a `DependencyType` value is invalid.
 #[derive(Debug, Error)]
@@ -48,4 +238,7 @@ pub enum DependencyTypeError {
     /// Indicates that the `DependencyType` value is invalid because it contains leading or trailing spaces.
     #[error("Dependency type contains leading or trailing spaces")]
     LeadingOrTrailingSpaces,
+    /// Indicates that a string could not be parsed as a `DependencyType`.
+    #[error("Invalid dependency type: {0}")]
+    InvalidDependencyType(String),
 }
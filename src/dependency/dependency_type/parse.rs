@@ -0,0 +1,68 @@
+//! Parsing functions for the `DependencyType` enum.
+
+use std::str::FromStr;
+
+use super::{DependencyType, DependencyTypeError};
+use crate::dependency::split_outside_braces;
+
+impl FromStr for DependencyType {
+    type Err = DependencyTypeError;
+
+    /// Parses a single dependency, such as `afterok:123` or `after:123+10`.
+    ///
+    /// A bare job id, such as `123` or `$JOB_ID`, is treated as `after:<job_id>`.
+    /// A job id may be a shell variable reference with a default value, such as `${STEP:-1}`;
+    /// the separators inside the braces are kept as part of the job id.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `DependencyTypeError` if the dependency type is unknown,
+    /// more than one job id is given, or the job id is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::DependencyType;
+    ///
+    /// let dependency_type: DependencyType = "afterok:123".parse().unwrap();
+    /// assert_eq!(dependency_type, DependencyType::AfterOk("123".to_string()));
+    ///
+    /// // A bare job id means `after`
+    /// let dependency_type: DependencyType = "123".parse().unwrap();
+    /// assert_eq!(dependency_type, DependencyType::After("123".to_string()));
+    ///
+    /// let dependency_type: DependencyType = "afterok:${STEP:-1}".parse().unwrap();
+    /// assert_eq!(dependency_type, DependencyType::AfterOk("${STEP:-1}".to_string()));
+    ///
+    /// assert!("afterfoo:123".parse::<DependencyType>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || DependencyTypeError::InvalidDependencyType(s.to_string());
+        if s == "singleton" {
+            return Ok(DependencyType::Singleton);
+        }
+
+        let (kind, job_id) = match split_outside_braces(s, &[':']).ok_or_else(invalid)?[..] {
+            [job_id] => ("after", job_id),
+            [kind, job_id] => (kind, job_id),
+            _ => return Err(invalid()),
+        };
+        let dependency_type = match kind {
+            "after" => match split_outside_braces(job_id, &['+']).ok_or_else(invalid)?[..] {
+                [job_id] => DependencyType::After(job_id.to_string()),
+                [job_id, time_delay] => {
+                    DependencyType::AfterTimeDelay(job_id.to_string(), time_delay.to_string())
+                }
+                _ => return Err(invalid()),
+            },
+            "afterany" => DependencyType::AfterAny(job_id.to_string()),
+            "afterburstbuffer" => DependencyType::AfterBurstBuffer(job_id.to_string()),
+            "aftercorr" => DependencyType::AfterCorr(job_id.to_string()),
+            "afternotok" => DependencyType::AfterNotOk(job_id.to_string()),
+            "afterok" => DependencyType::AfterOk(job_id.to_string()),
+            _ => return Err(invalid()),
+        };
+        dependency_type.validate()?;
+        Ok(dependency_type)
+    }
+}
@@ -4,10 +4,11 @@ use std::collections::BTreeSet;
 use thiserror::Error;
 
 mod dependency_type;
+mod parse;
 pub use dependency_type::{DependencyType, DependencyTypeError};
 
 /// Sbatch dependency representation
-/// 
+///
 /// Represents the different types of dependencies that can be used in a Slurm job script.
 /// See <https://slurm.schedmd.com/sbatch.html> for more information.
 ///
@@ -29,6 +30,7 @@ pub use dependency_type::{DependencyType, DependencyTypeError};
 /// assert_eq!(dependency, "after:123,after:456+10");
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Dependency {
     And(Vec<DependencyType>),
     Or(Vec<DependencyType>),
@@ -39,12 +41,42 @@ pub enum Dependency {
 ///
 /// - `NoDependencies`: Indicates that no dependencies were provided.
 /// - `DependencyTypeError`: Indicates that a `DependencyType` value is invalid.
+/// - `InvalidDependency`: Indicates that a dependency string could not be parsed.
+/// - `MixedSeparators`: Indicates that a dependency string uses both `,` and `?`.
 #[derive(Debug, Error)]
 pub enum DependencyError {
     #[error("No dependencies provided")]
     NoDependencies,
     #[error("Dependency type error: {0}")]
     DependencyTypeError(#[from] dependency_type::DependencyTypeError),
+    #[error("Invalid dependency: {0}")]
+    InvalidDependency(String),
+    #[error("Dependency mixes ',' and '?' separators: {0}")]
+    MixedSeparators(String),
+}
+
+// Helper function to split a string on the separators that are outside `${...}` shell variable references,
+// so that a default value such as `${STEP:-1}` stays in one piece.
+// This function returns `None` if a `${` is not closed by a matching `}`.
+fn split_outside_braces<'a>(s: &'a str, separators: &[char]) -> Option<Vec<&'a str>> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    let mut chars = s.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '$' if chars.next_if(|(_, c)| *c == '{').is_some() => depth += 1,
+            '{' if depth > 0 => depth += 1,
+            '}' if depth > 0 => depth -= 1,
+            c if depth == 0 && separators.contains(&c) => {
+                parts.push(&s[start..index]);
+                start = index + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(&s[start..]);
+    (depth == 0).then_some(parts)
 }
 
 // Helper functions for the `Dependency` enum
@@ -64,6 +96,14 @@ impl Dependency {
             Dependency::Or(dependencies) => dependencies,
         }
     }
+
+    // Helper function to get the mutable dependencies vector.
+    fn dependencies_mut(&mut self) -> &mut Vec<DependencyType> {
+        match self {
+            Dependency::And(dependencies) => dependencies,
+            Dependency::Or(dependencies) => dependencies,
+        }
+    }
 }
 
 // Interface functions for the `Dependency` enum
@@ -104,6 +144,74 @@ impl Dependency {
         Dependency::Or(Vec::new())
     }
 
+    /// Create an `And` dependency from a list of dependencies.
+    ///
+    /// # Arguments
+    ///
+    /// * `dependencies` - The `DependencyType` values that must all be met.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a new `Dependency` enum with an `And` variant holding the dependencies.
+    /// An empty list is accepted here, but `build` returns `DependencyError::NoDependencies` for it.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `DependencyError` if any of the dependencies is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Dependency, DependencyType};
+    ///
+    /// let dependency = Dependency::and(vec![
+    ///     DependencyType::AfterOk("1".to_string()),
+    ///     DependencyType::AfterOk("2".to_string()),
+    /// ]).unwrap();
+    /// assert_eq!(dependency.build().unwrap(), "afterok:1,afterok:2");
+    ///
+    /// assert!(Dependency::and(vec![DependencyType::After("".to_string())]).is_err());
+    /// ```
+    pub fn and(dependencies: Vec<DependencyType>) -> Result<Self, DependencyError> {
+        for dependency in &dependencies {
+            dependency.validate()?;
+        }
+        Ok(Dependency::And(dependencies))
+    }
+
+    /// Create an `Or` dependency from a list of dependencies.
+    ///
+    /// # Arguments
+    ///
+    /// * `dependencies` - The `DependencyType` values of which any must be met.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a new `Dependency` enum with an `Or` variant holding the dependencies.
+    /// An empty list is accepted here, but `build` returns `DependencyError::NoDependencies` for it.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `DependencyError` if any of the dependencies is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Dependency, DependencyType};
+    ///
+    /// let dependency = Dependency::or(vec![
+    ///     DependencyType::AfterOk("1".to_string()),
+    ///     DependencyType::Singleton,
+    /// ]).unwrap();
+    /// assert_eq!(dependency.build().unwrap(), "afterok:1?singleton");
+    /// ```
+    pub fn or(dependencies: Vec<DependencyType>) -> Result<Self, DependencyError> {
+        for dependency in &dependencies {
+            dependency.validate()?;
+        }
+        Ok(Dependency::Or(dependencies))
+    }
+
     /// Add a dependency to the `Dependency` enum.
     ///
     /// # Arguments
@@ -145,6 +253,41 @@ impl Dependency {
         Ok(self)
     }
 
+    /// Parse a single dependency, such as `afterok:123` or `after:123+10`, and add it to the `Dependency` enum.
+    ///
+    /// The dependency is parsed like `DependencyType::from_str`, so a bare job id means `after`.
+    /// Only one job id may be given: the colon-list shorthand (`afterok:1:2`) is rejected, so that each call
+    /// adds exactly one dependency. Push each job id separately instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `dependency` - The dependency to parse and add.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a mutable reference to the `Dependency` enum.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `DependencyError` if the dependency cannot be parsed or is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::Dependency;
+    ///
+    /// let dependency = Dependency::new_and()
+    ///     .push_str("afterok:123").unwrap()
+    ///     .push_str("after:456+10").unwrap()
+    ///     .build().unwrap();
+    /// assert_eq!(dependency, "after:456+10,afterok:123");
+    ///
+    /// assert!(Dependency::new_and().push_str("afterok:1:2").is_err());
+    /// ```
+    pub fn push_str(&mut self, dependency: &str) -> Result<&mut Self, DependencyError> {
+        self.push(dependency.parse()?)
+    }
+
     /// Add an `After` dependency to the `Dependency` enum.
     ///
     /// # Arguments
@@ -382,8 +525,104 @@ impl Dependency {
         self.push(DependencyType::Singleton)
     }
 
+    /// Remove exact duplicate dependencies from the `Dependency` enum.
+    ///
+    /// The first occurrence of each `DependencyType` is kept and the relative order
+    /// of the remaining dependencies is preserved.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a mutable reference to the `Dependency` enum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Dependency, DependencyType};
+    ///
+    /// let mut dependency = Dependency::new_and();
+    /// dependency.push_after("1").unwrap();
+    /// dependency.push_after("1").unwrap();
+    /// dependency.push_after_ok("2").unwrap();
+    ///
+    /// // Remove the duplicate `after:1`
+    /// dependency.dedup();
+    /// assert_eq!(
+    ///     dependency,
+    ///     Dependency::And(vec![
+    ///         DependencyType::After("1".to_string()),
+    ///         DependencyType::AfterOk("2".to_string()),
+    ///     ])
+    /// );
+    /// ```
+    pub fn dedup(&mut self) -> &mut Self {
+        let mut seen = BTreeSet::new();
+        self.dependencies_mut()
+            .retain(|dependency| seen.insert(dependency.clone()));
+        self
+    }
+
+    /// Remove every occurrence of a dependency.
+    ///
+    /// # Arguments
+    ///
+    /// * `dependency` - The `DependencyType` to remove.
+    ///
+    /// # Returns
+    ///
+    /// This function returns `true` if the dependency was present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Dependency, DependencyType};
+    ///
+    /// let mut dependency = Dependency::new_and();
+    /// dependency.push_after_ok("1").unwrap().push_after_ok("2").unwrap();
+    ///
+    /// // Drop the dependency on a cancelled job
+    /// assert!(dependency.remove(&DependencyType::AfterOk("1".to_string())));
+    /// assert_eq!(dependency.build().unwrap(), "afterok:2");
+    /// ```
+    pub fn remove(&mut self, dependency: &DependencyType) -> bool {
+        let dependencies = self.dependencies_mut();
+        let len = dependencies.len();
+        dependencies.retain(|d| d != dependency);
+        dependencies.len() != len
+    }
+
+    /// Returns an iterator over the dependencies, in the order they were added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Dependency, DependencyType};
+    ///
+    /// let mut dependency = Dependency::new_or();
+    /// dependency.push_after("1").unwrap().push_singleton().unwrap();
+    ///
+    /// let dependencies: Vec<_> = dependency.iter().collect();
+    /// assert_eq!(dependencies, vec![&DependencyType::After("1".to_string()), &DependencyType::Singleton]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &DependencyType> {
+        self.dependencies().iter()
+    }
+
+    /// Returns the number of dependencies, including duplicates.
+    pub fn len(&self) -> usize {
+        self.dependencies().len()
+    }
+
+    /// Returns `true` if there are no dependencies.
+    pub fn is_empty(&self) -> bool {
+        self.dependencies().is_empty()
+    }
+
     /// Build the dependency string.
     ///
+    /// Exact duplicate dependencies are collapsed into a single entry, so pushing
+    /// `after:1` twice produces `after:1` rather than `after:1,after:1`.
+    /// The stored dependencies are left untouched; use `dedup` to remove the duplicates in place.
+    ///
     /// # Returns
     ///
     /// This function returns a `String` containing the dependency string.
@@ -413,15 +652,7 @@ impl Dependency {
     /// assert_eq!(dependency_str, "after:123,after:456+10");
     /// ```
     pub fn build(&self) -> Result<String, DependencyError> {
-        // Check if there are any dependencies
-        if self.dependencies().is_empty() {
-            return Err(DependencyError::NoDependencies);
-        }
-
-        // Validate the dependencies
-        for dependency in self.dependencies() {
-            dependency.validate()?;
-        }
+        self.validate()?;
 
         // Convert the dependencies to a single string
         Ok(self
@@ -433,4 +664,93 @@ impl Dependency {
             .collect::<Vec<_>>()
             .join(self.separator()))
     }
+
+    /// Build the dependency string, keeping the order in which dependencies were added.
+    ///
+    /// Unlike `build`, which sorts the dependencies for deterministic output, this reflects the
+    /// structure of the pipeline as it was assembled. Exact duplicates are still collapsed, keeping
+    /// the first occurrence.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a `String` containing the dependency string.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `DependencyError` if the dependency is invalid, as with `build`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::Dependency;
+    ///
+    /// let mut dependency = Dependency::new_and();
+    /// dependency.push_after_ok("456").unwrap();
+    /// dependency.push_after("123").unwrap();
+    ///
+    /// assert_eq!(dependency.build().unwrap(), "after:123,afterok:456");
+    /// assert_eq!(dependency.to_ordered_string().unwrap(), "afterok:456,after:123");
+    /// ```
+    pub fn to_ordered_string(&self) -> Result<String, DependencyError> {
+        self.validate()?;
+
+        // Convert the dependencies to a single string, skipping repeats
+        let mut seen = BTreeSet::new();
+        Ok(self
+            .dependencies()
+            .iter()
+            .map(|d| d.to_string())
+            .filter(|d| seen.insert(d.clone()))
+            .collect::<Vec<_>>()
+            .join(self.separator()))
+    }
+
+    /// Returns a normalized key for the dependency set, suitable for caching or memoizing job graph edges.
+    ///
+    /// The key does not depend on the order in which dependencies were added, repeated entries, or the
+    /// colon shorthand the dependency was parsed from. A set with a single entry has the same key whether it
+    /// is `And` or `Or`, since both mean the same thing. Unlike `build`, this does not validate the
+    /// dependencies and is not meant for command lines.
+    ///
+    /// # Returns
+    ///
+    /// This function returns the sorted, deduplicated entries joined by the dependency's separator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::Dependency;
+    ///
+    /// let a: Dependency = "afterok:2:1,after:3".parse().unwrap();
+    /// let b: Dependency = "after:3,afterok:1,afterok:2,after:3".parse().unwrap();
+    /// assert_eq!(a.canonical_key(), b.canonical_key());
+    /// assert_eq!(a.canonical_key(), "after:3,afterok:1,afterok:2");
+    /// ```
+    pub fn canonical_key(&self) -> String {
+        let dependencies = self
+            .dependencies()
+            .iter()
+            .map(|d| d.to_string())
+            .collect::<BTreeSet<_>>();
+        let separator = if dependencies.len() > 1 {
+            self.separator()
+        } else {
+            ","
+        };
+        dependencies.into_iter().collect::<Vec<_>>().join(separator)
+    }
+
+    // Helper function to check that there are dependencies and that each one is valid.
+    fn validate(&self) -> Result<(), DependencyError> {
+        // Check if there are any dependencies
+        if self.dependencies().is_empty() {
+            return Err(DependencyError::NoDependencies);
+        }
+
+        // Validate the dependencies
+        for dependency in self.dependencies() {
+            dependency.validate()?;
+        }
+        Ok(())
+    }
 }
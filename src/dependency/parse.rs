@@ -0,0 +1,96 @@
+//! Parsing functions for the `Dependency` enum.
+
+use std::str::FromStr;
+
+use super::{Dependency, DependencyError, DependencyType, split_outside_braces};
+
+// Helper function to parse a single `type:job_id[:job_id...]` entry, expanding the colon shorthand.
+fn parse_entry(entry: &str) -> Result<Vec<DependencyType>, DependencyError> {
+    let invalid = || DependencyError::InvalidDependency(entry.to_string());
+    if entry == "singleton" {
+        return Ok(vec![DependencyType::Singleton]);
+    }
+
+    let (kind, job_ids) = entry.split_once(':').ok_or_else(invalid)?;
+    split_outside_braces(job_ids, &[':'])
+        .ok_or_else(invalid)?
+        .into_iter()
+        .map(|job_id| format!("{kind}:{job_id}").parse().map_err(|_| invalid()))
+        .collect()
+}
+
+// Helper function to strip one pair of matching surrounding quotes, as in `--dependency="afterok:1?afterok:2"`.
+fn strip_quotes(s: &str) -> &str {
+    ['"', '\'']
+        .into_iter()
+        .find_map(|quote| s.strip_prefix(quote)?.strip_suffix(quote))
+        .unwrap_or(s)
+}
+
+impl FromStr for Dependency {
+    type Err = DependencyError;
+
+    /// Parses a Slurm dependency string, such as the output of `Dependency::build`.
+    ///
+    /// Entries separated by `,` produce an `And` dependency and entries separated by `?` produce an `Or`
+    /// dependency. A single entry produces an `And` dependency. The colon shorthand for several job ids,
+    /// e.g. `afterok:1:2`, is expanded into one entry per job id. Separators inside a `${...}` shell variable
+    /// reference, such as `${STEP:-1}`, are kept as part of the job id. The string may be wrapped in one pair
+    /// of single or double quotes.
+    ///
+    /// The separator is detected from the top-level separators only, so `MixedSeparators` is returned
+    /// only when `,` and `?` both join entries.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `DependencyError` if the string is empty, mixes `,` and `?`,
+    /// or contains an entry that is not a valid dependency.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Dependency, DependencyType};
+    ///
+    /// let dependency: Dependency = "afterok:1:2,after:3+10".parse().unwrap();
+    /// assert_eq!(
+    ///     dependency,
+    ///     Dependency::And(vec![
+    ///         DependencyType::AfterOk("1".to_string()),
+    ///         DependencyType::AfterOk("2".to_string()),
+    ///         DependencyType::AfterTimeDelay("3".to_string(), "10".to_string()),
+    ///     ])
+    /// );
+    ///
+    /// let dependency: Dependency = "afterany:1?singleton".parse().unwrap();
+    /// assert_eq!(dependency.build().unwrap(), "afterany:1?singleton");
+    ///
+    /// assert!("afterok:1,afterok:2?afterok:3".parse::<Dependency>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = strip_quotes(s.trim()).trim();
+        if s.is_empty() {
+            return Err(DependencyError::NoDependencies);
+        }
+
+        // Detect the separator
+        let invalid = || DependencyError::InvalidDependency(s.to_string());
+        let has = |separator| {
+            split_outside_braces(s, &[separator])
+                .map(|parts| parts.len() > 1)
+                .ok_or_else(invalid)
+        };
+        let mut dependency = match (has(',')?, has('?')?) {
+            (true, true) => return Err(DependencyError::MixedSeparators(s.to_string())),
+            (_, true) => Dependency::new_or(),
+            (_, false) => Dependency::new_and(),
+        };
+
+        // Parse each entry
+        for entry in split_outside_braces(s, &[',', '?']).ok_or_else(invalid)? {
+            for dependency_type in parse_entry(entry)? {
+                dependency.push(dependency_type)?;
+            }
+        }
+        Ok(dependency)
+    }
+}
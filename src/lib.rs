@@ -1,8 +1,29 @@
 mod dependency;
 mod sbatch;
 mod sbatch_option;
+mod scancel;
+mod srun;
+mod threading_config;
+
+pub mod prelude;
 
 pub use dependency::{Dependency, DependencyError};
 pub use dependency::{DependencyType, DependencyTypeError};
-pub use sbatch::{Sbatch, SbatchError};
-pub use sbatch_option::{SbatchOption, SbatchOptionError};
+#[cfg(feature = "clap")]
+pub use sbatch::SbatchArgs;
+pub use sbatch::{
+    CommandParseError, MergeStrategy, OrderMode, Sbatch, SbatchError, check_unique_job_names,
+    tokenize_command_line,
+};
+#[cfg(feature = "process")]
+pub use sbatch::{CommandSubmitter, MockSubmitter};
+pub use sbatch_option::{
+    ArraySpec, BeginTime, BeginUnit, Constraint, ConstraintExpr, CpuDistribution, CpuFreq,
+    CpuFrequency, CpuGovernor, DisplayStyle, Distribution, Export, ExportMode, FilenameContext,
+    GpuSpec, Gres, GresEntry, LocatedError, Memory, MemoryUnit, NodeDistribution, NodeList,
+    Normalization, OpenMode, OptionKind, OptionSpec, ParseOptions, SbatchOption, SbatchOptionError,
+    SignalScope, SignalSpec, TimeLimit, UnknownPolicy, Warning,
+};
+pub use scancel::{Scancel, ScancelError};
+pub use srun::{Srun, SrunError};
+pub use threading_config::{Hint, ThreadingConfig, ThreadingConfigError};
@@ -0,0 +1,35 @@
+//! Re-exports of the types needed to build `sbatch`, `srun`, and `scancel` commands.
+//!
+//! # Examples
+//!
+//! ```
+//! use sbatch_rs::prelude::*;
+//!
+//! let mut dependency = Dependency::new_and();
+//! dependency.push(DependencyType::AfterOk("123".to_string())).unwrap();
+//!
+//! let command = Sbatch::new()
+//!     .add_option(SbatchOption::JobName("test".to_string())).unwrap()
+//!     .add_option(SbatchOption::Mem("4G".parse::<Memory>().unwrap())).unwrap()
+//!     .add_option(SbatchOption::Time("1:00:00".parse::<TimeLimit>().unwrap())).unwrap()
+//!     .set_dependency(dependency).unwrap()
+//!     .set_script("test.sh".to_string()).unwrap()
+//!     .build();
+//!
+//! assert_eq!(
+//!     command.unwrap(),
+//!     "sbatch --dependency=afterok:123 --job-name=test --mem=4G --time=0-01:00:00 test.sh"
+//! );
+//! ```
+
+#[cfg(feature = "clap")]
+pub use crate::SbatchArgs;
+pub use crate::{
+    ArraySpec, BeginTime, BeginUnit, Constraint, ConstraintExpr, CpuDistribution, CpuFreq,
+    CpuFrequency, CpuGovernor, Dependency, DependencyError, DependencyType, DependencyTypeError,
+    DisplayStyle, Distribution, Export, ExportMode, FilenameContext, GpuSpec, Gres, GresEntry,
+    Hint, Memory, MemoryUnit, MergeStrategy, NodeDistribution, NodeList, OpenMode, OptionKind,
+    OptionSpec, OrderMode, ParseOptions, Sbatch, SbatchError, SbatchOption, SbatchOptionError,
+    Scancel, ScancelError, SignalScope, SignalSpec, Srun, SrunError, ThreadingConfig,
+    ThreadingConfigError, TimeLimit, UnknownPolicy, Warning,
+};
@@ -0,0 +1,114 @@
+//! Command-line arguments for `Sbatch`, derived with `clap`
+
+use std::num::NonZeroU32;
+
+use super::{Sbatch, SbatchError};
+use crate::{Dependency, SbatchOption};
+
+/// Command-line arguments for the most common sbatch options
+///
+/// Binaries that forward to `sbatch` can parse these flags directly, or embed them in their own
+/// arguments with `#[command(flatten)]`, and convert them into a `Sbatch` with `TryFrom`.
+/// The conversion parses each value with the same typed parsers as `SbatchOption`, so an invalid
+/// `--mem`, `--time`, or `--dependency` is reported as a `SbatchError`.
+///
+/// # Examples
+///
+/// ```
+/// use clap::Parser;
+/// use sbatch_rs::{Sbatch, SbatchArgs};
+///
+/// let args = SbatchArgs::parse_from([
+///     "submit", "-p", "gpu", "--mem", "4G", "--time", "1:00:00", "-J", "test", "test.sh", "arg1",
+/// ]);
+/// let sbatch = Sbatch::try_from(args).unwrap();
+/// assert_eq!(
+///     sbatch.build().unwrap(),
+///     "sbatch --job-name=test --mem=4G --partition=gpu --time=0-01:00:00 test.sh arg1"
+/// );
+///
+/// let args = SbatchArgs::parse_from(["submit", "--mem", "lots", "test.sh"]);
+/// assert!(Sbatch::try_from(args).is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, clap::Parser)]
+pub struct SbatchArgs {
+    /// Partition to submit the job to
+    #[arg(short, long)]
+    pub partition: Option<String>,
+
+    /// Number of tasks
+    #[arg(short, long)]
+    pub ntasks: Option<NonZeroU32>,
+
+    /// Number of CPUs per task
+    #[arg(short, long)]
+    pub cpus_per_task: Option<NonZeroU32>,
+
+    /// Memory per node, such as `4G`
+    #[arg(long)]
+    pub mem: Option<String>,
+
+    /// Time limit, such as `1:00:00`
+    #[arg(short, long)]
+    pub time: Option<String>,
+
+    /// Name of the job
+    #[arg(short = 'J', long)]
+    pub job_name: Option<String>,
+
+    /// File for the standard output of the job
+    #[arg(short, long)]
+    pub output: Option<String>,
+
+    /// File for the standard error of the job
+    #[arg(short, long)]
+    pub error: Option<String>,
+
+    /// Dependency of the job, such as `afterok:1234`
+    #[arg(short, long)]
+    pub dependency: Option<String>,
+
+    /// Job script, followed by its arguments
+    #[arg(trailing_var_arg = true)]
+    pub script: Vec<String>,
+}
+
+impl TryFrom<SbatchArgs> for Sbatch {
+    type Error = SbatchError;
+
+    /// Converts the parsed arguments into a `Sbatch` instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchError` if a value is invalid.
+    fn try_from(args: SbatchArgs) -> Result<Self, Self::Error> {
+        let mut sbatch = Sbatch::new();
+        let options = [
+            args.partition.map(SbatchOption::Partition),
+            args.ntasks.map(SbatchOption::NTasks),
+            args.cpus_per_task
+                .map(|n| SbatchOption::CPUsPerTask(n.to_string())),
+            args.mem
+                .map(|mem| mem.parse())
+                .transpose()?
+                .map(SbatchOption::Mem),
+            args.time
+                .map(|time| time.parse())
+                .transpose()?
+                .map(SbatchOption::Time),
+            args.job_name.map(SbatchOption::JobName),
+            args.output.map(SbatchOption::Output),
+            args.error.map(SbatchOption::Error),
+        ];
+        for option in options.into_iter().flatten() {
+            sbatch.add_option(option)?;
+        }
+        if let Some(dependency) = args.dependency {
+            sbatch.set_dependency(dependency.parse::<Dependency>()?)?;
+        }
+        if !args.script.is_empty() {
+            sbatch.set_script(args.script.join(" "))?;
+        }
+        Ok(sbatch)
+    }
+}
@@ -0,0 +1,233 @@
+//! Command line parsing for `Sbatch`
+
+use std::str::FromStr;
+use thiserror::Error;
+
+use super::{Sbatch, SbatchError};
+use crate::SbatchOption;
+use crate::sbatch_option::shell_quote;
+
+/// Represents an error that can occur when parsing an `sbatch` command line.
+///
+/// - `UnterminatedQuote`: A single or double quote was opened but never closed.
+/// - `Empty`: The command line contains no tokens.
+/// - `InvalidToken`: A token could not be interpreted, e.g. a bare `-` or a program other than `sbatch`.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum CommandParseError {
+    #[error("Unterminated quote")]
+    UnterminatedQuote,
+    #[error("Empty command line")]
+    Empty,
+    #[error("Invalid token: {0}")]
+    InvalidToken(String),
+}
+
+/// Splits a command line into tokens using shell quoting rules.
+///
+/// Tokens are separated by whitespace. Single quotes preserve their contents literally, double quotes
+/// allow `\"` and `\\` escapes, and outside of quotes a backslash escapes the following character.
+/// A backslash followed by a newline is a line continuation and is removed, so the output of `Sbatch::build_multiline`
+/// can be parsed back.
+/// Quotes may appear in the middle of a token, so `--wrap="echo hello"` is a single token.
+///
+/// # Arguments
+///
+/// * `s` - The command line to split.
+///
+/// # Returns
+///
+/// This function returns the tokens with quotes removed.
+///
+/// # Errors
+///
+/// This function returns a `CommandParseError` if a quote is not terminated or there are no tokens.
+///
+/// # Examples
+///
+/// ```
+/// use sbatch_rs::tokenize_command_line;
+///
+/// let tokens = tokenize_command_line(r#"sbatch --job-name=test --wrap="echo 'hello world'""#).unwrap();
+/// assert_eq!(tokens, vec!["sbatch", "--job-name=test", "--wrap=echo 'hello world'"]);
+/// ```
+pub fn tokenize_command_line(s: &str) -> Result<Vec<String>, CommandParseError> {
+    let mut tokens = Vec::new();
+    let mut token: Option<String> = None;
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => tokens.extend(token.take()),
+            '\'' => {
+                let current = token.get_or_insert_with(String::new);
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(c) => current.push(c),
+                        None => return Err(CommandParseError::UnterminatedQuote),
+                    }
+                }
+            }
+            '"' => {
+                let current = token.get_or_insert_with(String::new);
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some('\n') => {}
+                            Some(c @ ('"' | '\\')) => current.push(c),
+                            Some(c) => {
+                                current.push('\\');
+                                current.push(c);
+                            }
+                            None => return Err(CommandParseError::UnterminatedQuote),
+                        },
+                        Some(c) => current.push(c),
+                        None => return Err(CommandParseError::UnterminatedQuote),
+                    }
+                }
+            }
+            '\\' => match chars.next() {
+                // A backslash-newline is a line continuation, as in POSIX shells
+                Some('\n') => {}
+                next => token.get_or_insert_with(String::new).extend(next),
+            },
+            c => token.get_or_insert_with(String::new).push(c),
+        }
+    }
+    tokens.extend(token);
+
+    if tokens.is_empty() {
+        Err(CommandParseError::Empty)
+    } else {
+        Ok(tokens)
+    }
+}
+
+impl Sbatch {
+    /// Creates a new `Sbatch` from the arguments of an `sbatch` invocation, without the program name.
+    ///
+    /// Options may be given as `--key=value`, `--key value`, `-k value`, `-kvalue`, or as bare flags such as `--hold`
+    /// or `-H`.
+    /// The first argument that is not an option is the script, and any remaining arguments are its arguments.
+    /// A `--` terminator ends option parsing, and the arguments after it are the script and its arguments.
+    /// The script arguments are quoted for the shell where needed, so they survive `build` unchanged.
+    /// A standalone `:` starts the next heterogeneous job component, as in the output of `build`.
+    /// Deprecated flags, such as `--workdir`, are parsed as the flag replacing them and reported by `warnings`.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - The arguments to parse, e.g. `std::env::args().skip(1)` collected into a `Vec`.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a new `Sbatch` with the options and script from the arguments.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchError::CommandParseError` if an argument is a bare `-`, and a
+    /// `SbatchError::SbatchOptionError` if an option is unknown, missing its value, or invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::Sbatch;
+    ///
+    /// let args: Vec<String> = ["-J", "test", "--output", "test.out", "--hold", "--", "-script.sh", "arg"]
+    ///     .iter()
+    ///     .map(|arg| arg.to_string())
+    ///     .collect();
+    /// let sbatch = Sbatch::from_args(&args).unwrap();
+    /// assert_eq!(
+    ///     sbatch.build().unwrap(),
+    ///     "sbatch --hold --job-name=test --output=test.out -script.sh arg"
+    /// );
+    /// ```
+    pub fn from_args(args: &[String]) -> Result<Sbatch, SbatchError> {
+        let mut args = args.iter();
+        let mut sbatch = Sbatch::new();
+        while let Some(arg) = args.next() {
+            // A standalone `:` separates the components of a heterogeneous job
+            if arg == ":" {
+                sbatch.add_het_group();
+                continue;
+            }
+
+            // The first non-option argument, or everything after `--`, is the script followed by its arguments
+            if arg == "--" || !arg.starts_with('-') {
+                let script = std::iter::once(arg)
+                    .filter(|arg| *arg != "--")
+                    .chain(args.by_ref())
+                    .map(|arg| shell_quote(arg))
+                    .collect::<Vec<_>>();
+                if !script.is_empty() {
+                    sbatch.set_script(script.join(" "))?;
+                }
+                break;
+            }
+
+            // Split the key from an inline value, or take the value from the next argument
+            let (key, value) = match arg.strip_prefix("--") {
+                Some(arg) => match arg.split_once('=') {
+                    Some((key, value)) => (key, Some(value)),
+                    None => (arg, None),
+                },
+                None => {
+                    // Short options that take a value may have it attached directly, e.g. `-Jname`
+                    let arg = &arg[1..];
+                    let (key, value) = arg.split_at(arg.chars().next().map_or(0, char::len_utf8));
+                    if !value.is_empty() && SbatchOption::requires_value(key) == Some(true) {
+                        (key, Some(value))
+                    } else {
+                        (arg, None)
+                    }
+                }
+            };
+            if key.is_empty() {
+                return Err(CommandParseError::InvalidToken(arg.clone()).into());
+            }
+            let value = match value {
+                None if SbatchOption::requires_value(key) == Some(true) => {
+                    args.next().map(String::as_str)
+                }
+                value => value,
+            };
+
+            sbatch.overwrite_option(SbatchOption::from_key_value(key, value)?)?;
+            sbatch
+                .warnings
+                .extend(SbatchOption::deprecation_warning(arg));
+        }
+        Ok(sbatch)
+    }
+}
+
+impl FromStr for Sbatch {
+    type Err = SbatchError;
+
+    /// Parses an `sbatch` command line, such as the output of `Sbatch::build`.
+    ///
+    /// The command must start with `sbatch`, and the remaining tokens are parsed with `Sbatch::from_args`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchError::CommandParseError` if the command line cannot be tokenized or
+    /// contains an invalid token, and a `SbatchError::SbatchOptionError` if an option is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::Sbatch;
+    ///
+    /// let sbatch: Sbatch = "sbatch -J test --output test.out test.sh arg".parse().unwrap();
+    /// assert_eq!(sbatch.build().unwrap(), "sbatch --job-name=test --output=test.out test.sh arg");
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens = tokenize_command_line(s)?;
+        match tokens.first() {
+            Some(program) if program == "sbatch" => Sbatch::from_args(&tokens[1..]),
+            Some(program) => Err(CommandParseError::InvalidToken(program.clone()).into()),
+            None => Err(CommandParseError::Empty.into()),
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! `SBATCH_*` input environment variables for `Sbatch`
+
+use super::{Sbatch, SbatchError};
+use crate::SbatchOption;
+
+impl Sbatch {
+    /// Returns the `SBATCH_*` input environment variables equivalent to the options.
+    ///
+    /// Options without an environment variable equivalent are skipped, and so is the script. As the
+    /// environment cannot describe several heterogeneous job components, only the options of the active
+    /// component are used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Sbatch, SbatchOption};
+    ///
+    /// let env = Sbatch::new()
+    ///     .add_option(SbatchOption::Partition("general".to_string())).unwrap()
+    ///     .add_option(SbatchOption::Hold).unwrap()
+    ///     .add_option(SbatchOption::Account("lab".to_string())).unwrap()
+    ///     .to_env();
+    ///
+    /// assert_eq!(
+    ///     env,
+    ///     vec![
+    ///         ("SBATCH_ACCOUNT".to_string(), "lab".to_string()),
+    ///         ("SBATCH_PARTITION".to_string(), "general".to_string()),
+    ///     ]
+    /// );
+    /// ```
+    pub fn to_env(&self) -> Vec<(String, String)> {
+        self.options().filter_map(SbatchOption::to_env).collect()
+    }
+
+    /// Creates a `Sbatch` instance from the `SBATCH_*` input environment variables among `vars`.
+    ///
+    /// Variables that sbatch does not read, including unknown `SBATCH_*` variables, are ignored. Explicit
+    /// options can be layered on top with `overwrite_option`, as sbatch lets command line flags override
+    /// the environment.
+    ///
+    /// # Arguments
+    ///
+    /// * `vars` - The environment variables, as name and value pairs.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a new `Sbatch` with an option for each recognized variable.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchError::InvalidEnvVar` naming the variable if its value is not
+    /// valid for the option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Sbatch, SbatchOption};
+    ///
+    /// let vars = [("SBATCH_PARTITION", "general"), ("SBATCH_TIMELIMIT", "30"), ("HOME", "/home/user")];
+    /// let mut sbatch = Sbatch::from_env_vars(vars).unwrap();
+    /// sbatch.overwrite_option(SbatchOption::Partition("debug".to_string())).unwrap();
+    ///
+    /// assert_eq!(sbatch.build().unwrap(), "sbatch --partition=debug --time=0-00:30:00");
+    /// ```
+    pub fn from_env_vars<K, V>(
+        vars: impl IntoIterator<Item = (K, V)>,
+    ) -> Result<Sbatch, SbatchError>
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let mut sbatch = Sbatch::new();
+        for (name, value) in vars {
+            let name = name.as_ref();
+            let option = SbatchOption::from_env(name, value.as_ref()).map_err(|source| {
+                SbatchError::InvalidEnvVar {
+                    name: name.to_string(),
+                    source,
+                }
+            })?;
+            if let Some(option) = option {
+                sbatch.overwrite_option(option)?;
+            }
+        }
+        Ok(sbatch)
+    }
+
+    /// Creates a `Sbatch` instance from the `SBATCH_*` input environment variables of the current process.
+    ///
+    /// This honors the same environment sbatch does. See `from_env_vars` for how the variables are read.
+    /// Variables whose name or value is not valid Unicode are ignored.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchError::InvalidEnvVar` naming the variable if its value is not
+    /// valid for the option.
+    pub fn from_env() -> Result<Sbatch, SbatchError> {
+        Sbatch::from_env_vars(std::env::vars_os().filter_map(|(name, value)| {
+            Some((name.into_string().ok()?, value.into_string().ok()?))
+        }))
+    }
+}
@@ -0,0 +1,62 @@
+//! Checks across several `Sbatch` jobs
+
+use super::Sbatch;
+use crate::SbatchOption;
+
+/// Checks that no two jobs share a `--job-name`.
+///
+/// Workflows often rely on job names being unique, e.g. for `singleton` dependencies, so a repeated
+/// name is usually a mistake. Jobs without a `--job-name` are ignored. Only the active component of
+/// a heterogeneous job is checked.
+///
+/// # Arguments
+///
+/// * `jobs` - The jobs to check.
+///
+/// # Returns
+///
+/// This function returns `Ok(())` if every job name is unique.
+///
+/// # Errors
+///
+/// This function returns the names used by more than one job, each listed once in the order they first appear.
+///
+/// # Examples
+///
+/// ```
+/// use sbatch_rs::{Sbatch, SbatchOption, check_unique_job_names};
+///
+/// let job = |name: &str| {
+///     Sbatch::new()
+///         .option(SbatchOption::JobName(name.to_string()))
+///         .unwrap()
+/// };
+///
+/// assert!(check_unique_job_names(&[job("a"), job("b")]).is_ok());
+/// assert_eq!(
+///     check_unique_job_names(&[job("a"), job("b"), job("a")]),
+///     Err(vec!["a".to_string()])
+/// );
+/// ```
+pub fn check_unique_job_names(jobs: &[Sbatch]) -> Result<(), Vec<String>> {
+    let mut seen = Vec::new();
+    let mut duplicates = Vec::new();
+    for job in jobs {
+        if let Some(SbatchOption::JobName(name)) =
+            job.get_option(&SbatchOption::JobName(String::new()))
+        {
+            if seen.contains(&name) {
+                if !duplicates.contains(name) {
+                    duplicates.push(name.clone());
+                }
+            } else {
+                seen.push(name);
+            }
+        }
+    }
+    if duplicates.is_empty() {
+        Ok(())
+    } else {
+        Err(duplicates)
+    }
+}
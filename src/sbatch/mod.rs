@@ -3,10 +3,138 @@
 use std::collections::BTreeSet;
 use thiserror::Error;
 
-use crate::{SbatchOption, SbatchOptionError};
+#[cfg(feature = "clap")]
+mod args;
+mod command;
+mod env;
+mod jobs;
+#[cfg(feature = "process")]
+mod process;
+mod script;
+
+#[cfg(feature = "clap")]
+pub use args::SbatchArgs;
+pub use command::{CommandParseError, tokenize_command_line};
+pub use jobs::check_unique_job_names;
+#[cfg(feature = "process")]
+pub use process::{CommandSubmitter, MockSubmitter};
+
+use crate::{
+    Dependency, DependencyError, DependencyType, OpenMode, OptionSpec, SbatchOption,
+    SbatchOptionError, TimeLimit, Warning,
+};
+
+// Helper type matching a single `SbatchOption` variant
+type OptionMatcher = fn(&SbatchOption) -> bool;
+
+/// Pairs of options that Slurm refuses to accept together.
+///
+/// Extend this table to have `Sbatch::validate` reject further combinations.
+const CONFLICTING_OPTIONS: &[(OptionMatcher, OptionMatcher)] = &[
+    (
+        |o| matches!(o, SbatchOption::Mem(_)),
+        |o| matches!(o, SbatchOption::MemPerCPU(_)),
+    ),
+    (
+        |o| matches!(o, SbatchOption::Mem(_)),
+        |o| matches!(o, SbatchOption::MemPerGPU(_)),
+    ),
+    (
+        |o| matches!(o, SbatchOption::MemPerCPU(_)),
+        |o| matches!(o, SbatchOption::MemPerGPU(_)),
+    ),
+    (
+        |o| matches!(o, SbatchOption::Exclusive(_)),
+        |o| matches!(o, SbatchOption::Oversubscribe),
+    ),
+];
+
+// Helper function to find an individual topology option contradicting `--extra-node-info`.
+// `--extra-node-info` is `sockets[:cores[:threads]]`, where `*` leaves a level unconstrained.
+fn topology_conflict(options: &[SbatchOption]) -> Option<(SbatchOption, SbatchOption)> {
+    let (extra, value) = options.iter().find_map(|option| match option {
+        SbatchOption::ExtraNodeInfo(value) => Some((option, value)),
+        _ => None,
+    })?;
+    let levels: Vec<Option<u32>> = value.split(':').map(|level| level.parse().ok()).collect();
+
+    options
+        .iter()
+        .find(|option| {
+            let (index, count) = match option {
+                SbatchOption::SocketsPerNode(count) => (0, count.get()),
+                SbatchOption::CoresPerSocket(count) => (1, count.get()),
+                SbatchOption::ThreadsPerCore(count) => match count.parse() {
+                    Ok(count) => (2, count),
+                    Err(_) => return false,
+                },
+                _ => return false,
+            };
+            levels
+                .get(index)
+                .copied()
+                .flatten()
+                .is_some_and(|level| level != count)
+        })
+        .map(|option| (extra.clone(), option.clone()))
+}
+
+// Helper function to describe a task count that cannot fit on the requested nodes.
+// With `--ntasks-per-node`, `--ntasks` is the total and `--ntasks-per-node` a maximum, so the layout is
+// only contradictory when there are more tasks than the nodes can hold. A `--nodes` value that is not a
+// plain number, such as a range, is not checked.
+fn task_layout_conflict(options: &[SbatchOption]) -> Option<String> {
+    let count = |matches: OptionMatcher| {
+        options.iter().find(|o| matches(o)).and_then(|o| match o {
+            SbatchOption::NTasks(count) | SbatchOption::NTasksPerNode(count) => Some(count.get()),
+            SbatchOption::Nodes(value) => value.parse::<u32>().ok(),
+            _ => None,
+        })
+    };
+    let ntasks = count(|o| matches!(o, SbatchOption::NTasks(_)))?;
+    let nodes = count(|o| matches!(o, SbatchOption::Nodes(_)))?;
+    let per_node = count(|o| matches!(o, SbatchOption::NTasksPerNode(_)))?;
+
+    (u64::from(ntasks) > u64::from(nodes) * u64::from(per_node)).then(|| {
+        format!(
+            "--ntasks={ntasks} does not fit on --nodes={nodes} with --ntasks-per-node={per_node}"
+        )
+    })
+}
+
+// Helper function to normalize an option value that can be written in several equivalent ways.
+// A `--dependency` that parses is replaced by its canonical key, so `afterok:2:1` matches `afterok:1,afterok:2`.
+fn canonical_option(option: &SbatchOption) -> SbatchOption {
+    match option {
+        SbatchOption::Dependency(value) => match value.parse::<Dependency>() {
+            Ok(dependency) => SbatchOption::Dependency(dependency.canonical_key()),
+            Err(_) => option.clone(),
+        },
+        _ => option.clone(),
+    }
+}
+
+// Helper function to check whether a filename pattern contains the array task id (`%a`).
+// `%%` is a literal percent sign, and a zero-padding width may precede the specifier (e.g. `%3a`).
+fn has_array_task_pattern(pattern: &str) -> bool {
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c == '%' {
+            match chars.by_ref().find(|c| !c.is_ascii_digit()) {
+                Some('a') => return true,
+                _ => continue,
+            }
+        }
+    }
+    false
+}
 
 /// sbatch command builder
 ///
+/// The `set_*`, `add_*`, and `with_order` methods borrow the builder mutably and return `&mut Self`,
+/// while `option` and `script` take and return the builder by value, for storing it mid-chain or
+/// moving it into a closure.
+///
 /// # Examples
 ///
 /// ```
@@ -25,9 +153,44 @@ use crate::{SbatchOption, SbatchOptionError};
 /// assert_eq!(sbatch.unwrap(), "sbatch --error=test.err --job-name=test --output=test.out test.sh");
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Sbatch {
-    sbatch_options: Option<BTreeSet<SbatchOption>>,
+    sbatch_options: Option<Vec<SbatchOption>>,
     script: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    script_from_stdin: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    order: OrderMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    het_groups: Vec<Vec<SbatchOption>>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    warnings: Vec<Warning>,
+}
+
+/// The order in which a `Sbatch` instance emits its options.
+///
+/// - `Sorted`: Options are sorted by flag name, so the output is the same regardless of the order they were added in,
+///   and the position of a flag does not depend on its value.
+/// - `InsertionOrder`: Options are emitted in the order they were added. Overwriting an option of a variant
+///   that is already present replaces the earlier value in place.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OrderMode {
+    #[default]
+    Sorted,
+    InsertionOrder,
+}
+
+/// How `Sbatch::merge` resolves an option or script set differently on both builders.
+///
+/// - `PreferSelf`: Keep the value from the builder being merged into.
+/// - `PreferOther`: Take the value from the builder being merged in.
+/// - `ErrorOnConflict`: Return an error and leave the builder unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MergeStrategy {
+    PreferSelf,
+    PreferOther,
+    ErrorOnConflict,
 }
 
 /// The `SbatchError` enum represents an error that can occur when building an `sbatch` command.
@@ -36,6 +199,17 @@ pub struct Sbatch {
 /// - No options or script provided
 /// - Script is empty
 /// - Sbatch option error
+/// - Invalid `#SBATCH` directive in a job script
+/// - Invalid `SBATCH_*` input environment variable
+/// - Conflicting options, or both a script and `--wrap`
+/// - Multi-line script body where a script path is expected
+/// - Task counts that cannot fit on the requested nodes
+/// - Adding a second option of a variant that is already set
+/// - Different scripts when merging builders, or merging in a heterogeneous job
+/// - Time limit or array task id exceeding a caller-supplied maximum
+/// - Array tasks appending to a shared output file
+/// - Invalid `sbatch` command line
+/// - Invalid dependency
 #[derive(Debug, Error)]
 pub enum SbatchError {
     #[error("No sbatch options or script provided")]
@@ -46,6 +220,41 @@ pub enum SbatchError {
     SbatchOptionError(#[from] SbatchOptionError),
     #[error("Execution failed: {0}")]
     SbatchExecutionError(String),
+    #[error("Invalid #SBATCH directive on line {line} ({directive}): {source}")]
+    InvalidDirective {
+        line: usize,
+        directive: String,
+        source: SbatchOptionError,
+    },
+    #[error("Invalid environment variable {name}: {source}")]
+    InvalidEnvVar {
+        name: String,
+        source: SbatchOptionError,
+    },
+    #[error("Conflicting options: {0} and {1}")]
+    ConflictingOptions(SbatchOption, SbatchOption),
+    #[error("Option {0} is already set, cannot add {1}")]
+    DuplicateOption(SbatchOption, SbatchOption),
+    #[error("Inconsistent task layout: {0}")]
+    InconsistentTaskLayout(String),
+    #[error("A script and the --wrap option cannot be used together")]
+    WrapAndScript,
+    #[error("The script is a multi-line body, use to_script to render it as a job script")]
+    MultiLineScript,
+    #[error("Conflicting scripts: {0} and {1}")]
+    ConflictingScripts(String, String),
+    #[error("A heterogeneous job cannot be merged into another builder")]
+    HetJobMerge,
+    #[error("Time limit {time} exceeds the maximum of {limit}")]
+    TimeLimitExceeded { time: TimeLimit, limit: TimeLimit },
+    #[error("Array index {index} is not below the maximum array size of {max_array_size}")]
+    ArraySizeExceeded { index: u32, max_array_size: u32 },
+    #[error("Array tasks append to the same file, as {0} has no %a task id")]
+    SharedArrayOutput(SbatchOption),
+    #[error("Command parse error: {0}")]
+    CommandParseError(#[from] CommandParseError),
+    #[error("Dependency error: {0}")]
+    DependencyError(#[from] DependencyError),
 }
 
 impl Sbatch {
@@ -63,9 +272,43 @@ impl Sbatch {
         Sbatch {
             sbatch_options: None,
             script: None,
+            script_from_stdin: false,
+            order: OrderMode::default(),
+            het_groups: Vec::new(),
+            warnings: Vec::new(),
         }
     }
 
+    /// Sets the order in which options are emitted by `build` and `to_script`.
+    ///
+    /// The mode should be chosen before adding options, as it also decides how repeated options are stored.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The `OrderMode` to use. The default is `OrderMode::Sorted`.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a mutable reference to the `Sbatch` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{OrderMode, Sbatch, SbatchOption};
+    ///
+    /// let sbatch = Sbatch::new()
+    ///     .with_order(OrderMode::InsertionOrder)
+    ///     .add_option(SbatchOption::Output("test.out".to_string())).unwrap()
+    ///     .add_option(SbatchOption::JobName("test".to_string())).unwrap()
+    ///     .build();
+    ///
+    /// assert_eq!(sbatch.unwrap(), "sbatch --output=test.out --job-name=test");
+    /// ```
+    pub fn with_order(&mut self, mode: OrderMode) -> &mut Self {
+        self.order = mode;
+        self
+    }
+
     /// Adds an `SbatchOption` to the `Sbatch` instance.
     ///
     /// # Arguments
@@ -76,9 +319,14 @@ impl Sbatch {
     ///
     /// This function returns a mutable reference to the `Sbatch` instance.
     ///
+    /// Adding an option identical to one already present does nothing. A mergeable option, such as `--gres` or
+    /// `--licenses`, is combined with one of the same variant that is already set (see `SbatchOption::merge_value`).
+    /// To change the value of any other option that is already set, use `overwrite_option`.
+    ///
     /// # Errors
     ///
-    /// This function returns a `SbatchError` if the `SbatchOption` is invalid.
+    /// This function returns a `SbatchError` if the `SbatchOption` is invalid, or a `SbatchError::DuplicateOption`
+    /// if a non-mergeable option of the same variant is already set to a different value.
     ///
     /// # Examples
     ///
@@ -95,24 +343,36 @@ impl Sbatch {
     ///
     /// // Verify that the `sbatch` command was built properly
     /// assert!(sbatch.is_ok());
-    /// assert_eq!(sbatch.unwrap(), "sbatch --error=test.err --job-name=test --output=test.out --wrap=\"test\"");
+    /// assert_eq!(sbatch.unwrap(), "sbatch --error=test.err --job-name=test --output=test.out --wrap=test");
     /// ```
     pub fn add_option(&mut self, option: SbatchOption) -> Result<&mut Self, SbatchError> {
         // Validate the option
         option.validate()?;
 
-        // Add the option to the set
-        self.sbatch_options
-            .get_or_insert_with(BTreeSet::new)
-            .insert(option);
+        // Merge or reject a different value for a variant that is already set
+        if let Some(existing) = self.get_option(&option)
+            && *existing != option
+        {
+            let existing = existing.clone();
+            return match existing.clone().merge_value(option.clone()) {
+                Some(merged) => {
+                    self.replace_option(merged);
+                    Ok(self)
+                }
+                None => Err(SbatchError::DuplicateOption(existing, option)),
+            };
+        }
+        self.insert_option(option);
         Ok(self)
     }
 
-    /// Sets the script for the `Sbatch` instance.
+    /// Adds an `SbatchOption`, replacing any option of the same variant.
+    ///
+    /// With `OrderMode::InsertionOrder`, the new value takes the position of the one it replaces.
     ///
     /// # Arguments
     ///
-    /// * `script` - A string representing the script to run.
+    /// * `option` - An `SbatchOption` to add to the `Sbatch` instance.
     ///
     /// # Returns
     ///
@@ -120,90 +380,1372 @@ impl Sbatch {
     ///
     /// # Errors
     ///
-    /// This function returns a `SbatchError` if the script is empty.
+    /// This function returns a `SbatchError` if the `SbatchOption` is invalid.
     ///
     /// # Examples
     ///
     /// ```
-    /// use sbatch_rs::Sbatch;
+    /// use sbatch_rs::{Sbatch, SbatchOption};
+    ///
+    /// let sbatch = Sbatch::new()
+    ///     .add_option(SbatchOption::JobName("test".to_string())).unwrap()
+    ///     .overwrite_option(SbatchOption::JobName("other".to_string())).unwrap()
+    ///     .build();
+    ///
+    /// assert_eq!(sbatch.unwrap(), "sbatch --job-name=other");
+    /// ```
+    pub fn overwrite_option(&mut self, option: SbatchOption) -> Result<&mut Self, SbatchError> {
+        option.validate()?;
+        self.replace_option(option);
+        Ok(self)
+    }
+
+    /// Adds an `SbatchOption`, taking and returning the `Sbatch` instance by value.
+    ///
+    /// This is the consuming form of `add_option`, for storing a builder mid-chain or moving it into a closure.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - An `SbatchOption` to add to the `Sbatch` instance.
+    ///
+    /// # Returns
+    ///
+    /// This function returns the `Sbatch` instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchError` if the `SbatchOption` is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Sbatch, SbatchOption};
+    ///
+    /// let base = Sbatch::new()
+    ///     .option(SbatchOption::Partition("gpu".to_string())).unwrap();
+    ///
+    /// let sbatch = base
+    ///     .clone()
+    ///     .option(SbatchOption::JobName("test".to_string())).unwrap()
+    ///     .script("test.sh".to_string()).unwrap();
+    ///
+    /// assert_eq!(sbatch.build().unwrap(), "sbatch --job-name=test --partition=gpu test.sh");
+    /// assert_eq!(base.build().unwrap(), "sbatch --partition=gpu");
+    /// ```
+    pub fn option(mut self, option: SbatchOption) -> Result<Self, SbatchError> {
+        self.add_option(option)?;
+        Ok(self)
+    }
+
+    /// Merges the options and script of another builder into this one.
+    ///
+    /// This is useful for combining a builder holding site defaults with one holding per-job overrides.
+    /// Options and a script set on only one builder are always kept. When both set an option of the same
+    /// variant to different values, or both set different scripts, `strategy` decides which is kept.
+    /// Options taken from `other` are added after the existing ones. The options are merged into the active
+    /// component of a heterogeneous job, and this builder keeps its `OrderMode`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The builder to merge in.
+    /// * `strategy` - How to resolve options or scripts set differently on both builders.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a mutable reference to the `Sbatch` instance.
+    ///
+    /// # Errors
+    ///
+    /// With `MergeStrategy::ErrorOnConflict`, this function returns a `SbatchError::ConflictingOptions` or
+    /// `SbatchError::ConflictingScripts` for the first difference found, and leaves this builder unchanged.
+    /// It returns `SbatchError::HetJobMerge` if `other` is a heterogeneous job, since its components have
+    /// no counterpart in this builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{MergeStrategy, Sbatch, SbatchOption};
+    ///
+    /// let mut defaults = Sbatch::new();
+    /// defaults
+    ///     .add_option(SbatchOption::Account("site".to_string())).unwrap()
+    ///     .add_option(SbatchOption::Partition("batch".to_string())).unwrap();
+    ///
+    /// let mut job = Sbatch::new();
+    /// job
+    ///     .add_option(SbatchOption::Partition("gpu".to_string())).unwrap()
+    ///     .set_script("test.sh".to_string()).unwrap();
+    ///
+    /// defaults.merge(job, MergeStrategy::PreferOther).unwrap();
+    /// assert_eq!(defaults.build().unwrap(), "sbatch --account=site --partition=gpu test.sh");
+    /// ```
+    pub fn merge(
+        &mut self,
+        other: Sbatch,
+        strategy: MergeStrategy,
+    ) -> Result<&mut Self, SbatchError> {
+        // Only the options of a single component can be merged
+        if !other.het_groups.is_empty() {
+            return Err(SbatchError::HetJobMerge);
+        }
+
+        let ours = self.sbatch_options.clone().unwrap_or_default();
+        let theirs = other.sbatch_options.unwrap_or_default();
+        let conflicts = |a: &SbatchOption, b: &SbatchOption| a.is_same_variant(b) && a != b;
+
+        // Check for differences before changing anything
+        if strategy == MergeStrategy::ErrorOnConflict {
+            for option in &theirs {
+                if let Some(existing) = ours.iter().find(|existing| conflicts(existing, option)) {
+                    return Err(SbatchError::ConflictingOptions(
+                        existing.clone(),
+                        option.clone(),
+                    ));
+                }
+            }
+            if let (Some(a), Some(b)) = (&self.script, &other.script)
+                && a != b
+            {
+                return Err(SbatchError::ConflictingScripts(a.clone(), b.clone()));
+            }
+        }
+
+        // Keep our options unless they are overridden, then add theirs unless they are overridden
+        let (kept, added): (Vec<_>, Vec<_>) = match strategy {
+            MergeStrategy::PreferOther => (
+                ours.iter()
+                    .filter(|option| !theirs.iter().any(|other| conflicts(option, other)))
+                    .cloned()
+                    .collect(),
+                theirs,
+            ),
+            _ => (
+                ours.clone(),
+                theirs
+                    .into_iter()
+                    .filter(|option| !ours.iter().any(|existing| conflicts(existing, option)))
+                    .collect(),
+            ),
+        };
+        self.sbatch_options = None;
+        for option in kept.into_iter().chain(added) {
+            self.insert_option(option);
+        }
+
+        // The script follows the same rule as the options, with reading from stdin counting as a script
+        let take_script = match (&self.script, &other.script) {
+            (_, None) => false,
+            (None, Some(_)) if !self.script_from_stdin => true,
+            _ => strategy == MergeStrategy::PreferOther,
+        };
+        if take_script {
+            self.script = other.script;
+            self.script_from_stdin = false;
+        } else if self.script.is_none() && other.script_from_stdin {
+            self.script_from_stdin = true;
+        }
+        Ok(self)
+    }
+
+    /// Starts a new component of a heterogeneous job.
+    ///
+    /// Options added afterwards apply to the new component, and the methods that inspect or validate
+    /// options (such as `options` and `get_option`) only see the active component.
+    /// `build` separates the components with ` : ` and `to_script` with `#SBATCH hetjob` lines.
+    /// Nothing happens if the active component has no options yet.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a mutable reference to the `Sbatch` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Sbatch, SbatchOption};
     ///
-    /// // Create a new `Sbatch` instance
     /// let sbatch = Sbatch::new()
+    ///     .add_option(SbatchOption::NTasks("1".parse().unwrap())).unwrap()
+    ///     .add_het_group()
+    ///     .add_option(SbatchOption::NTasks("4".parse().unwrap())).unwrap()
     ///     .set_script("test.sh".to_string()).unwrap()
     ///     .build();
     ///
-    /// // Verify that the `sbatch` command was built properly
-    /// assert!(sbatch.is_ok());
-    /// assert_eq!(sbatch.unwrap(), "sbatch test.sh");
+    /// assert_eq!(sbatch.unwrap(), "sbatch --ntasks=1 : --ntasks=4 test.sh");
     /// ```
-    pub fn set_script(&mut self, script: String) -> Result<&mut Self, SbatchError> {
-        let script = script.trim().to_string();
-        if script.is_empty() {
-            Err(SbatchError::ScriptEmpty)
-        } else {
-            self.script = Some(script);
-            Ok(self)
+    pub fn add_het_group(&mut self) -> &mut Self {
+        if let Some(options) = self.sbatch_options.take_if(|options| !options.is_empty()) {
+            self.het_groups.push(options);
         }
+        self
     }
 
-    /// Builds the `sbatch` command.
+    /// Returns an iterator over the options, in the order they are emitted by `build`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Sbatch, SbatchOption};
+    ///
+    /// let mut sbatch = Sbatch::new();
+    /// sbatch
+    ///     .add_option(SbatchOption::Output("test.out".to_string())).unwrap()
+    ///     .add_option(SbatchOption::JobName("test".to_string())).unwrap();
+    ///
+    /// let options: Vec<_> = sbatch.options().collect();
+    /// assert_eq!(options, vec![&SbatchOption::JobName("test".to_string()), &SbatchOption::Output("test.out".to_string())]);
+    /// ```
+    pub fn options(&self) -> impl Iterator<Item = &SbatchOption> {
+        self.ordered_options().into_iter()
+    }
+
+    /// Returns the number of options.
+    ///
+    /// Only the active component of a heterogeneous job is counted.
+    pub fn len(&self) -> usize {
+        self.sbatch_options.as_ref().map_or(0, Vec::len)
+    }
+
+    /// Returns `true` if no options have been added.
+    ///
+    /// The script is not taken into account.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Finds an option of the same variant as `like`, ignoring its value.
+    ///
+    /// # Arguments
+    ///
+    /// * `like` - An option of the variant to look for. Its value is not compared.
     ///
     /// # Returns
     ///
-    /// This function returns a string representing the `sbatch` command.
+    /// This function returns the first matching option, or `None` if there is no option of that variant.
     ///
-    /// # Errors
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Sbatch, SbatchOption};
+    ///
+    /// let mut sbatch = Sbatch::new();
+    /// sbatch.add_option(SbatchOption::JobName("test".to_string())).unwrap();
+    ///
+    /// let job_name = sbatch.get_option(&SbatchOption::JobName(String::new()));
+    /// assert_eq!(job_name, Some(&SbatchOption::JobName("test".to_string())));
+    /// ```
+    pub fn get_option(&self, like: &SbatchOption) -> Option<&SbatchOption> {
+        self.options().find(|option| option.is_same_variant(like))
+    }
+
+    /// Removes an option of the same variant as `like`, ignoring its value.
+    ///
+    /// # Arguments
     ///
-    /// This function returns a `SbatchError` if no options or script are provided.
+    /// * `like` - An option of the variant to remove. Its value is not compared.
+    ///
+    /// # Returns
+    ///
+    /// This function returns the removed option, or `None` if there is no option of that variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Sbatch, SbatchOption};
+    ///
+    /// let mut sbatch = Sbatch::new();
+    /// sbatch.add_option(SbatchOption::JobName("test".to_string())).unwrap();
+    ///
+    /// let removed = sbatch.remove_option(&SbatchOption::JobName(String::new()));
+    /// assert_eq!(removed, Some(SbatchOption::JobName("test".to_string())));
+    /// assert_eq!(sbatch.options().count(), 0);
+    /// ```
+    pub fn remove_option(&mut self, like: &SbatchOption) -> Option<SbatchOption> {
+        let like = self.get_option(like)?.clone();
+        let options = self.sbatch_options.as_mut()?;
+        let index = options.iter().position(|option| *option == like)?;
+        Some(options.remove(index))
+    }
+
+    /// Returns `true` if there is an option of the same variant as `like`, ignoring its value.
+    ///
+    /// # Arguments
+    ///
+    /// * `like` - An option of the variant to look for. Its value is not compared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Sbatch, SbatchOption};
+    ///
+    /// let mut sbatch = Sbatch::new();
+    /// sbatch.add_option(SbatchOption::JobName("test".to_string())).unwrap();
+    ///
+    /// assert!(sbatch.has_option(&SbatchOption::JobName(String::new())));
+    /// assert!(!sbatch.has_option(&SbatchOption::Output(String::new())));
+    /// ```
+    pub fn has_option(&self, like: &SbatchOption) -> bool {
+        self.get_option(like).is_some()
+    }
+
+    /// Returns the number of options in all components of the job.
+    ///
+    /// Unlike `len`, this also counts the options of the earlier components of a heterogeneous job.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Sbatch, SbatchOption};
+    ///
+    /// let mut sbatch = Sbatch::new();
+    /// sbatch
+    ///     .add_option(SbatchOption::NTasks("1".parse().unwrap())).unwrap()
+    ///     .add_het_group()
+    ///     .add_option(SbatchOption::NTasks("4".parse().unwrap())).unwrap();
+    ///
+    /// assert_eq!(sbatch.option_count(), 2);
+    /// assert_eq!(sbatch.len(), 1);
+    /// ```
+    pub fn option_count(&self) -> usize {
+        self.het_groups.iter().map(Vec::len).sum::<usize>() + self.len()
+    }
+
+    /// Removes all options, including those of earlier heterogeneous job components.
+    ///
+    /// The script and the order mode are kept.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a mutable reference to the `Sbatch` instance.
     ///
     /// # Examples
     ///
     /// ```
     /// use sbatch_rs::{Sbatch, SbatchOption};
     ///
-    /// // Create a new `Sbatch` instance
     /// let sbatch = Sbatch::new()
     ///     .add_option(SbatchOption::JobName("test".to_string())).unwrap()
-    ///     .add_option(SbatchOption::Output("test.out".to_string())).unwrap()
-    ///     .add_option(SbatchOption::Error("test.err".to_string())).unwrap()
     ///     .set_script("test.sh".to_string()).unwrap()
+    ///     .clear_options()
     ///     .build();
-    ///     
-    /// // Verify that the `sbatch` command was built properly
-    /// assert!(sbatch.is_ok());
-    /// assert_eq!(sbatch.unwrap(), "sbatch --error=test.err --job-name=test --output=test.out test.sh");
-    pub fn build(&self) -> Result<String, SbatchError> {
-        // Convert the sbatch options to a space-joined string
-        let options: Option<String> = self.sbatch_options.as_ref().map(|options| {
-            options
-                .iter()
-                .map(|o| o.to_string())
-                .collect::<Vec<String>>()
-                .join(" ")
-        });
-
-        // Combine the options and script
-        match (options, &self.script) {
-            (Some(o), Some(s)) => Ok(format!("sbatch {o} {s}")),
-            (Some(o), None) => Ok(format!("sbatch {o}")),
-            (None, Some(s)) => Ok(format!("sbatch {s}")),
-            (None, None) => Err(SbatchError::NoOptionsOrScript),
-        }
+    ///
+    /// assert_eq!(sbatch.unwrap(), "sbatch test.sh");
+    /// ```
+    pub fn clear_options(&mut self) -> &mut Self {
+        self.sbatch_options = None;
+        self.het_groups.clear();
+        self.warnings.clear();
+        self
     }
-}
 
-impl Default for Sbatch {
-    /// Creates a default `Sbatch` instance.
+    /// Returns warnings about options that Slurm deprecated or discourages.
+    ///
+    /// This includes the deprecated spellings that `from_args`, `from_str`, and `from_script` mapped to
+    /// their current variant while parsing, followed by any raw options that use a deprecated flag.
+    /// Building the command is not affected by warnings.
+    ///
+    /// # Returns
+    ///
+    /// This function returns the warnings, in the order the options were parsed or added.
     ///
     /// # Examples
     ///
     /// ```
     /// use sbatch_rs::Sbatch;
     ///
-    /// // Create a default `Sbatch` instance
-    /// let sbatch = Sbatch::default();
+    /// let sbatch: Sbatch = "sbatch --workdir=/scratch test.sh".parse().unwrap();
+    /// assert_eq!(sbatch.build().unwrap(), "sbatch --chdir=/scratch test.sh");
+    ///
+    /// let warnings = sbatch.warnings();
+    /// assert_eq!(warnings.len(), 1);
+    /// assert_eq!(warnings[0].flag, "--workdir");
+    /// assert_eq!(warnings[0].replacement, "--chdir");
     /// ```
-    fn default() -> Self {
-        Self::new()
+    pub fn warnings(&self) -> Vec<Warning> {
+        let raw = self
+            .het_groups
+            .iter()
+            .flatten()
+            .chain(self.sbatch_options.iter().flatten())
+            .filter_map(|option| match option {
+                SbatchOption::Raw(raw) => SbatchOption::deprecation_warning(raw),
+                _ => None,
+            });
+        self.warnings.iter().cloned().chain(raw).collect()
     }
-}
+
+    /// Removes the script, including a script read from standard input.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a mutable reference to the `Sbatch` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Sbatch, SbatchOption};
+    ///
+    /// let sbatch = Sbatch::new()
+    ///     .add_option(SbatchOption::JobName("test".to_string())).unwrap()
+    ///     .set_script("test.sh".to_string()).unwrap()
+    ///     .clear_script()
+    ///     .build();
+    ///
+    /// assert_eq!(sbatch.unwrap(), "sbatch --job-name=test");
+    /// ```
+    pub fn clear_script(&mut self) -> &mut Self {
+        self.script = None;
+        self.script_from_stdin = false;
+        self
+    }
+
+    /// Sets the script for the `Sbatch` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `script` - A string representing the script to run.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a mutable reference to the `Sbatch` instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchError` if the script is empty, or `SbatchError::WrapAndScript` if a `--wrap`
+    /// option has already been set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::Sbatch;
+    ///
+    /// // Create a new `Sbatch` instance
+    /// let sbatch = Sbatch::new()
+    ///     .set_script("test.sh".to_string()).unwrap()
+    ///     .build();
+    ///
+    /// // Verify that the `sbatch` command was built properly
+    /// assert!(sbatch.is_ok());
+    /// assert_eq!(sbatch.unwrap(), "sbatch test.sh");
+    /// ```
+    pub fn set_script(&mut self, script: String) -> Result<&mut Self, SbatchError> {
+        let script = script.trim().to_string();
+        if script.is_empty() {
+            Err(SbatchError::ScriptEmpty)
+        } else if self.has_wrap() {
+            Err(SbatchError::WrapAndScript)
+        } else {
+            self.script = Some(script);
+            self.script_from_stdin = false;
+            Ok(self)
+        }
+    }
+
+    /// Sets the script, taking and returning the `Sbatch` instance by value.
+    ///
+    /// This is the consuming form of `set_script`.
+    ///
+    /// # Arguments
+    ///
+    /// * `script` - A string representing the script to run.
+    ///
+    /// # Returns
+    ///
+    /// This function returns the `Sbatch` instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchError` if the script is empty, or `SbatchError::WrapAndScript` if a `--wrap`
+    /// option has already been set.
+    pub fn script(mut self, script: String) -> Result<Self, SbatchError> {
+        self.set_script(script)?;
+        Ok(self)
+    }
+
+    /// Configures the `sbatch` command to read the job script from stdin.
+    ///
+    /// No script path is added to the command, so `sbatch` reads the script from its standard input.
+    /// The caller is responsible for piping the script into the process, e.g. with `sbatch ... < job.sh`.
+    /// Any script set with `set_script` is cleared. At least one option is still required to build the command.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a mutable reference to the `Sbatch` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Sbatch, SbatchOption};
+    ///
+    /// let sbatch = Sbatch::new()
+    ///     .add_option(SbatchOption::JobName("test".to_string())).unwrap()
+    ///     .set_script_stdin()
+    ///     .build();
+    ///
+    /// assert_eq!(sbatch.unwrap(), "sbatch --job-name=test");
+    /// ```
+    pub fn set_script_stdin(&mut self) -> &mut Self {
+        self.script = None;
+        self.script_from_stdin = true;
+        self
+    }
+
+    /// Returns `true` if the job script is read from stdin, see `set_script_stdin`.
+    pub fn reads_script_from_stdin(&self) -> bool {
+        self.script_from_stdin
+    }
+
+    /// Sets the `--wrap` option to run a single command.
+    ///
+    /// Any existing `--wrap` option is replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `cmd` - The command to run.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a mutable reference to the `Sbatch` instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchError` if the command is invalid, or `SbatchError::WrapAndScript` if a script
+    /// has already been set, including with `set_script_stdin`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::Sbatch;
+    ///
+    /// let sbatch = Sbatch::new()
+    ///     .set_wrap("python main.py".to_string()).unwrap()
+    ///     .build();
+    ///
+    /// assert_eq!(sbatch.unwrap(), "sbatch --wrap='python main.py'");
+    /// ```
+    pub fn set_wrap(&mut self, cmd: String) -> Result<&mut Self, SbatchError> {
+        if self.script.is_some() || self.script_from_stdin {
+            return Err(SbatchError::WrapAndScript);
+        }
+        let wrap = SbatchOption::Wrap(cmd);
+        wrap.validate()?;
+
+        // Replace any existing wrap option
+        self.replace_option(wrap);
+        Ok(self)
+    }
+
+    /// Sets the `--dependency` option from a `Dependency`.
+    ///
+    /// Any existing `--dependency` option is replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `dependency` - The `Dependency` to set.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a mutable reference to the `Sbatch` instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchError::DependencyError` if the dependency cannot be built.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Dependency, Sbatch};
+    ///
+    /// let mut dependency = Dependency::new_or();
+    /// dependency.push_after_ok("1").unwrap().push_after_ok("2").unwrap();
+    ///
+    /// let sbatch = Sbatch::new()
+    ///     .set_dependency(dependency).unwrap()
+    ///     .set_script("test.sh".to_string()).unwrap()
+    ///     .build();
+    ///
+    /// // The `?` separator is quoted for the shell
+    /// assert_eq!(sbatch.unwrap(), "sbatch --dependency='afterok:1?afterok:2' test.sh");
+    /// ```
+    pub fn set_dependency(&mut self, dependency: Dependency) -> Result<&mut Self, SbatchError> {
+        let option = SbatchOption::Dependency(dependency.build()?);
+        option.validate()?;
+        self.replace_option(option);
+        Ok(self)
+    }
+
+    /// Adds an `after:<job_id>` dependency, merging it into any existing `--dependency` option.
+    ///
+    /// The existing option keeps its `,` (all) or `?` (any) separator, and duplicate dependencies are collapsed.
+    /// An existing option with a single entry has no separator, so it is treated as `,`.
+    /// If there is no `--dependency` option yet, one is created.
+    ///
+    /// # Arguments
+    ///
+    /// * `job_id` - The job id to depend on.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a mutable reference to the `Sbatch` instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchError::DependencyError` if the job id is invalid,
+    /// or the existing `--dependency` option cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::Sbatch;
+    ///
+    /// let sbatch = Sbatch::new()
+    ///     .add_dependency_after("1").unwrap()
+    ///     .add_dependency_after("2").unwrap()
+    ///     .add_dependency_after("1").unwrap()
+    ///     .build();
+    ///
+    /// assert_eq!(sbatch.unwrap(), "sbatch --dependency=after:1,after:2");
+    /// ```
+    pub fn add_dependency_after(&mut self, job_id: &str) -> Result<&mut Self, SbatchError> {
+        self.add_dependency(DependencyType::After(job_id.to_string()))
+    }
+
+    /// Adds a dependency, merging it into any existing `--dependency` option.
+    ///
+    /// The existing option keeps its `,` (all) or `?` (any) separator, and duplicate dependencies are collapsed.
+    /// An existing option with a single entry has no separator, so it is treated as `,`.
+    /// If there is no `--dependency` option yet, one is created.
+    ///
+    /// # Arguments
+    ///
+    /// * `dependency_type` - The `DependencyType` to add.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a mutable reference to the `Sbatch` instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchError::DependencyError` if the dependency is invalid,
+    /// or the existing `--dependency` option cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{DependencyType, Sbatch};
+    ///
+    /// let sbatch = Sbatch::new()
+    ///     .add_dependency(DependencyType::AfterOk("1".to_string())).unwrap()
+    ///     .add_dependency(DependencyType::Singleton).unwrap()
+    ///     .build();
+    ///
+    /// assert_eq!(sbatch.unwrap(), "sbatch --dependency=afterok:1,singleton");
+    /// ```
+    pub fn add_dependency(
+        &mut self,
+        dependency_type: DependencyType,
+    ) -> Result<&mut Self, SbatchError> {
+        let mut dependency = match self.get_option(&SbatchOption::Dependency(String::new())) {
+            Some(SbatchOption::Dependency(existing)) => existing.parse()?,
+            _ => Dependency::new_and(),
+        };
+        dependency.push(dependency_type)?.dedup();
+        self.set_dependency(dependency)
+    }
+
+    /// Sets the `--wrap` option to run several commands in sequence.
+    ///
+    /// The commands are joined with `; `, so every command runs regardless of the exit status of the previous one.
+    /// Use `set_wrap_commands_with` to choose a different separator. Any existing `--wrap` option is replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `cmds` - The commands to run. Empty commands are ignored.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a mutable reference to the `Sbatch` instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchError` if there are no non-empty commands, or `SbatchError::WrapAndScript`
+    /// if a script has already been set, as `set_wrap` does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::Sbatch;
+    ///
+    /// let sbatch = Sbatch::new()
+    ///     .set_wrap_commands(&["module load python", "python main.py"]).unwrap()
+    ///     .build();
+    ///
+    /// assert_eq!(sbatch.unwrap(), "sbatch --wrap='module load python; python main.py'");
+    /// ```
+    pub fn set_wrap_commands(&mut self, cmds: &[&str]) -> Result<&mut Self, SbatchError> {
+        self.set_wrap_commands_with(cmds, "; ")
+    }
+
+    /// Sets the `--wrap` option to run several commands joined by a custom separator.
+    ///
+    /// Any existing `--wrap` option is replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `cmds` - The commands to run. Empty commands are ignored.
+    /// * `separator` - The separator placed between the commands, e.g. `" && "` to stop at the first failure.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a mutable reference to the `Sbatch` instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchError` if there are no non-empty commands, or `SbatchError::WrapAndScript`
+    /// if a script has already been set, as `set_wrap` does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::Sbatch;
+    ///
+    /// let sbatch = Sbatch::new()
+    ///     .set_wrap_commands_with(&["cd data", "./run.sh"], " && ").unwrap()
+    ///     .build();
+    ///
+    /// assert_eq!(sbatch.unwrap(), "sbatch --wrap='cd data && ./run.sh'");
+    /// ```
+    pub fn set_wrap_commands_with(
+        &mut self,
+        cmds: &[&str],
+        separator: &str,
+    ) -> Result<&mut Self, SbatchError> {
+        let wrap = cmds
+            .iter()
+            .map(|cmd| cmd.trim())
+            .filter(|cmd| !cmd.is_empty())
+            .collect::<Vec<_>>()
+            .join(separator);
+
+        // Unlike a bare `--wrap=""`, an empty list of commands is a mistake
+        if wrap.is_empty() {
+            return Err(SbatchOptionError::EmptyString.into());
+        }
+        self.set_wrap(wrap)
+    }
+
+    /// Checks the option set for combinations that Slurm would reject.
+    ///
+    /// Each option is also validated on its own, which covers options collected with `FromIterator`.
+    /// Use `validate_all` to get every error instead of the first one.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchError::SbatchOptionError` if an option is invalid.
+    /// This function returns `SbatchError::ConflictingOptions` for the first pair of mutually exclusive options,
+    /// or if `--extra-node-info` disagrees with `--sockets-per-node`, `--cores-per-socket`, or `--threads-per-core`.
+    /// It returns `SbatchError::InconsistentTaskLayout` if `--ntasks`, `--nodes`, and `--ntasks-per-node` are
+    /// all plain numbers and there are more tasks than the nodes can hold.
+    /// It returns `SbatchError::WrapAndScript` if both a script and the `--wrap` option are set, and
+    /// `SbatchError::MultiLineScript` if the script is a multi-line body, such as one read by `from_script`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Sbatch, SbatchError, SbatchOption};
+    ///
+    /// let mut sbatch = Sbatch::new();
+    /// sbatch
+    ///     .add_option(SbatchOption::Exclusive(None)).unwrap()
+    ///     .add_option(SbatchOption::Oversubscribe).unwrap();
+    ///
+    /// assert!(matches!(sbatch.validate(), Err(SbatchError::ConflictingOptions(_, _))));
+    /// ```
+    pub fn validate(&self) -> Result<(), SbatchError> {
+        self.validate_all().map_err(|mut errors| errors.remove(0))
+    }
+
+    /// Checks the option set like `validate`, but reports every error instead of stopping at the first one.
+    ///
+    /// This is useful for showing a user everything that needs fixing in one pass.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the errors described in `validate`, in the order `validate` checks for them.
+    /// The returned list is never empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Sbatch, SbatchError, SbatchOption};
+    ///
+    /// let sbatch: Sbatch = [
+    ///     SbatchOption::JobName(" test".to_string()),
+    ///     SbatchOption::Exclusive(None),
+    ///     SbatchOption::Oversubscribe,
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// let errors = sbatch.validate_all().unwrap_err();
+    /// assert_eq!(errors.len(), 2);
+    /// assert!(matches!(errors[0], SbatchError::SbatchOptionError(_)));
+    /// assert!(matches!(errors[1], SbatchError::ConflictingOptions(_, _)));
+    /// ```
+    pub fn validate_all(&self) -> Result<(), Vec<SbatchError>> {
+        let mut errors = Vec::new();
+
+        // Check for a script, or a script read from stdin, alongside the wrap option
+        if (self.script.is_some() || self.script_from_stdin) && self.has_wrap() {
+            errors.push(SbatchError::WrapAndScript);
+        }
+
+        // Check for a script body, which can only be rendered with `to_script`, not passed as a script path
+        if self
+            .script
+            .as_ref()
+            .is_some_and(|script| script.contains('\n'))
+        {
+            errors.push(SbatchError::MultiLineScript);
+        }
+
+        // Check each component of the job separately
+        for options in self.het_groups.iter().chain(&self.sbatch_options) {
+            errors.extend(
+                options
+                    .iter()
+                    .filter_map(|option| option.validate().err())
+                    .map(SbatchError::from),
+            );
+
+            // Check each known conflicting pair
+            for (first, second) in CONFLICTING_OPTIONS {
+                if let (Some(a), Some(b)) = (
+                    options.iter().find(|o| first(o)),
+                    options.iter().find(|o| second(o)),
+                ) {
+                    errors.push(SbatchError::ConflictingOptions(a.clone(), b.clone()));
+                }
+            }
+
+            // Check the node topology options against each other
+            if let Some((a, b)) = topology_conflict(options) {
+                errors.push(SbatchError::ConflictingOptions(a, b));
+            }
+
+            // Check that the tasks fit on the nodes
+            if let Some(description) = task_layout_conflict(options) {
+                errors.push(SbatchError::InconsistentTaskLayout(description));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Checks the `--time` option against a maximum wall time, such as a partition's limit.
+    ///
+    /// The limit is supplied by the caller; it is not looked up from Slurm.
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - The maximum time limit allowed.
+    ///
+    /// # Errors
+    ///
+    /// This function returns `SbatchError::TimeLimitExceeded` if `--time` is set and exceeds `limit`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Sbatch, SbatchError, SbatchOption};
+    ///
+    /// let mut sbatch = Sbatch::new();
+    /// sbatch.add_option(SbatchOption::Time("3-00:00:00".parse().unwrap())).unwrap();
+    ///
+    /// let limit = "2-00:00:00".parse().unwrap();
+    /// assert!(matches!(sbatch.check_time_limit(&limit), Err(SbatchError::TimeLimitExceeded { .. })));
+    /// ```
+    pub fn check_time_limit(&self, limit: &TimeLimit) -> Result<(), SbatchError> {
+        match self.get_option(&SbatchOption::Time(TimeLimit::unlimited())) {
+            Some(SbatchOption::Time(time)) if time.exceeds(limit) => {
+                Err(SbatchError::TimeLimitExceeded {
+                    time: *time,
+                    limit: *limit,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks the `--array` option against a maximum array size, such as the cluster's `MaxArraySize`.
+    ///
+    /// As in Slurm, every task id must be below the maximum array size, so a `MaxArraySize` of 1001 allows
+    /// task ids up to 1000. The maximum is supplied by the caller; it is not looked up from Slurm.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_array_size` - The maximum array size allowed.
+    ///
+    /// # Errors
+    ///
+    /// This function returns `SbatchError::ArraySizeExceeded` if `--array` is set and its highest task id is
+    /// not below `max_array_size`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Sbatch, SbatchError, SbatchOption};
+    ///
+    /// let mut sbatch = Sbatch::new();
+    /// sbatch.add_option(SbatchOption::Array("0-1000".parse().unwrap())).unwrap();
+    ///
+    /// assert!(sbatch.check_array_size(1001).is_ok());
+    /// assert!(matches!(sbatch.check_array_size(1000), Err(SbatchError::ArraySizeExceeded { .. })));
+    /// ```
+    pub fn check_array_size(&self, max_array_size: u32) -> Result<(), SbatchError> {
+        match self.get_option(&SbatchOption::Array("0".parse()?)) {
+            Some(SbatchOption::Array(array)) if array.max_index() >= max_array_size => {
+                Err(SbatchError::ArraySizeExceeded {
+                    index: array.max_index(),
+                    max_array_size,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks that the tasks of an array job do not append to a shared output or error file.
+    ///
+    /// With `--open-mode=append`, an `--output` or `--error` pattern without the `%a` array task id makes every
+    /// task of the array append to the same file. This is advisory and is not part of `validate`, since a shared
+    /// file is occasionally intended. Slurm's default output pattern, `slurm-%A_%a.out`, is task-specific.
+    ///
+    /// # Errors
+    ///
+    /// This function returns `SbatchError::SharedArrayOutput` with the offending option if `--array` and
+    /// `--open-mode=append` are set and `--output` or `--error` has no `%a`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{OpenMode, Sbatch, SbatchError, SbatchOption};
+    ///
+    /// let mut sbatch = Sbatch::new();
+    /// sbatch
+    ///     .add_option(SbatchOption::Array("1-10".parse().unwrap())).unwrap()
+    ///     .add_option(SbatchOption::OpenMode(OpenMode::Append)).unwrap()
+    ///     .add_option(SbatchOption::Output("job-%A.out".to_string())).unwrap();
+    /// assert!(matches!(sbatch.check_array_output(), Err(SbatchError::SharedArrayOutput(_))));
+    ///
+    /// sbatch.remove_option(&SbatchOption::Output(String::new()));
+    /// sbatch.add_option(SbatchOption::Output("job-%A_%a.out".to_string())).unwrap();
+    /// assert!(sbatch.check_array_output().is_ok());
+    /// ```
+    pub fn check_array_output(&self) -> Result<(), SbatchError> {
+        let appends = self
+            .options()
+            .any(|option| option == &SbatchOption::OpenMode(OpenMode::Append));
+        if !appends
+            || self
+                .get_option(&SbatchOption::Array("0".parse()?))
+                .is_none()
+        {
+            return Ok(());
+        }
+
+        match self.options().find(|option| match option {
+            SbatchOption::Output(pattern) | SbatchOption::Error(pattern) => {
+                !has_array_task_pattern(pattern)
+            }
+            _ => false,
+        }) {
+            Some(option) => Err(SbatchError::SharedArrayOutput(option.clone())),
+            None => Ok(()),
+        }
+    }
+
+    // Helper function to add an option unless an identical one is already present
+    fn insert_option(&mut self, option: SbatchOption) {
+        let options = self.sbatch_options.get_or_insert_with(Vec::new);
+        if !options.contains(&option) {
+            options.push(option);
+        }
+    }
+
+    // Helper function to replace an option of the same variant in place, or add it at the end
+    fn replace_option(&mut self, option: SbatchOption) {
+        let options = self.sbatch_options.get_or_insert_with(Vec::new);
+        match options.iter_mut().find(|o| o.is_same_variant(&option)) {
+            Some(existing) => *existing = option,
+            None => options.push(option),
+        }
+    }
+
+    // Helper function to get the options of the active component in the order they should be emitted
+    fn ordered_options(&self) -> Vec<&SbatchOption> {
+        self.order_group(self.sbatch_options.as_deref().unwrap_or_default())
+    }
+
+    // Helper function to get the options of a component in the order they should be emitted
+    fn order_group<'a>(&self, options: &'a [SbatchOption]) -> Vec<&'a SbatchOption> {
+        match self.order {
+            OrderMode::Sorted => options
+                .iter()
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect(),
+            OrderMode::InsertionOrder => options.iter().collect(),
+        }
+    }
+
+    // Helper function to get every component of a heterogeneous job, each in emission order.
+    // For a regular job this is just the active component, or nothing if no options were added.
+    fn ordered_groups(&self) -> Vec<Vec<&SbatchOption>> {
+        self.het_groups
+            .iter()
+            .chain(&self.sbatch_options)
+            .map(|options| self.order_group(options))
+            .collect()
+    }
+
+    // Helper function to check whether a `--wrap` option is set
+    fn has_wrap(&self) -> bool {
+        self.sbatch_options
+            .iter()
+            .flatten()
+            .any(|option| matches!(option, SbatchOption::Wrap(_)))
+    }
+
+    /// Builds the `sbatch` command.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a string representing the `sbatch` command.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchError` if no options or script are provided, or if `validate` fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Sbatch, SbatchOption};
+    ///
+    /// // Create a new `Sbatch` instance
+    /// let sbatch = Sbatch::new()
+    ///     .add_option(SbatchOption::JobName("test".to_string())).unwrap()
+    ///     .add_option(SbatchOption::Output("test.out".to_string())).unwrap()
+    ///     .add_option(SbatchOption::Error("test.err".to_string())).unwrap()
+    ///     .set_script("test.sh".to_string()).unwrap()
+    ///     .build();
+    ///     
+    /// // Verify that the `sbatch` command was built properly
+    /// assert!(sbatch.is_ok());
+    /// assert_eq!(sbatch.unwrap(), "sbatch --error=test.err --job-name=test --output=test.out test.sh");
+    pub fn build(&self) -> Result<String, SbatchError> {
+        Ok(self.command_parts()?.join(" "))
+    }
+
+    /// Builds the `sbatch` command split across lines with backslash continuations.
+    ///
+    /// `sbatch` is on the first line, followed by each option on its own indented line, and the script on the
+    /// last line. Every line but the last ends with ` \`, so the output is still a single shell command, with
+    /// the same options in the same order as `build`.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a string representing the `sbatch` command.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchError` if no options or script are provided, or if `validate` fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Sbatch, SbatchOption};
+    ///
+    /// let sbatch = Sbatch::new()
+    ///     .add_option(SbatchOption::JobName("test".to_string())).unwrap()
+    ///     .add_option(SbatchOption::Output("test.out".to_string())).unwrap()
+    ///     .set_script("test.sh".to_string()).unwrap()
+    ///     .build_multiline();
+    ///
+    /// assert_eq!(
+    ///     sbatch.unwrap(),
+    ///     "sbatch \\\n    --job-name=test \\\n    --output=test.out \\\n    test.sh"
+    /// );
+    /// ```
+    pub fn build_multiline(&self) -> Result<String, SbatchError> {
+        Ok(self.command_parts()?.join(" \\\n    "))
+    }
+
+    /// Returns a structured description of each option, as an alternative to the flat command string.
+    ///
+    /// This is useful for rendering the options in a form. The options are listed in the order `build`
+    /// emits them, including those of earlier heterogeneous job components. The options are not validated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{OptionKind, Sbatch, SbatchOption};
+    ///
+    /// let specs = Sbatch::new()
+    ///     .add_option(SbatchOption::JobName("test".to_string())).unwrap()
+    ///     .add_option(SbatchOption::Hold).unwrap()
+    ///     .to_spec();
+    ///
+    /// assert_eq!(specs[0].name, "hold");
+    /// assert_eq!(specs[0].kind, OptionKind::Flag);
+    /// assert_eq!(specs[1].name, "job-name");
+    /// assert_eq!(specs[1].value.as_deref(), Some("test"));
+    /// ```
+    pub fn to_spec(&self) -> Vec<OptionSpec> {
+        self.ordered_groups()
+            .into_iter()
+            .flatten()
+            .map(SbatchOption::to_spec)
+            .collect()
+    }
+
+    /// Returns a normalized representation of the job, suitable for hashing to detect duplicate jobs.
+    ///
+    /// Unlike `build`, this does not depend on the order mode or the order in which options were added:
+    /// the options of each heterogeneous job component are sorted by flag name, and a `--dependency` is
+    /// replaced by its canonical key. Each option is on its own line, components are separated by a `:`
+    /// line, and the script is on a last line after `--` (`-` when it is read from standard input).
+    /// The options are not validated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{OrderMode, Sbatch, SbatchOption};
+    ///
+    /// let a: Sbatch = "sbatch --job-name=test --dependency=afterok:2:1 test.sh".parse().unwrap();
+    ///
+    /// let mut b = Sbatch::new();
+    /// b.with_order(OrderMode::InsertionOrder)
+    ///     .add_option(SbatchOption::Dependency("afterok:1,afterok:2".to_string())).unwrap()
+    ///     .add_option(SbatchOption::JobName("test".to_string())).unwrap()
+    ///     .set_script("test.sh".to_string()).unwrap();
+    ///
+    /// assert_eq!(a.canonical_string(), b.canonical_string());
+    /// assert_eq!(a.canonical_string(), "--dependency=afterok:1,afterok:2\n--job-name=test\n-- test.sh");
+    /// ```
+    pub fn canonical_string(&self) -> String {
+        let groups = self
+            .het_groups
+            .iter()
+            .chain(&self.sbatch_options)
+            .filter(|options| !options.is_empty());
+
+        let mut lines = Vec::new();
+        for (index, options) in groups.enumerate() {
+            if index > 0 {
+                lines.push(":".to_string());
+            }
+            let options = options
+                .iter()
+                .map(canonical_option)
+                .collect::<BTreeSet<_>>();
+            lines.extend(options.iter().map(SbatchOption::to_string));
+        }
+        if let Some(script) = &self.script {
+            lines.push(format!("-- {script}"));
+        } else if self.script_from_stdin {
+            lines.push("-- -".to_string());
+        }
+        lines.join("\n")
+    }
+
+    // Helper function to validate the command and split it into `sbatch`, each option, and the script.
+    // Heterogeneous job components are separated by a `:` part.
+    fn command_parts(&self) -> Result<Vec<String>, SbatchError> {
+        self.validate()?;
+
+        let mut parts = vec!["sbatch".to_string()];
+        for (index, group) in self.ordered_groups().iter().enumerate() {
+            if index > 0 {
+                parts.push(":".to_string());
+            }
+            parts.extend(group.iter().map(|o| o.to_string()));
+        }
+        parts.extend(self.script.clone());
+
+        if parts.len() == 1 {
+            Err(SbatchError::NoOptionsOrScript)
+        } else {
+            Ok(parts)
+        }
+    }
+
+    /// Builds an `sbatch` command from a complete set of options and an optional script in one call.
+    ///
+    /// This is a shorthand for adding each option with `add_option`, setting the script with `set_script`,
+    /// and calling `build`, for callers that have every input up front.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The sbatch options.
+    /// * `script` - The script and its arguments, if any.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a string representing the `sbatch` command.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchError` if an option or the script is invalid, or if the options
+    /// conflict as checked by `validate`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Sbatch, SbatchError, SbatchOption};
+    ///
+    /// let command = Sbatch::try_build(
+    ///     [SbatchOption::JobName("test".to_string()), SbatchOption::Mem("4G".parse().unwrap())],
+    ///     Some("test.sh".to_string()),
+    /// );
+    /// assert_eq!(command.unwrap(), "sbatch --job-name=test --mem=4G test.sh");
+    ///
+    /// let command = Sbatch::try_build(
+    ///     [SbatchOption::Mem("4G".parse().unwrap()), SbatchOption::MemPerCPU("1G".parse().unwrap())],
+    ///     None,
+    /// );
+    /// assert!(matches!(command, Err(SbatchError::ConflictingOptions(..))));
+    /// ```
+    pub fn try_build(
+        options: impl IntoIterator<Item = SbatchOption>,
+        script: Option<String>,
+    ) -> Result<String, SbatchError> {
+        let mut sbatch = Sbatch::new();
+        for option in options {
+            sbatch.add_option(option)?;
+        }
+        if let Some(script) = script {
+            sbatch.set_script(script)?;
+        }
+        sbatch.build()
+    }
+}
+
+impl FromIterator<SbatchOption> for Sbatch {
+    /// Collects options into a new `Sbatch` instance.
+    ///
+    /// A later option replaces an earlier one of the same variant. The options are not validated
+    /// until `validate` or `build` is called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Sbatch, SbatchOption};
+    ///
+    /// let sbatch: Sbatch = vec![
+    ///     SbatchOption::Partition("batch".to_string()),
+    ///     SbatchOption::JobName("test".to_string()),
+    ///     SbatchOption::Partition("gpu".to_string()),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// assert_eq!(sbatch.len(), 2);
+    /// assert_eq!(sbatch.build().unwrap(), "sbatch --job-name=test --partition=gpu");
+    /// ```
+    fn from_iter<I: IntoIterator<Item = SbatchOption>>(iter: I) -> Self {
+        let mut sbatch = Sbatch::new();
+        for option in iter {
+            sbatch.replace_option(option);
+        }
+        sbatch
+    }
+}
+
+impl IntoIterator for Sbatch {
+    type Item = SbatchOption;
+    type IntoIter = std::vec::IntoIter<SbatchOption>;
+
+    /// Consumes the `Sbatch` instance, returning its options in the order they are emitted by `build`.
+    ///
+    /// Only the options of the active component of a heterogeneous job are returned.
+    fn into_iter(self) -> Self::IntoIter {
+        let options = self.sbatch_options.unwrap_or_default();
+        match self.order {
+            OrderMode::Sorted => options
+                .into_iter()
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect::<Vec<_>>()
+                .into_iter(),
+            OrderMode::InsertionOrder => options.into_iter(),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Sbatch {
+    type Item = &'a SbatchOption;
+    type IntoIter = std::vec::IntoIter<&'a SbatchOption>;
+
+    /// Returns an iterator over the options, in the order they are emitted by `build`.
+    fn into_iter(self) -> Self::IntoIter {
+        self.ordered_options().into_iter()
+    }
+}
+
+impl Default for Sbatch {
+    /// Creates a default `Sbatch` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::Sbatch;
+    ///
+    /// // Create a default `Sbatch` instance
+    /// let sbatch = Sbatch::default();
+    /// ```
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Helper function to get the option groups in emission order, without the empty ones
+fn non_empty_groups(sbatch: &Sbatch) -> Vec<Vec<&SbatchOption>> {
+    sbatch
+        .ordered_groups()
+        .into_iter()
+        .filter(|options| !options.is_empty())
+        .collect()
+}
+
+impl PartialEq for Sbatch {
+    /// Compares two `Sbatch` instances by the command they describe.
+    ///
+    /// With `OrderMode::Sorted`, the order in which options were added does not matter, as it does not
+    /// change the output. With `OrderMode::InsertionOrder`, it does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Sbatch, SbatchOption};
+    ///
+    /// let a = Sbatch::new()
+    ///     .option(SbatchOption::JobName("test".to_string())).unwrap()
+    ///     .option(SbatchOption::Partition("gpu".to_string())).unwrap();
+    /// let b = Sbatch::new()
+    ///     .option(SbatchOption::Partition("gpu".to_string())).unwrap()
+    ///     .option(SbatchOption::JobName("test".to_string())).unwrap();
+    ///
+    /// assert_eq!(a, b);
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        self.order == other.order
+            && self.script == other.script
+            && self.script_from_stdin == other.script_from_stdin
+            && non_empty_groups(self) == non_empty_groups(other)
+    }
+}
+
+impl Eq for Sbatch {}
@@ -0,0 +1,189 @@
+//! Process execution for `Sbatch`
+
+use std::process::Command;
+
+use super::{Sbatch, SbatchError, tokenize_command_line};
+use crate::{DisplayStyle, SbatchOption};
+
+// Helper function to convert an option into a single process argument.
+// No shell is involved, so values are passed without the quotes used by `Display`.
+fn option_to_arg(option: &SbatchOption) -> String {
+    option.display_with(DisplayStyle::Unquoted)
+}
+
+// Helper function to parse the job id from the stdout of `sbatch`.
+// With `--parsable` the output is `<job_id>[;<cluster>]`, otherwise it is `Submitted batch job <job_id>`.
+fn parse_job_id(stdout: &str, parsable: bool) -> Result<u32, SbatchError> {
+    let job_id = if parsable {
+        stdout.trim().split(';').next()
+    } else {
+        stdout
+            .lines()
+            .find_map(|line| line.trim().strip_prefix("Submitted batch job"))
+            .map(|rest| rest.split_whitespace().next().unwrap_or_default())
+    };
+
+    job_id
+        .and_then(|job_id| job_id.trim().parse().ok())
+        .ok_or_else(|| {
+            SbatchError::SbatchExecutionError(format!(
+                "Unable to parse job id from output: {}",
+                stdout.trim()
+            ))
+        })
+}
+
+/// Runs a tokenized `sbatch` command and returns its stdout.
+///
+/// `Sbatch::submit` uses `CommandSubmitter`, which runs the command as a process. Tests can implement
+/// this trait to return canned output, so the submission path can be exercised without Slurm installed.
+///
+/// # Examples
+///
+/// ```
+/// use sbatch_rs::{MockSubmitter, Sbatch, SbatchError};
+///
+/// struct FixedSubmitter;
+///
+/// impl MockSubmitter for FixedSubmitter {
+///     fn submit(&self, _command: &[String]) -> Result<String, SbatchError> {
+///         Ok("Submitted batch job 42\n".to_string())
+///     }
+/// }
+///
+/// let job_id = Sbatch::new()
+///     .set_script("test.sh".to_string()).unwrap()
+///     .submit_with_submitter(&FixedSubmitter);
+/// assert_eq!(job_id.unwrap(), 42);
+/// ```
+pub trait MockSubmitter {
+    /// Runs the command, given as the program followed by its arguments, and returns its stdout.
+    fn submit(&self, command: &[String]) -> Result<String, SbatchError>;
+}
+
+/// Submitter that runs the command with `std::process::Command`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CommandSubmitter;
+
+impl MockSubmitter for CommandSubmitter {
+    fn submit(&self, command: &[String]) -> Result<String, SbatchError> {
+        let (program, args) = command
+            .split_first()
+            .ok_or(SbatchError::NoOptionsOrScript)?;
+        let output = Command::new(program)
+            .args(args)
+            .output()
+            .map_err(|e| SbatchError::SbatchExecutionError(e.to_string()))?;
+
+        if !output.status.success() {
+            return Err(SbatchError::SbatchExecutionError(
+                String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            ));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+}
+
+impl Sbatch {
+    // Helper function to validate the command and get its program and arguments, as `build` does.
+    fn to_args(&self) -> Result<Vec<String>, SbatchError> {
+        self.validate()?;
+        if self.option_count() == 0 && self.script.is_none() {
+            return Err(SbatchError::NoOptionsOrScript);
+        }
+
+        let mut args = vec!["sbatch".to_string()];
+        for (index, group) in self.ordered_groups().into_iter().enumerate() {
+            if index > 0 {
+                args.push(":".to_string());
+            }
+            args.extend(group.into_iter().map(option_to_arg));
+        }
+        if let Some(script) = &self.script {
+            args.extend(tokenize_command_line(script)?);
+        }
+        Ok(args)
+    }
+
+    /// Creates a `std::process::Command` that runs the `sbatch` command.
+    ///
+    /// Each option is passed as a separate argument, so no shell quoting is required.
+    /// The script is split into the script path and its arguments using shell quoting rules.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a `Command` with the program set to `sbatch`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchError` if no options or script are provided, if the options
+    /// conflict as checked by `validate`, or if the script has unbalanced quotes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Sbatch, SbatchOption};
+    ///
+    /// let command = Sbatch::new()
+    ///     .add_option(SbatchOption::JobName("test".to_string())).unwrap()
+    ///     .add_option(SbatchOption::Wrap("echo hello".to_string())).unwrap()
+    ///     .to_command()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(command.get_program(), "sbatch");
+    /// assert_eq!(
+    ///     command.get_args().collect::<Vec<_>>(),
+    ///     vec!["--job-name=test", "--wrap=echo hello"]
+    /// );
+    /// ```
+    pub fn to_command(&self) -> Result<Command, SbatchError> {
+        let args = self.to_args()?;
+        let mut command = Command::new(&args[0]);
+        command.args(&args[1..]);
+        Ok(command)
+    }
+
+    /// Submits the job by running the `sbatch` command.
+    ///
+    /// # Returns
+    ///
+    /// This function returns the job id reported by `sbatch`.
+    /// Both the default output and the `--parsable` output are supported.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchError` if the options conflict as checked by `validate`, and a
+    /// `SbatchError::SbatchExecutionError` if `sbatch` cannot be run,
+    /// exits with a non-zero status (the error contains the captured stderr),
+    /// or its output does not contain a job id.
+    pub fn submit(&self) -> Result<u32, SbatchError> {
+        self.submit_with_submitter(&CommandSubmitter)
+    }
+
+    /// Submits the job using the given submitter.
+    ///
+    /// # Arguments
+    ///
+    /// * `submitter` - The `MockSubmitter` that runs the command, e.g. `CommandSubmitter` or a test double.
+    ///
+    /// # Returns
+    ///
+    /// This function returns the job id parsed from the submitter's output.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchError` if no options or script are provided, the options conflict
+    /// as checked by `validate`, the submitter fails, or its output does not contain a job id. The
+    /// submitter is not run when the options are invalid.
+    pub fn submit_with_submitter(
+        &self,
+        submitter: &impl MockSubmitter,
+    ) -> Result<u32, SbatchError> {
+        let stdout = submitter.submit(&self.to_args()?)?;
+        let parsable = self
+            .sbatch_options
+            .as_ref()
+            .is_some_and(|options| options.contains(&SbatchOption::Parsable));
+        parse_job_id(&stdout, parsable)
+    }
+}
@@ -0,0 +1,307 @@
+//! Job script rendering for `Sbatch`
+
+use super::{Sbatch, SbatchError};
+use crate::{ParseOptions, SbatchOption, SbatchOptionError, UnknownPolicy};
+
+impl Sbatch {
+    /// Creates a `Sbatch` instance from the `#SBATCH` directives of an existing job script.
+    ///
+    /// Directives are read from the leading comment block of the script. As with Slurm, parsing
+    /// stops at the first line that is neither blank nor a comment, and everything from that line
+    /// onwards is stored as the script body. A `#SBATCH hetjob` line starts the next component of a
+    /// heterogeneous job. Deprecated flags, such as `--workdir`, are parsed as the flag replacing them
+    /// and reported by `warnings`. The body is meant to be rendered with `to_script`, since `build`
+    /// rejects a multi-line body with `SbatchError::MultiLineScript`.
+    ///
+    /// # Arguments
+    ///
+    /// * `contents` - The contents of the job script.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a `Sbatch` instance populated with the parsed options and body.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchError::InvalidDirective` identifying the offending line
+    /// if a directive cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::Sbatch;
+    ///
+    /// let contents = "#!/bin/bash\n#SBATCH --job-name=test\n#SBATCH -o test.out\n\nsrun hostname\n";
+    /// let sbatch = Sbatch::from_script(contents).unwrap();
+    ///
+    /// assert_eq!(
+    ///     sbatch.to_script("#!/bin/bash").unwrap(),
+    ///     "#!/bin/bash\n#SBATCH --job-name=test\n#SBATCH --output=test.out\n\nsrun hostname\n"
+    /// );
+    /// ```
+    pub fn from_script(contents: &str) -> Result<Sbatch, SbatchError> {
+        Sbatch::from_script_with(contents, &ParseOptions::strict()).map(|(sbatch, _)| sbatch)
+    }
+
+    /// Creates a `Sbatch` instance from the `#SBATCH` directives of an existing job script, using custom parse options.
+    ///
+    /// Each directive is parsed with `SbatchOption::from_str_with`. A directive with an unknown option is handled
+    /// according to `options.on_unknown`, which lets a migration tool report what it could not handle instead of
+    /// aborting. Invalid values for known options are always an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `contents` - The contents of the job script.
+    /// * `options` - The `ParseOptions` controlling how the directives are parsed.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a `Sbatch` instance populated with the parsed options and body, along with the
+    /// trimmed lines of the skipped directives when `options.on_unknown` is `UnknownPolicy::Collect`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchError::InvalidDirective` identifying the offending line
+    /// if a directive cannot be parsed and is not skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{ParseOptions, Sbatch, UnknownPolicy};
+    ///
+    /// let contents = "#!/bin/bash\n#SBATCH --job-name=test\n#SBATCH --newfangled-flag=x\n\nsrun hostname\n";
+    /// let options = ParseOptions { on_unknown: UnknownPolicy::Collect, ..ParseOptions::strict() };
+    /// let (sbatch, unknown) = Sbatch::from_script_with(contents, &options).unwrap();
+    ///
+    /// assert_eq!(sbatch.to_directives(), vec!["#SBATCH --job-name=test"]);
+    /// assert_eq!(unknown, vec!["#SBATCH --newfangled-flag=x"]);
+    /// assert!(Sbatch::from_script(contents).is_err());
+    /// ```
+    pub fn from_script_with(
+        contents: &str,
+        options: &ParseOptions,
+    ) -> Result<(Sbatch, Vec<String>), SbatchError> {
+        let mut sbatch = Sbatch::new();
+        let mut unknown = Vec::new();
+        let lines: Vec<&str> = contents.lines().collect();
+
+        // Collect directives until the first command line
+        let mut body_start = lines.len();
+        for (index, line) in lines.iter().enumerate() {
+            let line = line.trim();
+            if let Some(directive) = line
+                .strip_prefix("#SBATCH")
+                .filter(|d| d.starts_with(char::is_whitespace))
+            {
+                let directive = directive.trim();
+                // A `hetjob` directive starts the next component of a heterogeneous job
+                if directive == "hetjob" {
+                    sbatch.add_het_group();
+                    continue;
+                }
+                let option = match SbatchOption::from_str_with(directive, options) {
+                    Err(SbatchOptionError::UnknownArgument(_))
+                        if matches!(
+                            options.on_unknown,
+                            UnknownPolicy::Skip | UnknownPolicy::Collect
+                        ) =>
+                    {
+                        if options.on_unknown == UnknownPolicy::Collect {
+                            unknown.push(line.to_string());
+                        }
+                        continue;
+                    }
+                    result => result.map_err(|source| SbatchError::InvalidDirective {
+                        line: index + 1,
+                        directive: directive.to_string(),
+                        source,
+                    })?,
+                };
+                sbatch.overwrite_option(option)?;
+                sbatch
+                    .warnings
+                    .extend(SbatchOption::deprecation_warning(directive));
+            } else if !line.is_empty() && !line.starts_with('#') {
+                body_start = index;
+                break;
+            }
+        }
+
+        // Everything after the directives is the script body
+        let body = lines[body_start..].join("\n");
+        if !body.trim().is_empty() {
+            sbatch.set_script(body)?;
+        }
+
+        Ok((sbatch, unknown))
+    }
+
+    /// Creates a `Sbatch` instance from a heredoc-style job definition.
+    ///
+    /// This is intended for job definitions embedded in configuration files, where the whole
+    /// block is usually indented. The common leading indentation is removed before the block is
+    /// parsed with `from_script`, so `#SBATCH` directives set the options and the remaining lines
+    /// become the script body. A script rendered by `to_script` parses back to the same options and
+    /// heterogeneous job components, but a body starting with a comment line is read as part of the
+    /// directive block, so those leading comments are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `heredoc` - The job definition.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a `Sbatch` instance populated with the parsed options and body.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchError::InvalidDirective` if a directive cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::Sbatch;
+    ///
+    /// let heredoc = "
+    ///     #SBATCH --job-name=test
+    ///     #SBATCH --ntasks=4
+    ///
+    ///     srun hostname
+    ///       echo done
+    /// ";
+    /// let sbatch = Sbatch::from_heredoc(heredoc).unwrap();
+    ///
+    /// assert_eq!(
+    ///     sbatch.to_script("#!/bin/bash").unwrap(),
+    ///     "#!/bin/bash\n#SBATCH --job-name=test\n#SBATCH --ntasks=4\n\nsrun hostname\n  echo done\n"
+    /// );
+    /// ```
+    pub fn from_heredoc(heredoc: &str) -> Result<Sbatch, SbatchError> {
+        // Find the indentation shared by every non-blank line
+        let indent = heredoc
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.len() - line.trim_start().len())
+            .min()
+            .unwrap_or(0);
+
+        let dedented = heredoc
+            .lines()
+            .map(|line| line.get(indent..).unwrap_or_else(|| line.trim_start()))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Sbatch::from_script(&dedented)
+    }
+
+    /// Renders the options as `#SBATCH <option>` directive lines.
+    ///
+    /// The options are in the same order as `build`, and heterogeneous job components are separated by
+    /// `#SBATCH hetjob` lines.
+    ///
+    /// # Returns
+    ///
+    /// This function returns one line per directive, without trailing newlines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Sbatch, SbatchOption};
+    ///
+    /// let directives = Sbatch::new()
+    ///     .add_option(SbatchOption::JobName("test".to_string())).unwrap()
+    ///     .add_option(SbatchOption::Output("test.out".to_string())).unwrap()
+    ///     .to_directives();
+    ///
+    /// assert_eq!(directives, vec!["#SBATCH --job-name=test", "#SBATCH --output=test.out"]);
+    /// ```
+    pub fn to_directives(&self) -> Vec<String> {
+        self.to_directives_with_prefix("#SBATCH")
+    }
+
+    /// Renders the options as directive lines with a custom prefix instead of `#SBATCH`.
+    ///
+    /// This is useful for wrappers that translate the options for other schedulers.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The directive prefix, e.g. `#PBS`. It is separated from each option by a space.
+    ///
+    /// # Returns
+    ///
+    /// This function returns one line per directive, without trailing newlines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Sbatch, SbatchOption};
+    ///
+    /// let directives = Sbatch::new()
+    ///     .add_option(SbatchOption::JobName("test".to_string())).unwrap()
+    ///     .to_directives_with_prefix("#CUSTOM");
+    ///
+    /// assert_eq!(directives, vec!["#CUSTOM --job-name=test"]);
+    /// ```
+    pub fn to_directives_with_prefix(&self, prefix: &str) -> Vec<String> {
+        let prefix = prefix.trim();
+        let mut lines = Vec::new();
+        for (index, group) in self.ordered_groups().iter().enumerate() {
+            if index > 0 {
+                lines.push(format!("{prefix} hetjob"));
+            }
+            lines.extend(group.iter().map(|o| format!("{prefix} {o}")));
+        }
+        lines
+    }
+
+    /// Renders the `Sbatch` instance as a job script with `#SBATCH` directive lines.
+    ///
+    /// The script starts with the shebang, followed by one `#SBATCH <option>` line per option
+    /// (in the same order as `build`, see `OrderMode`), a blank line, and finally the script body.
+    ///
+    /// # Arguments
+    ///
+    /// * `shebang` - The first line of the script, e.g. `#!/bin/bash`.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a string containing the job script.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchError` if no options or script are provided.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Sbatch, SbatchOption};
+    ///
+    /// let script = Sbatch::new()
+    ///     .add_option(SbatchOption::JobName("test".to_string())).unwrap()
+    ///     .add_option(SbatchOption::Output("test.out".to_string())).unwrap()
+    ///     .set_script("echo hello".to_string()).unwrap()
+    ///     .to_script("#!/bin/bash");
+    ///
+    /// assert_eq!(
+    ///     script.unwrap(),
+    ///     "#!/bin/bash\n#SBATCH --job-name=test\n#SBATCH --output=test.out\n\necho hello\n"
+    /// );
+    /// ```
+    pub fn to_script(&self, shebang: &str) -> Result<String, SbatchError> {
+        if self.option_count() == 0 && self.script.is_none() {
+            return Err(SbatchError::NoOptionsOrScript);
+        }
+
+        // Shebang followed by one directive per option
+        let mut lines = vec![shebang.trim().to_string()];
+        lines.extend(self.to_directives());
+
+        // Blank line separating the directives from the body
+        if let Some(script) = &self.script {
+            lines.push(String::new());
+            lines.push(script.clone());
+        }
+
+        Ok(lines.join("\n") + "\n")
+    }
+}
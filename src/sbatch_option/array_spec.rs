@@ -0,0 +1,188 @@
+//! Typed array specification for the `--array` option
+
+use std::num::NonZeroU32;
+use std::str::FromStr;
+
+use super::SbatchOptionError;
+
+/// A single entry of an array specification: an index or an inclusive range with a step
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct ArrayRange {
+    start: u32,
+    end: u32,
+    step: u32,
+}
+
+/// Array specification
+///
+/// Represents the value of the `--array` option: a comma-separated list of single indices (`3`),
+/// inclusive ranges (`1-5`), and ranges with a step (`1-7:2`), optionally followed by a `%N`
+/// limit on the number of simultaneously running tasks (`0-15%4`).
+///
+/// # Examples
+///
+/// ```
+/// use sbatch_rs::ArraySpec;
+///
+/// let spec: ArraySpec = "1-7:2,10%2".parse().unwrap();
+/// assert_eq!(spec.indices().unwrap(), vec![1, 3, 5, 7, 10]);
+/// assert_eq!(spec.max_concurrent().map(|n| n.get()), Some(2));
+/// assert_eq!(spec.to_string(), "1-7:2,10%2");
+///
+/// assert!("1-".parse::<ArraySpec>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArraySpec {
+    ranges: Vec<ArrayRange>,
+    max_concurrent: Option<NonZeroU32>,
+}
+
+impl ArraySpec {
+    /// The default maximum number of task ids expanded by `indices`.
+    pub const DEFAULT_INDEX_LIMIT: usize = 100_000;
+
+    /// Returns the limit on the number of simultaneously running tasks (`%N`), if any.
+    pub fn max_concurrent(&self) -> Option<NonZeroU32> {
+        self.max_concurrent
+    }
+
+    /// Returns the highest task id in the specification.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::ArraySpec;
+    ///
+    /// let spec: ArraySpec = "1-8:3,5%2".parse().unwrap();
+    /// assert_eq!(spec.max_index(), 7);
+    /// ```
+    pub fn max_index(&self) -> u32 {
+        self.ranges
+            .iter()
+            .map(|range| range.end - (range.end - range.start) % range.step)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Expands the specification into the concrete task ids, in the order they were specified.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchOptionError::ArrayTooLarge` if the specification expands to
+    /// more than `ArraySpec::DEFAULT_INDEX_LIMIT` task ids.
+    pub fn indices(&self) -> Result<Vec<u32>, SbatchOptionError> {
+        self.indices_with_limit(Self::DEFAULT_INDEX_LIMIT)
+    }
+
+    /// Expands the specification into the concrete task ids, erroring past `limit` task ids.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchOptionError::ArrayTooLarge` if the specification expands to
+    /// more than `limit` task ids.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::ArraySpec;
+    ///
+    /// let spec: ArraySpec = "0-999".parse().unwrap();
+    /// assert_eq!(spec.indices_with_limit(1000).unwrap().len(), 1000);
+    /// assert!(spec.indices_with_limit(999).is_err());
+    /// ```
+    pub fn indices_with_limit(&self, limit: usize) -> Result<Vec<u32>, SbatchOptionError> {
+        let mut indices = Vec::new();
+        for range in &self.ranges {
+            for index in (range.start..=range.end).step_by(range.step as usize) {
+                if indices.len() == limit {
+                    return Err(SbatchOptionError::ArrayTooLarge(limit));
+                }
+                indices.push(index);
+            }
+        }
+        Ok(indices)
+    }
+}
+
+// Helper function to parse a single array entry such as `3`, `1-5`, or `1-7:2`.
+fn parse_range(s: &str) -> Option<ArrayRange> {
+    let parse = |s: &str| {
+        if s.is_empty() || !s.chars().all(|c| c.is_ascii_digit()) {
+            None
+        } else {
+            s.parse::<u32>().ok()
+        }
+    };
+
+    let (range, step) = match s.split_once(':') {
+        Some((range, step)) => (range, parse(step).filter(|step| *step > 0)?),
+        None => (s, 1),
+    };
+    let (start, end) = match range.split_once('-') {
+        Some((start, end)) => (parse(start)?, parse(end)?),
+        None if !s.contains(':') => (parse(range)?, parse(range)?),
+        None => return None,
+    };
+
+    (start <= end).then_some(ArrayRange { start, end, step })
+}
+
+impl FromStr for ArraySpec {
+    type Err = SbatchOptionError;
+
+    /// Parses an array specification such as `0-15%4` or `1,3,5-7`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SbatchOptionError::InvalidArraySpec(s.to_string());
+
+        let (list, max_concurrent) = match s.split_once('%') {
+            Some((list, limit)) => {
+                let limit = if !limit.is_empty() && limit.chars().all(|c| c.is_ascii_digit()) {
+                    limit.parse().ok().and_then(NonZeroU32::new)
+                } else {
+                    None
+                };
+                (list, Some(limit.ok_or_else(invalid)?))
+            }
+            None => (s, None),
+        };
+
+        let ranges = list
+            .split(',')
+            .map(parse_range)
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(invalid)?;
+
+        Ok(ArraySpec {
+            ranges,
+            max_concurrent,
+        })
+    }
+}
+
+impl std::fmt::Display for ArraySpec {
+    /// Displays the array specification in Slurm syntax.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ranges = self
+            .ranges
+            .iter()
+            .map(|range| match range {
+                ArrayRange { start, end, .. } if start == end => start.to_string(),
+                ArrayRange {
+                    start,
+                    end,
+                    step: 1,
+                } => format!("{start}-{end}"),
+                ArrayRange { start, end, step } => format!("{start}-{end}:{step}"),
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+
+        write!(f, "{}", ranges)?;
+        match self.max_concurrent {
+            Some(max_concurrent) => write!(f, "%{}", max_concurrent),
+            None => Ok(()),
+        }
+    }
+}
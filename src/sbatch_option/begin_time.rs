@@ -0,0 +1,513 @@
+//! Typed start time for the `--begin` option
+
+use std::str::FromStr;
+#[cfg(feature = "time")]
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::SbatchOptionError;
+
+/// Unit of a relative `now+<count><unit>` start time
+///
+/// When no unit is given, Slurm interprets the count as seconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BeginUnit {
+    Seconds,
+    Minutes,
+    Hours,
+    Days,
+    Weeks,
+}
+
+impl BeginUnit {
+    // Helper function to get the number of seconds in one unit.
+    #[cfg(feature = "time")]
+    fn seconds(&self) -> u64 {
+        match self {
+            BeginUnit::Seconds => 1,
+            BeginUnit::Minutes => 60,
+            BeginUnit::Hours => 60 * 60,
+            BeginUnit::Days => 24 * 60 * 60,
+            BeginUnit::Weeks => 7 * 24 * 60 * 60,
+        }
+    }
+
+    // Helper function to get the canonical name of the unit.
+    fn name(&self) -> &'static str {
+        match self {
+            BeginUnit::Seconds => "seconds",
+            BeginUnit::Minutes => "minutes",
+            BeginUnit::Hours => "hours",
+            BeginUnit::Days => "days",
+            BeginUnit::Weeks => "weeks",
+        }
+    }
+}
+
+impl FromStr for BeginUnit {
+    type Err = SbatchOptionError;
+
+    /// Parses a unit such as `hours`, `hour`, or `h` (case-insensitive).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "" | "s" | "sec" | "secs" | "second" | "seconds" => Ok(BeginUnit::Seconds),
+            "m" | "min" | "mins" | "minute" | "minutes" => Ok(BeginUnit::Minutes),
+            "h" | "hr" | "hrs" | "hour" | "hours" => Ok(BeginUnit::Hours),
+            "d" | "day" | "days" => Ok(BeginUnit::Days),
+            "w" | "week" | "weeks" => Ok(BeginUnit::Weeks),
+            _ => Err(SbatchOptionError::InvalidBeginTime(s.to_string())),
+        }
+    }
+}
+
+// Half of a 12-hour clock
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Meridiem {
+    Am,
+    Pm,
+}
+
+// Time of day, with seconds only when they were given, and a meridiem for a 12-hour clock time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct TimeOfDay {
+    hour: u8,
+    minute: u8,
+    second: Option<u8>,
+    meridiem: Option<Meridiem>,
+}
+
+impl TimeOfDay {
+    // Helper function to get the hour on a 24-hour clock.
+    #[cfg(feature = "time")]
+    fn hour24(&self) -> u8 {
+        match self.meridiem {
+            Some(Meridiem::Am) => self.hour % 12,
+            Some(Meridiem::Pm) => self.hour % 12 + 12,
+            None => self.hour,
+        }
+    }
+
+    // Helper function to get the number of seconds since midnight.
+    #[cfg(feature = "time")]
+    fn seconds(&self) -> u64 {
+        u64::from(self.hour24()) * 3600
+            + u64::from(self.minute) * 60
+            + u64::from(self.second.unwrap_or(0))
+    }
+}
+
+// The different forms of a start time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum BeginKind {
+    Now,
+    NowPlus(u32, BeginUnit),
+    Today,
+    Tomorrow,
+    Midnight,
+    Noon,
+    Elevenses,
+    Fika,
+    Teatime,
+    Time(TimeOfDay),
+    Date {
+        year: u16,
+        month: u8,
+        day: u8,
+        time: Option<TimeOfDay>,
+    },
+    ShortDate {
+        month: u8,
+        day: u8,
+        year: Option<u8>,
+    },
+}
+
+/// Begin time
+///
+/// Represents the value of the `--begin` option. The following forms are accepted:
+/// - `now` and `now+<count>[unit]`, where the unit is `seconds` (the default), `minutes`, `hours`, `days`, or `weeks`
+/// - the keywords `today`, `tomorrow`, `midnight`, `noon`, `elevenses` (11 AM), `fika` (3 PM), and `teatime` (4 PM)
+/// - a time of day, `HH:MM[:SS]`, or `H[H]:MM[:SS] AM` / `PM` on a 12-hour clock
+/// - a date, `MMDD[YY]`, `MM/DD[/YY]`, `MM.DD[.YY]`, or `YYYY-MM-DD[THH:MM[:SS]]`
+///
+/// Keywords, units, and `AM` / `PM` are case-insensitive. The value is displayed in its canonical form, e.g.
+/// `now+1hours`, `10:30PM`, or `0120` for `01/20`.
+///
+/// # Examples
+///
+/// ```
+/// use sbatch_rs::{BeginTime, BeginUnit};
+///
+/// let begin: BeginTime = "now+1hour".parse().unwrap();
+/// assert_eq!(begin, BeginTime::now_plus(1, BeginUnit::Hours));
+/// assert_eq!(begin.to_string(), "now+1hours");
+///
+/// let begin: BeginTime = "2025-01-20T12:34".parse().unwrap();
+/// assert_eq!(begin.to_string(), "2025-01-20T12:34");
+///
+/// assert!("now+".parse::<BeginTime>().is_err());
+/// assert!("25:00".parse::<BeginTime>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BeginTime {
+    kind: BeginKind,
+}
+
+impl BeginTime {
+    /// Creates a begin time of `now`.
+    pub fn now() -> Self {
+        BeginTime {
+            kind: BeginKind::Now,
+        }
+    }
+
+    /// Creates a begin time relative to now, `now+<count><unit>`.
+    pub fn now_plus(count: u32, unit: BeginUnit) -> Self {
+        BeginTime {
+            kind: BeginKind::NowPlus(count, unit),
+        }
+    }
+
+    /// Returns `true` if the begin time is `now` or relative to now.
+    pub fn is_relative(&self) -> bool {
+        matches!(self.kind, BeginKind::Now | BeginKind::NowPlus(..))
+    }
+
+    /// Resolves the begin time to the instant Slurm would start the job, given the current time.
+    ///
+    /// A time of day or keyword that has already passed today refers to the next day, as in Slurm.
+    /// `today` is the start of the current day, so the job can start immediately, and `tomorrow` is the
+    /// start of the next day. Since `std::time` has no time zones, dates and times of day are interpreted
+    /// as UTC, and `MMDDYY` years are taken to be in the 2000s. A date without a year is in the current
+    /// year, or the next year if it has already passed.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time.
+    ///
+    /// # Returns
+    ///
+    /// This function returns the resolved start time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// use sbatch_rs::BeginTime;
+    ///
+    /// // 1970-01-01T18:00:00
+    /// let now = UNIX_EPOCH + Duration::from_secs(18 * 3600);
+    ///
+    /// let begin: BeginTime = "now+30minutes".parse().unwrap();
+    /// assert_eq!(begin.resolve(now), now + Duration::from_secs(30 * 60));
+    ///
+    /// // Noon has passed, so it refers to noon the next day
+    /// let begin: BeginTime = "noon".parse().unwrap();
+    /// assert_eq!(begin.resolve(now), UNIX_EPOCH + Duration::from_secs(36 * 3600));
+    /// ```
+    #[cfg(feature = "time")]
+    pub fn resolve(&self, now: SystemTime) -> SystemTime {
+        const DAY: u64 = 24 * 60 * 60;
+
+        let since_epoch = now.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        let today = since_epoch - since_epoch % DAY;
+
+        // The next occurrence of a time of day, counting from the current time
+        let next_time_of_day = |seconds: u64| {
+            let day = if today + seconds <= since_epoch {
+                today + DAY
+            } else {
+                today
+            };
+            UNIX_EPOCH + Duration::from_secs(day + seconds)
+        };
+        // The start of a calendar date, plus a time of day
+        let date = |year: i64, month: u8, day: u8, seconds: u64| {
+            let days = days_from_civil(year, month, day);
+            let seconds = days * DAY as i64 + seconds as i64;
+            match u64::try_from(seconds) {
+                Ok(seconds) => UNIX_EPOCH + Duration::from_secs(seconds),
+                Err(_) => UNIX_EPOCH - Duration::from_secs(seconds.unsigned_abs()),
+            }
+        };
+
+        match self.kind {
+            BeginKind::Now => now,
+            BeginKind::NowPlus(count, unit) => {
+                now + Duration::from_secs(u64::from(count) * unit.seconds())
+            }
+            BeginKind::Today => UNIX_EPOCH + Duration::from_secs(today),
+            BeginKind::Tomorrow => UNIX_EPOCH + Duration::from_secs(today + DAY),
+            BeginKind::Midnight => next_time_of_day(0),
+            BeginKind::Noon => next_time_of_day(12 * 3600),
+            BeginKind::Elevenses => next_time_of_day(11 * 3600),
+            BeginKind::Fika => next_time_of_day(15 * 3600),
+            BeginKind::Teatime => next_time_of_day(16 * 3600),
+            BeginKind::Time(time) => next_time_of_day(time.seconds()),
+            BeginKind::Date {
+                year,
+                month,
+                day,
+                time,
+            } => date(
+                i64::from(year),
+                month,
+                day,
+                time.map_or(0, |time| time.seconds()),
+            ),
+            BeginKind::ShortDate {
+                month,
+                day,
+                year: Some(year),
+            } => date(2000 + i64::from(year), month, day, 0),
+            BeginKind::ShortDate {
+                month,
+                day,
+                year: None,
+            } => {
+                // The date in the current year, unless it is already over
+                let current_year = civil_year(since_epoch / DAY);
+                let start = date(current_year, month, day, 0);
+                if start < UNIX_EPOCH + Duration::from_secs(today) {
+                    date(current_year + 1, month, day, 0)
+                } else {
+                    start
+                }
+            }
+        }
+    }
+}
+
+// Helper function to count the days from 1970-01-01 to a date in the proleptic Gregorian calendar.
+#[cfg(feature = "time")]
+fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((i64::from(month) + 9) % 12) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+// Helper function to get the year of a day counted from 1970-01-01, the inverse of `days_from_civil`.
+#[cfg(feature = "time")]
+fn civil_year(days: u64) -> i64 {
+    let days = days as i64 + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    // January and February count towards the previous year in the March-based calendar
+    year_of_era + era * 400 + i64::from(month_index >= 10)
+}
+
+// Helper function to get the number of days in a month.
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => {
+            29
+        }
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// Helper function to parse a fixed-width run of digits.
+fn parse_digits<T: FromStr>(s: &str, width: usize) -> Option<T> {
+    if s.len() == width && s.chars().all(|c| c.is_ascii_digit()) {
+        s.parse().ok()
+    } else {
+        None
+    }
+}
+
+// Helper function to parse a time of day, `HH:MM[:SS]`.
+fn parse_time_of_day(s: &str) -> Option<TimeOfDay> {
+    let mut parts = s.split(':');
+    let hour = parse_digits(parts.next()?, 2).filter(|hour| *hour < 24)?;
+    let minute = parse_digits(parts.next()?, 2).filter(|minute| *minute < 60)?;
+    let second = match parts.next() {
+        Some(second) => Some(parse_digits(second, 2).filter(|second| *second < 60)?),
+        None => None,
+    };
+    if parts.next().is_some() {
+        return None;
+    }
+    Some(TimeOfDay {
+        hour,
+        minute,
+        second,
+        meridiem: None,
+    })
+}
+
+// Helper function to parse a time of day on a 24-hour clock, or on a 12-hour clock with a trailing `AM` or `PM`.
+// Only a 12-hour clock time may have a single-digit hour.
+fn parse_clock_time(s: &str) -> Option<TimeOfDay> {
+    let lower = s.to_ascii_lowercase();
+    let (time, meridiem) = if let Some(time) = lower.strip_suffix("am") {
+        (time.trim_end(), Some(Meridiem::Am))
+    } else if let Some(time) = lower.strip_suffix("pm") {
+        (time.trim_end(), Some(Meridiem::Pm))
+    } else {
+        (lower.as_str(), None)
+    };
+    // A 12-hour clock time may have a single-digit hour, e.g. `4:00 PM`
+    let time = match meridiem {
+        Some(_) if time.find(':') == Some(1) => parse_time_of_day(&format!("0{time}"))?,
+        _ => parse_time_of_day(time)?,
+    };
+    if meridiem.is_some() && !(1..=12).contains(&time.hour) {
+        return None;
+    }
+    Some(TimeOfDay { meridiem, ..time })
+}
+
+// Helper function to parse a date, `YYYY-MM-DD[THH:MM[:SS]]`.
+fn parse_date(s: &str) -> Option<BeginKind> {
+    let (date, time) = match s.split_once('T') {
+        Some((date, time)) => (date, Some(parse_time_of_day(time)?)),
+        None => (s, None),
+    };
+    let mut parts = date.split('-');
+    let year = parse_digits(parts.next()?, 4)?;
+    let month = parse_digits(parts.next()?, 2).filter(|month| (1..=12).contains(month))?;
+    let day = parse_digits(parts.next()?, 2)
+        .filter(|day| (1..=days_in_month(year, month)).contains(day))?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Some(BeginKind::Date {
+        year,
+        month,
+        day,
+        time,
+    })
+}
+
+// Helper function to parse a date, `MMDD[YY]`, `MM/DD[/YY]`, or `MM.DD[.YY]`.
+fn parse_short_date(s: &str) -> Option<BeginKind> {
+    let parts: Vec<&str> = if s.contains(['/', '.']) {
+        let separator = if s.contains('/') { '/' } else { '.' };
+        s.split(separator).collect()
+    } else if s.chars().all(|c| c.is_ascii_digit()) && (s.len() == 4 || s.len() == 6) {
+        (0..s.len()).step_by(2).map(|i| &s[i..i + 2]).collect()
+    } else {
+        return None;
+    };
+    let (month, day, year) = match parts.as_slice() {
+        [month, day] => (month, day, None),
+        [month, day, year] => (month, day, Some(parse_digits(year, 2)?)),
+        _ => return None,
+    };
+    // Without a year, February 29 is accepted since it exists in leap years
+    let month = parse_digits(month, 2).filter(|month| (1..=12).contains(month))?;
+    let day = parse_digits(day, 2).filter(|day| {
+        let year = year.map_or(2000, |year: u8| 2000 + u16::from(year));
+        (1..=days_in_month(year, month)).contains(day)
+    })?;
+    Some(BeginKind::ShortDate { month, day, year })
+}
+
+impl FromStr for BeginTime {
+    type Err = SbatchOptionError;
+
+    /// Parses a begin time such as `now+1hour`, `teatime`, `16:00`, `4:00 PM`, `01/20`, `012025`, or `2025-01-20T12:34:56`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SbatchOptionError::InvalidBeginTime(s.to_string());
+
+        let lower = s.to_ascii_lowercase();
+        let kind = match lower.as_str() {
+            "now" => BeginKind::Now,
+            "today" => BeginKind::Today,
+            "tomorrow" => BeginKind::Tomorrow,
+            "midnight" => BeginKind::Midnight,
+            "noon" => BeginKind::Noon,
+            "elevenses" => BeginKind::Elevenses,
+            "fika" => BeginKind::Fika,
+            "teatime" => BeginKind::Teatime,
+            _ => {
+                if let Some(offset) = lower.strip_prefix("now+") {
+                    // The count is required, and the unit defaults to seconds
+                    let split = offset
+                        .find(|c: char| !c.is_ascii_digit())
+                        .unwrap_or(offset.len());
+                    let (count, unit) = offset.split_at(split);
+                    if count.is_empty() {
+                        return Err(invalid());
+                    }
+                    BeginKind::NowPlus(
+                        count.parse().map_err(|_| invalid())?,
+                        unit.parse().map_err(|_| invalid())?,
+                    )
+                } else if s.contains('-') {
+                    parse_date(s).ok_or_else(invalid)?
+                } else if s.contains(':') {
+                    BeginKind::Time(parse_clock_time(s).ok_or_else(invalid)?)
+                } else {
+                    parse_short_date(s).ok_or_else(invalid)?
+                }
+            }
+        };
+        Ok(BeginTime { kind })
+    }
+}
+
+impl std::fmt::Display for TimeOfDay {
+    // Displays the time of day as `HH:MM` or `HH:MM:SS`, followed by `AM` or `PM` without a space for a 12-hour clock.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)?;
+        if let Some(second) = self.second {
+            write!(f, ":{:02}", second)?;
+        }
+        match self.meridiem {
+            Some(Meridiem::Am) => write!(f, "AM"),
+            Some(Meridiem::Pm) => write!(f, "PM"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl std::fmt::Display for BeginTime {
+    /// Displays the begin time in its canonical form, e.g. `now+1hours` or `2025-01-20T12:34`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            BeginKind::Now => write!(f, "now"),
+            BeginKind::NowPlus(count, unit) => write!(f, "now+{}{}", count, unit.name()),
+            BeginKind::Today => write!(f, "today"),
+            BeginKind::Tomorrow => write!(f, "tomorrow"),
+            BeginKind::Midnight => write!(f, "midnight"),
+            BeginKind::Noon => write!(f, "noon"),
+            BeginKind::Elevenses => write!(f, "elevenses"),
+            BeginKind::Fika => write!(f, "fika"),
+            BeginKind::Teatime => write!(f, "teatime"),
+            BeginKind::Time(time) => write!(f, "{}", time),
+            BeginKind::Date {
+                year,
+                month,
+                day,
+                time,
+            } => {
+                write!(f, "{:04}-{:02}-{:02}", year, month, day)?;
+                match time {
+                    Some(time) => write!(f, "T{}", time),
+                    None => Ok(()),
+                }
+            }
+            BeginKind::ShortDate { month, day, year } => {
+                write!(f, "{:02}{:02}", month, day)?;
+                match year {
+                    Some(year) => write!(f, "{:02}", year),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,214 @@
+//! Typed feature expression for the `--constraint` option
+
+use std::iter::Peekable;
+use std::num::NonZeroU32;
+use std::str::{Chars, FromStr};
+
+use super::SbatchOptionError;
+
+/// A node of a parsed `--constraint` expression
+///
+/// - `Feature`: A feature name, with an optional node count (`knl*2`).
+/// - `Not`: A negated expression (`!gpu`).
+/// - `And`: Expressions joined by `&`.
+/// - `Or`: Expressions joined by `|`.
+/// - `Brackets`: An expression grouped with `[...]`, used for matching OR.
+/// - `Parens`: An expression grouped with `(...)`.
+///
+/// `&` binds more tightly than `|`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConstraintExpr {
+    Feature {
+        name: String,
+        count: Option<NonZeroU32>,
+    },
+    Not(Box<ConstraintExpr>),
+    And(Vec<ConstraintExpr>),
+    Or(Vec<ConstraintExpr>),
+    Brackets(Box<ConstraintExpr>),
+    Parens(Box<ConstraintExpr>),
+}
+
+impl ConstraintExpr {
+    // Helper function to collect the feature names referenced by the expression, in order.
+    fn collect_features<'a>(&'a self, features: &mut Vec<&'a str>) {
+        match self {
+            ConstraintExpr::Feature { name, .. } => {
+                if !features.contains(&name.as_str()) {
+                    features.push(name);
+                }
+            }
+            ConstraintExpr::Not(expr)
+            | ConstraintExpr::Brackets(expr)
+            | ConstraintExpr::Parens(expr) => expr.collect_features(features),
+            ConstraintExpr::And(exprs) | ConstraintExpr::Or(exprs) => {
+                for expr in exprs {
+                    expr.collect_features(features);
+                }
+            }
+        }
+    }
+}
+
+/// Constraint expression
+///
+/// Represents the value of the `--constraint` option: feature names combined with `&` (AND) and `|` (OR),
+/// grouped with `[...]` or `(...)`, negated with `!`, and optionally followed by a node count (`feature*2`).
+/// Feature names may contain ASCII letters, digits, `_`, `-`, `.`, `:`, and `=`.
+///
+/// The value is displayed exactly as it was written.
+///
+/// # Examples
+///
+/// ```
+/// use sbatch_rs::Constraint;
+///
+/// let constraint: Constraint = "[rack1|rack2]&knl*2&!gpu".parse().unwrap();
+/// assert_eq!(constraint.features(), vec!["rack1", "rack2", "knl", "gpu"]);
+/// assert_eq!(constraint.to_string(), "[rack1|rack2]&knl*2&!gpu");
+///
+/// assert!("a&&b".parse::<Constraint>().is_err());
+/// assert!("[a|b".parse::<Constraint>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Constraint {
+    expr: ConstraintExpr,
+}
+
+impl Constraint {
+    /// Returns the parsed expression.
+    pub fn expr(&self) -> &ConstraintExpr {
+        &self.expr
+    }
+
+    /// Returns the feature names referenced by the expression, in order of first appearance.
+    pub fn features(&self) -> Vec<&str> {
+        let mut features = Vec::new();
+        self.expr.collect_features(&mut features);
+        features
+    }
+}
+
+// Recursive descent parser for constraint expressions
+struct Parser<'a> {
+    chars: Peekable<Chars<'a>>,
+}
+
+impl Parser<'_> {
+    // Helper function to parse expressions joined by `|`.
+    fn parse_or(&mut self) -> Option<ConstraintExpr> {
+        let mut exprs = vec![self.parse_and()?];
+        while self.chars.next_if_eq(&'|').is_some() {
+            exprs.push(self.parse_and()?);
+        }
+        Some(match exprs.len() {
+            1 => exprs.remove(0),
+            _ => ConstraintExpr::Or(exprs),
+        })
+    }
+
+    // Helper function to parse expressions joined by `&`.
+    fn parse_and(&mut self) -> Option<ConstraintExpr> {
+        let mut exprs = vec![self.parse_unary()?];
+        while self.chars.next_if_eq(&'&').is_some() {
+            exprs.push(self.parse_unary()?);
+        }
+        Some(match exprs.len() {
+            1 => exprs.remove(0),
+            _ => ConstraintExpr::And(exprs),
+        })
+    }
+
+    // Helper function to parse a negated expression, a group, or a feature.
+    fn parse_unary(&mut self) -> Option<ConstraintExpr> {
+        match self.chars.next()? {
+            '!' => Some(ConstraintExpr::Not(Box::new(self.parse_unary()?))),
+            '[' => {
+                let expr = self.parse_or()?;
+                self.chars.next_if_eq(&']')?;
+                Some(ConstraintExpr::Brackets(Box::new(expr)))
+            }
+            '(' => {
+                let expr = self.parse_or()?;
+                self.chars.next_if_eq(&')')?;
+                Some(ConstraintExpr::Parens(Box::new(expr)))
+            }
+            c if is_feature_char(c) => {
+                let mut name = c.to_string();
+                while let Some(c) = self.chars.next_if(|c| is_feature_char(*c)) {
+                    name.push(c);
+                }
+                let count = match self.chars.next_if_eq(&'*') {
+                    Some(_) => {
+                        let mut count = String::new();
+                        while let Some(c) = self.chars.next_if(char::is_ascii_digit) {
+                            count.push(c);
+                        }
+                        Some(count.parse().ok()?)
+                    }
+                    None => None,
+                };
+                Some(ConstraintExpr::Feature { name, count })
+            }
+            _ => None,
+        }
+    }
+}
+
+// Helper function to check whether a character may appear in a feature name.
+fn is_feature_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | ':' | '=')
+}
+
+impl FromStr for Constraint {
+    type Err = SbatchOptionError;
+
+    /// Parses a constraint expression such as `intel&gpu` or `[rack1*2&rack2*4]`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            chars: s.chars().peekable(),
+        };
+        match parser.parse_or() {
+            Some(expr) if parser.chars.peek().is_none() => Ok(Constraint { expr }),
+            _ => Err(SbatchOptionError::InvalidConstraint(s.to_string())),
+        }
+    }
+}
+
+impl std::fmt::Display for ConstraintExpr {
+    /// Displays the expression as it is written in a `--constraint` value.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Helper function to join expressions with an operator
+        let join = |f: &mut std::fmt::Formatter<'_>, exprs: &[ConstraintExpr], op: &str| {
+            for (index, expr) in exprs.iter().enumerate() {
+                if index > 0 {
+                    write!(f, "{}", op)?;
+                }
+                write!(f, "{}", expr)?;
+            }
+            Ok(())
+        };
+
+        match self {
+            ConstraintExpr::Feature {
+                name,
+                count: Some(count),
+            } => write!(f, "{}*{}", name, count),
+            ConstraintExpr::Feature { name, count: None } => write!(f, "{}", name),
+            ConstraintExpr::Not(expr) => write!(f, "!{}", expr),
+            ConstraintExpr::And(exprs) => join(f, exprs, "&"),
+            ConstraintExpr::Or(exprs) => join(f, exprs, "|"),
+            ConstraintExpr::Brackets(expr) => write!(f, "[{}]", expr),
+            ConstraintExpr::Parens(expr) => write!(f, "({})", expr),
+        }
+    }
+}
+
+impl std::fmt::Display for Constraint {
+    /// Displays the constraint expression as it was written.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.expr)
+    }
+}
@@ -0,0 +1,214 @@
+//! Typed CPU frequency request for the `--cpu-freq` option
+
+use std::num::NonZeroU32;
+use std::str::FromStr;
+
+use super::SbatchOptionError;
+
+/// A CPU frequency, either in kilohertz or relative to the frequencies available on the node
+///
+/// - `KHz`: A frequency in kilohertz (`2400000`).
+/// - `Low`: The lowest available frequency (`low`).
+/// - `Medium`: A frequency in the middle of the available range (`medium`).
+/// - `High`: The highest available frequency (`high`).
+/// - `HighM1`: The next frequency below the highest (`highm1`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CpuFrequency {
+    KHz(NonZeroU32),
+    Low,
+    Medium,
+    High,
+    HighM1,
+}
+
+/// A CPU frequency governor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CpuGovernor {
+    Conservative,
+    OnDemand,
+    Performance,
+    PowerSave,
+    UserSpace,
+    SchedUtil,
+}
+
+/// CPU frequency request
+///
+/// Represents the value of the `--cpu-freq` option: `<p1>[-p2][:p3]`, where `p1` and `p2` are
+/// frequencies (a number of kilohertz, or `low`, `medium`, `high`, or `highm1`) and `p3` is a
+/// governor. A governor may also be given on its own. When `p2` is given, `p1` is the minimum and
+/// `p2` the maximum frequency, and `p2` may not be `low`. Names are matched case-insensitively.
+///
+/// The value is displayed in its canonical form, e.g. `low-high:Conservative`.
+///
+/// # Examples
+///
+/// ```
+/// use sbatch_rs::{CpuFreq, CpuFrequency, CpuGovernor};
+///
+/// let cpu_freq: CpuFreq = "LOW-high:conservative".parse().unwrap();
+/// assert_eq!(cpu_freq.frequency(), Some(CpuFrequency::Low));
+/// assert_eq!(cpu_freq.max_frequency(), Some(CpuFrequency::High));
+/// assert_eq!(cpu_freq.governor(), Some(CpuGovernor::Conservative));
+/// assert_eq!(cpu_freq.to_string(), "low-high:Conservative");
+///
+/// let cpu_freq: CpuFreq = "Performance".parse().unwrap();
+/// assert_eq!(cpu_freq.frequency(), None);
+/// assert_eq!(cpu_freq.governor(), Some(CpuGovernor::Performance));
+///
+/// assert!("turbo".parse::<CpuFreq>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CpuFreq {
+    frequency: Option<CpuFrequency>,
+    max_frequency: Option<CpuFrequency>,
+    governor: Option<CpuGovernor>,
+}
+
+impl CpuFreq {
+    /// Returns the requested frequency, or the minimum frequency when a maximum is also given.
+    pub fn frequency(&self) -> Option<CpuFrequency> {
+        self.frequency
+    }
+
+    /// Returns the maximum frequency, if given.
+    pub fn max_frequency(&self) -> Option<CpuFrequency> {
+        self.max_frequency
+    }
+
+    /// Returns the governor, if given.
+    pub fn governor(&self) -> Option<CpuGovernor> {
+        self.governor
+    }
+}
+
+impl FromStr for CpuFrequency {
+    type Err = SbatchOptionError;
+
+    /// Parses a frequency such as `2400000` or `highm1`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "low" => Ok(CpuFrequency::Low),
+            "medium" => Ok(CpuFrequency::Medium),
+            "high" => Ok(CpuFrequency::High),
+            "highm1" => Ok(CpuFrequency::HighM1),
+            khz if !khz.is_empty() && khz.chars().all(|c| c.is_ascii_digit()) => khz
+                .parse()
+                .map(CpuFrequency::KHz)
+                .map_err(|_| SbatchOptionError::InvalidCpuFreq(s.to_string())),
+            _ => Err(SbatchOptionError::InvalidCpuFreq(s.to_string())),
+        }
+    }
+}
+
+impl FromStr for CpuGovernor {
+    type Err = SbatchOptionError;
+
+    /// Parses a governor name such as `Performance`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "conservative" => Ok(CpuGovernor::Conservative),
+            "ondemand" => Ok(CpuGovernor::OnDemand),
+            "performance" => Ok(CpuGovernor::Performance),
+            "powersave" => Ok(CpuGovernor::PowerSave),
+            "userspace" => Ok(CpuGovernor::UserSpace),
+            "schedutil" => Ok(CpuGovernor::SchedUtil),
+            _ => Err(SbatchOptionError::InvalidCpuFreq(s.to_string())),
+        }
+    }
+}
+
+impl FromStr for CpuFreq {
+    type Err = SbatchOptionError;
+
+    /// Parses a CPU frequency request such as `Performance`, `1000000`, or `low-high:Conservative`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SbatchOptionError::InvalidCpuFreq(s.to_string());
+
+        // A governor on its own
+        if let Ok(governor) = s.parse() {
+            return Ok(CpuFreq {
+                frequency: None,
+                max_frequency: None,
+                governor: Some(governor),
+            });
+        }
+
+        let (frequencies, governor) = match s.split_once(':') {
+            Some((frequencies, governor)) => {
+                (frequencies, Some(governor.parse().map_err(|_| invalid())?))
+            }
+            None => (s, None),
+        };
+        let (frequency, max_frequency) = match frequencies.split_once('-') {
+            Some((min, max)) => {
+                let min: CpuFrequency = min.parse().map_err(|_| invalid())?;
+                let max: CpuFrequency = max.parse().map_err(|_| invalid())?;
+                match (min, max) {
+                    (_, CpuFrequency::Low) => return Err(invalid()),
+                    (CpuFrequency::KHz(min), CpuFrequency::KHz(max)) if min > max => {
+                        return Err(invalid());
+                    }
+                    _ => (min, Some(max)),
+                }
+            }
+            None => (frequencies.parse().map_err(|_| invalid())?, None),
+        };
+
+        Ok(CpuFreq {
+            frequency: Some(frequency),
+            max_frequency,
+            governor,
+        })
+    }
+}
+
+impl std::fmt::Display for CpuFrequency {
+    /// Displays the frequency, e.g. `2400000` or `highm1`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CpuFrequency::KHz(khz) => write!(f, "{}", khz),
+            CpuFrequency::Low => write!(f, "low"),
+            CpuFrequency::Medium => write!(f, "medium"),
+            CpuFrequency::High => write!(f, "high"),
+            CpuFrequency::HighM1 => write!(f, "highm1"),
+        }
+    }
+}
+
+impl std::fmt::Display for CpuGovernor {
+    /// Displays the governor name, e.g. `OnDemand`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CpuGovernor::Conservative => write!(f, "Conservative"),
+            CpuGovernor::OnDemand => write!(f, "OnDemand"),
+            CpuGovernor::Performance => write!(f, "Performance"),
+            CpuGovernor::PowerSave => write!(f, "PowerSave"),
+            CpuGovernor::UserSpace => write!(f, "UserSpace"),
+            CpuGovernor::SchedUtil => write!(f, "SchedUtil"),
+        }
+    }
+}
+
+impl std::fmt::Display for CpuFreq {
+    /// Displays the request in its canonical form, e.g. `low-high:Conservative`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some(frequency) = self.frequency else {
+            return match self.governor {
+                Some(governor) => write!(f, "{}", governor),
+                None => Ok(()),
+            };
+        };
+        write!(f, "{}", frequency)?;
+        if let Some(max_frequency) = self.max_frequency {
+            write!(f, "-{}", max_frequency)?;
+        }
+        if let Some(governor) = self.governor {
+            write!(f, ":{}", governor)?;
+        }
+        Ok(())
+    }
+}
@@ -1,136 +1,228 @@
 //! Display implementation for `SbatchOption`
 
+use std::borrow::Cow;
+
 use super::SbatchOption;
 
-impl std::fmt::Display for SbatchOption {
-    /// The `Display` trait is implemented for `SbatchOption` to allow the `SbatchOption` to be converted into a string for display purposes.
+/// How option values are quoted when an `SbatchOption` is displayed
+///
+/// - `Shell`: Values containing characters that are significant to the shell, such as spaces, quotes, `$`, or `*`,
+///   are single-quoted, so the output can be pasted into a shell without `$` or backticks being expanded.
+///   This is the style used by `Display`.
+/// - `Unquoted`: Values are written as-is, for passing each option as a separate process argument without a shell.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DisplayStyle {
+    #[default]
+    Shell,
+    Unquoted,
+}
+
+// Helper function to single-quote a value if it contains characters that are significant to the shell.
+pub fn shell_quote(value: &str) -> Cow<'_, str> {
+    let is_safe = |c: char| c.is_ascii_alphanumeric() || "-_./:,=+@%^".contains(c);
+    if !value.is_empty() && value.chars().all(is_safe) {
+        Cow::Borrowed(value)
+    } else {
+        Cow::Owned(format!("'{}'", value.replace('\'', r"'\''")))
+    }
+}
+
+// Helper struct to display an `SbatchOption` with a given `DisplayStyle`
+struct Styled<'a>(&'a SbatchOption, DisplayStyle);
+
+impl std::fmt::Display for Styled<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt_with(f, self.1)
+    }
+}
+
+impl SbatchOption {
+    /// Displays the option with the given `DisplayStyle`.
     ///
-    /// # Example
+    /// # Arguments
+    ///
+    /// * `style` - The `DisplayStyle` deciding how values are quoted.
+    ///
+    /// # Returns
+    ///
+    /// This function returns the option as a string.
+    ///
+    /// # Examples
     ///
     /// ```
-    /// use sbatch_rs::SbatchOption;
+    /// use sbatch_rs::{DisplayStyle, SbatchOption};
     ///
-    /// let option = SbatchOption::JobName("test".to_string());
-    /// assert_eq!(option.to_string(), "--job-name=test");
+    /// let option = SbatchOption::Comment("hello world".to_string());
+    /// assert_eq!(option.display_with(DisplayStyle::Shell), "--comment='hello world'");
+    /// assert_eq!(option.display_with(DisplayStyle::Unquoted), "--comment=hello world");
     /// ```
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    pub fn display_with(&self, style: DisplayStyle) -> String {
+        Styled(self, style).to_string()
+    }
+
+    // Helper function to write the option with the given style
+    fn fmt_with(&self, f: &mut std::fmt::Formatter<'_>, style: DisplayStyle) -> std::fmt::Result {
+        let quote = |value: &str| match style {
+            DisplayStyle::Shell => shell_quote(value).into_owned(),
+            DisplayStyle::Unquoted => value.to_string(),
+        };
         match self {
-            SbatchOption::Account(value) => write!(f, "--account={}", value),
-            SbatchOption::AcctgFreq(value) => write!(f, "--acctg-freq={}", value),
+            SbatchOption::Account(value) => write!(f, "--account={}", quote(value)),
+            SbatchOption::AcctgFreq(value) => write!(f, "--acctg-freq={}", quote(value)),
             SbatchOption::Array(value) => write!(f, "--array={}", value),
-            SbatchOption::Batch(value) => write!(f, "--batch={}", value),
-            SbatchOption::Bb(value) => write!(f, "--bb={}", value),
-            SbatchOption::Bbf(value) => write!(f, "--bbf={}", value),
+            SbatchOption::Batch(value) => write!(f, "--batch={}", quote(value)),
+            SbatchOption::Bb(value) => write!(f, "--bb={}", quote(value)),
+            SbatchOption::Bbf(value) => write!(f, "--bbf={}", quote(value)),
             SbatchOption::Begin(value) => write!(f, "--begin={}", value),
-            SbatchOption::Chdir(value) => write!(f, "--chdir={}", value),
-            SbatchOption::ClusterConstraint(value) => write!(f, "--cluster-constraint={}", value),
-            SbatchOption::Clusters(value) => write!(f, "--clusters={}", value),
-            SbatchOption::Comment(value) => write!(f, "--comment={}", value),
-            SbatchOption::Constraint(value) => write!(f, "--constraint={}", value),
-            SbatchOption::Container(value) => write!(f, "--container={}", value),
-            SbatchOption::ContainerID(value) => write!(f, "--container-id={}", value),
+            SbatchOption::Chdir(value) => write!(f, "--chdir={}", quote(value)),
+            SbatchOption::ClusterConstraint(value) => {
+                write!(f, "--cluster-constraint={}", quote(value))
+            }
+            SbatchOption::Clusters(value) => write!(f, "--clusters={}", quote(value)),
+            SbatchOption::Comment(value) => write!(f, "--comment={}", quote(value)),
+            SbatchOption::Constraint(value) => {
+                write!(f, "--constraint={}", quote(&value.to_string()))
+            }
+            SbatchOption::Container(value) => write!(f, "--container={}", quote(value)),
+            SbatchOption::ContainerID(value) => write!(f, "--container-id={}", quote(value)),
             SbatchOption::Contiguous => write!(f, "--contiguous"),
             SbatchOption::CoreSpec(value) => write!(f, "--core-spec={}", value),
             SbatchOption::CoresPerSocket(value) => write!(f, "--cores-per-socket={}", value),
             SbatchOption::CPUFreq(value) => write!(f, "--cpu-freq={}", value),
-            SbatchOption::CPUsPerGPU(value) => write!(f, "--cpus-per-gpu={}", value),
-            SbatchOption::CPUsPerTask(value) => write!(f, "--cpus-per-task={}", value),
-            SbatchOption::Deadline(value) => write!(f, "--deadline={}", value),
-            SbatchOption::DelayBoot(value) => write!(f, "--delay-boot={}", value),
-            SbatchOption::Dependency(value) => write!(f, "--dependency={}", value),
-            SbatchOption::Distribution(value) => write!(f, "--distribution={}", value),
-            SbatchOption::Error(value) => write!(f, "--error={}", value),
-            SbatchOption::Exclude(value) => write!(f, "--exclude={}", value),
-            SbatchOption::Exclusive(Some(value)) => write!(f, "--exclusive={}", value),
+            SbatchOption::CPUsPerGPU(value) => write!(f, "--cpus-per-gpu={}", quote(value)),
+            SbatchOption::CPUsPerTask(value) => write!(f, "--cpus-per-task={}", quote(value)),
+            SbatchOption::Deadline(value) => write!(f, "--deadline={}", quote(value)),
+            SbatchOption::DelayBoot(value) => write!(f, "--delay-boot={}", quote(value)),
+            SbatchOption::Dependency(value) => write!(f, "--dependency={}", quote(value)),
+            SbatchOption::Distribution(value) => {
+                write!(f, "--distribution={}", quote(&value.to_string()))
+            }
+            SbatchOption::Error(value) => write!(f, "--error={}", quote(value)),
+            SbatchOption::Exclude(value) => write!(f, "--exclude={}", quote(&value.to_string())),
+            SbatchOption::Exclusive(Some(value)) => write!(f, "--exclusive={}", quote(value)),
             SbatchOption::Exclusive(None) => write!(f, "--exclusive"),
-            SbatchOption::Export(value) => write!(f, "--export={}", value),
-            SbatchOption::ExportFile(value) => write!(f, "--export-file={}", value),
-            SbatchOption::Extra(value) => write!(f, "--extra={}", value),
-            SbatchOption::ExtraNodeInfo(value) => write!(f, "--extra-node-info={}", value),
-            SbatchOption::GetUserEnv(Some(value)) => write!(f, "--get-user-env={}", value),
+            SbatchOption::Export(value) => write!(f, "--export={}", quote(&value.to_string())),
+            SbatchOption::ExportFile(value) => write!(f, "--export-file={}", quote(value)),
+            SbatchOption::Extra(value) => write!(f, "--extra={}", quote(value)),
+            SbatchOption::ExtraNodeInfo(value) => write!(f, "--extra-node-info={}", quote(value)),
+            SbatchOption::GetUserEnv(Some(value)) => write!(f, "--get-user-env={}", quote(value)),
             SbatchOption::GetUserEnv(None) => write!(f, "--get-user-env"),
-            SbatchOption::GID(value) => write!(f, "--gid={}", value),
-            SbatchOption::GPUBind(value) => write!(f, "--gpu-bind={}", value),
-            SbatchOption::GPUFreq(value) => write!(f, "--gpu-freq={}", value),
+            SbatchOption::GID(value) => write!(f, "--gid={}", quote(value)),
+            SbatchOption::GPUBind(value) => write!(f, "--gpu-bind={}", quote(value)),
+            SbatchOption::GPUFreq(value) => write!(f, "--gpu-freq={}", quote(value)),
             SbatchOption::GPUs(value) => write!(f, "--gpus={}", value),
             SbatchOption::GPUsPerNode(value) => write!(f, "--gpus-per-node={}", value),
             SbatchOption::GPUsPerSocket(value) => write!(f, "--gpus-per-socket={}", value),
             SbatchOption::GPUsPerTask(value) => write!(f, "--gpus-per-task={}", value),
             SbatchOption::Gres(value) => write!(f, "--gres={}", value),
-            SbatchOption::GresFlags(value) => write!(f, "--gres-flags={}", value),
+            SbatchOption::GresFlags(value) => write!(f, "--gres-flags={}", quote(value)),
             SbatchOption::Help => write!(f, "--help"),
-            SbatchOption::Hint(value) => write!(f, "--hint={}", value),
+            SbatchOption::Hint(value) => write!(f, "--hint={}", quote(value)),
             SbatchOption::Hold => write!(f, "--hold"),
             SbatchOption::IgnorePbs => write!(f, "--ignore-pbs"),
-            SbatchOption::Input(value) => write!(f, "--input={}", value),
-            SbatchOption::JobName(value) => write!(f, "--job-name={}", value),
-            SbatchOption::KillOnInvalidDep(value) => write!(f, "--kill-on-invalid-dep={}", value),
-            SbatchOption::Licenses(value) => write!(f, "--licenses={}", value),
-            SbatchOption::MailType(value) => write!(f, "--mail-type={}", value),
-            SbatchOption::MailUser(value) => write!(f, "--mail-user={}", value),
-            SbatchOption::McsLabel(value) => write!(f, "--mcs-label={}", value),
+            SbatchOption::Input(value) => write!(f, "--input={}", quote(value)),
+            SbatchOption::JobName(value) => write!(f, "--job-name={}", quote(value)),
+            SbatchOption::KillOnInvalidDep(value) => {
+                write!(f, "--kill-on-invalid-dep={}", quote(value))
+            }
+            SbatchOption::Licenses(value) => write!(f, "--licenses={}", quote(value)),
+            SbatchOption::MailType(value) => write!(f, "--mail-type={}", quote(value)),
+            SbatchOption::MailUser(value) => write!(f, "--mail-user={}", quote(value)),
+            SbatchOption::McsLabel(value) => write!(f, "--mcs-label={}", quote(value)),
             SbatchOption::Mem(value) => write!(f, "--mem={}", value),
-            SbatchOption::MemBind(value) => write!(f, "--mem-bind={}", value),
+            SbatchOption::MemBind(value) => write!(f, "--mem-bind={}", quote(value)),
             SbatchOption::MemPerCPU(value) => write!(f, "--mem-per-cpu={}", value),
             SbatchOption::MemPerGPU(value) => write!(f, "--mem-per-gpu={}", value),
-            SbatchOption::MinCPUs(value) => write!(f, "--min-cpus={}", value),
-            SbatchOption::Network(value) => write!(f, "--network={}", value),
+            SbatchOption::MinCPUs(value) => write!(f, "--min-cpus={}", quote(value)),
+            SbatchOption::Network(value) => write!(f, "--network={}", quote(value)),
             SbatchOption::Nice(Some(value)) => write!(f, "--nice={}", value),
+            // A bare `--nice` applies Slurm's default adjustment of +100
             SbatchOption::Nice(None) => write!(f, "--nice"),
-            SbatchOption::NoKill(Some(value)) => write!(f, "--no-kill={}", value),
+            SbatchOption::NoKill(Some(value)) => write!(f, "--no-kill={}", quote(value)),
             SbatchOption::NoKill(None) => write!(f, "--no-kill"),
             SbatchOption::NoRequeue => write!(f, "--no-requeue"),
-            SbatchOption::NodeFile(value) => write!(f, "--nodefile={}", value),
-            SbatchOption::NodeList(value) => write!(f, "--nodelist={}", value),
-            SbatchOption::Nodes(value) => write!(f, "--nodes={}", value),
+            SbatchOption::NodeFile(value) => write!(f, "--nodefile={}", quote(value)),
+            SbatchOption::NodeList(value) => write!(f, "--nodelist={}", quote(&value.to_string())),
+            SbatchOption::Nodes(value) => write!(f, "--nodes={}", quote(value)),
             SbatchOption::NTasks(value) => write!(f, "--ntasks={}", value),
-            SbatchOption::NTasksPerCore(value) => write!(f, "--ntasks-per-core={}", value),
-            SbatchOption::NTasksPerGPU(value) => write!(f, "--ntasks-per-gpu={}", value),
+            SbatchOption::NTasksPerCore(value) => write!(f, "--ntasks-per-core={}", quote(value)),
+            SbatchOption::NTasksPerGPU(value) => write!(f, "--ntasks-per-gpu={}", quote(value)),
             SbatchOption::NTasksPerNode(value) => write!(f, "--ntasks-per-node={}", value),
-            SbatchOption::NTasksPerSocket(value) => write!(f, "--ntasks-per-socket={}", value),
-            SbatchOption::OOMKillStep(Some(value)) => write!(f, "--oom-kill-step={}", value),
+            SbatchOption::NTasksPerSocket(value) => {
+                write!(f, "--ntasks-per-socket={}", quote(value))
+            }
+            SbatchOption::OOMKillStep(Some(value)) => write!(f, "--oom-kill-step={}", quote(value)),
             SbatchOption::OOMKillStep(None) => write!(f, "--oom-kill-step"),
             SbatchOption::OpenMode(value) => write!(f, "--open-mode={}", value),
-            SbatchOption::Output(value) => write!(f, "--output={}", value),
+            SbatchOption::Output(value) => write!(f, "--output={}", quote(value)),
             SbatchOption::Overcommit => write!(f, "--overcommit"),
             SbatchOption::Oversubscribe => write!(f, "--oversubscribe"),
             SbatchOption::Parsable => write!(f, "--parsable"),
-            SbatchOption::Partition(value) => write!(f, "--partition={}", value),
-            SbatchOption::Prefer(value) => write!(f, "--prefer={}", value),
-            SbatchOption::Priority(value) => write!(f, "--priority={}", value),
-            SbatchOption::Profile(value) => write!(f, "--profile={}", value),
-            SbatchOption::Propagate(Some(value)) => write!(f, "--propagate={}", value),
+            SbatchOption::Partition(value) => write!(f, "--partition={}", quote(value)),
+            SbatchOption::Prefer(value) => write!(f, "--prefer={}", quote(value)),
+            SbatchOption::Priority(value) => write!(f, "--priority={}", quote(value)),
+            SbatchOption::Profile(value) => write!(f, "--profile={}", quote(value)),
+            SbatchOption::Propagate(Some(value)) => write!(f, "--propagate={}", quote(value)),
             SbatchOption::Propagate(None) => write!(f, "--propagate"),
-            SbatchOption::Qos(value) => write!(f, "--qos={}", value),
+            SbatchOption::Qos(value) => write!(f, "--qos={}", quote(value)),
             SbatchOption::Quiet => write!(f, "--quiet"),
+            // The flag is validated, so only the value after it needs quoting
+            SbatchOption::Raw(value) => match value.find(['=', ' ']) {
+                Some(index) => {
+                    let (flag, value) = value.split_at(index + 1);
+                    write!(f, "{}{}", flag, quote(value))
+                }
+                None => write!(f, "{}", value),
+            },
             SbatchOption::Reboot => write!(f, "--reboot"),
             SbatchOption::Requeue => write!(f, "--requeue"),
-            SbatchOption::Reservation(value) => write!(f, "--reservation={}", value),
-            SbatchOption::ResvPorts(Some(value)) => write!(f, "--resv-ports={}", value),
+            SbatchOption::Reservation(value) => write!(f, "--reservation={}", quote(value)),
+            SbatchOption::ResvPorts(Some(value)) => write!(f, "--resv-ports={}", quote(value)),
             SbatchOption::ResvPorts(None) => write!(f, "--resv-ports"),
-            SbatchOption::Segment(value) => write!(f, "--segment={}", value),
+            SbatchOption::Segment(value) => write!(f, "--segment={}", quote(value)),
             SbatchOption::Signal(value) => write!(f, "--signal={}", value),
             SbatchOption::SocketsPerNode(value) => write!(f, "--sockets-per-node={}", value),
             SbatchOption::SpreadJob => write!(f, "--spread-job"),
             SbatchOption::Stepmgr => write!(f, "--stepmgr"),
-            SbatchOption::Switches(value) => write!(f, "--switches={}", value),
+            SbatchOption::Switches(value) => write!(f, "--switches={}", quote(value)),
             SbatchOption::TestOnly => write!(f, "--test-only"),
-            SbatchOption::ThreadSpec(value) => write!(f, "--thread-spec={}", value),
-            SbatchOption::ThreadsPerCore(value) => write!(f, "--threads-per-core={}", value),
+            SbatchOption::ThreadSpec(value) => write!(f, "--thread-spec={}", quote(value)),
+            SbatchOption::ThreadsPerCore(value) => write!(f, "--threads-per-core={}", quote(value)),
             SbatchOption::Time(value) => write!(f, "--time={}", value),
             SbatchOption::TimeMin(value) => write!(f, "--time-min={}", value),
-            SbatchOption::Tmp(value) => write!(f, "--tmp={}", value),
-            SbatchOption::TresBind(value) => write!(f, "--tres-bind={}", value),
-            SbatchOption::TresPerTask(value) => write!(f, "--tres-per-task={}", value),
-            SbatchOption::UID(value) => write!(f, "--uid={}", value),
+            SbatchOption::Tmp(value) => write!(f, "--tmp={}", quote(value)),
+            SbatchOption::TresBind(value) => write!(f, "--tres-bind={}", quote(value)),
+            SbatchOption::TresPerTask(value) => write!(f, "--tres-per-task={}", quote(value)),
+            SbatchOption::UID(value) => write!(f, "--uid={}", quote(value)),
             SbatchOption::Usage => write!(f, "--usage"),
             SbatchOption::UseMinNodes => write!(f, "--use-min-nodes"),
             SbatchOption::Verbose => write!(f, "--verbose"),
             SbatchOption::Version => write!(f, "--version"),
             SbatchOption::Wait => write!(f, "--wait"),
-            SbatchOption::WaitAllNodes(value) => write!(f, "--wait-all-nodes={}", value),
-            SbatchOption::WCKey(value) => write!(f, "--wckey={}", value),
-            SbatchOption::Wrap(value) => write!(f, r#"--wrap="{}""#, value),
+            SbatchOption::WaitAllNodes(value) => write!(f, "--wait-all-nodes={}", quote(value)),
+            SbatchOption::WCKey(value) => write!(f, "--wckey={}", quote(value)),
+            SbatchOption::Wrap(value) => write!(f, "--wrap={}", quote(value)),
         }
     }
 }
+
+impl std::fmt::Display for SbatchOption {
+    /// The `Display` trait is implemented for `SbatchOption` to allow the `SbatchOption` to be converted into a string for display purposes.
+    ///
+    /// Values are quoted for the shell, see `DisplayStyle::Shell`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sbatch_rs::SbatchOption;
+    ///
+    /// let option = SbatchOption::JobName("test".to_string());
+    /// assert_eq!(option.to_string(), "--job-name=test");
+    ///
+    /// let option = SbatchOption::Comment("hello world".to_string());
+    /// assert_eq!(option.to_string(), "--comment='hello world'");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_with(f, DisplayStyle::Shell)
+    }
+}
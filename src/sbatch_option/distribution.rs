@@ -0,0 +1,213 @@
+//! Typed task distribution for the `--distribution` option
+
+use std::num::NonZeroU32;
+use std::str::FromStr;
+
+use super::SbatchOptionError;
+
+/// How tasks are distributed across nodes, the first level of a `--distribution` value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NodeDistribution {
+    /// Maps to `*`, the default method
+    Default,
+    /// Maps to `block`, filling each node before moving to the next
+    Block,
+    /// Maps to `cyclic`, placing tasks on the nodes in turn
+    Cyclic,
+    /// Maps to `arbitrary`, following the order of the node list
+    Arbitrary,
+    /// Maps to `plane=<size>`, distributing blocks of `size` tasks in turn
+    Plane(NonZeroU32),
+}
+
+/// How CPUs are allocated to tasks within a node, the second (sockets) and third (cores) levels of a
+/// `--distribution` value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CpuDistribution {
+    /// Maps to `*`, the default method
+    Default,
+    /// Maps to `block`
+    Block,
+    /// Maps to `cyclic`
+    Cyclic,
+    /// Maps to `fcyclic`, a full cyclic distribution
+    FCyclic,
+}
+
+/// Task distribution
+///
+/// Represents the value of the `--distribution` option:
+/// `{*|block|cyclic|arbitrary|plane=<size>}[:{*|block|cyclic|fcyclic}[:{*|block|cyclic|fcyclic}]][,{Pack|NoPack}]`.
+/// Method names are matched case-insensitively.
+///
+/// The value is displayed in its canonical form, e.g. `block:cyclic,Pack`.
+///
+/// # Examples
+///
+/// ```
+/// use sbatch_rs::{CpuDistribution, Distribution, NodeDistribution};
+///
+/// let distribution: Distribution = "Block:cyclic,nopack".parse().unwrap();
+/// assert_eq!(distribution.nodes(), NodeDistribution::Block);
+/// assert_eq!(distribution.sockets(), Some(CpuDistribution::Cyclic));
+/// assert_eq!(distribution.cores(), None);
+/// assert_eq!(distribution.pack(), Some(false));
+/// assert_eq!(distribution.to_string(), "block:cyclic,NoPack");
+///
+/// assert!("spiral".parse::<Distribution>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Distribution {
+    nodes: NodeDistribution,
+    sockets: Option<CpuDistribution>,
+    cores: Option<CpuDistribution>,
+    pack: Option<bool>,
+}
+
+impl Distribution {
+    /// Returns how tasks are distributed across nodes.
+    pub fn nodes(&self) -> NodeDistribution {
+        self.nodes
+    }
+
+    /// Returns how CPUs are distributed across sockets, if given.
+    pub fn sockets(&self) -> Option<CpuDistribution> {
+        self.sockets
+    }
+
+    /// Returns how CPUs are distributed across cores, if given.
+    pub fn cores(&self) -> Option<CpuDistribution> {
+        self.cores
+    }
+
+    /// Returns `Some(true)` for `Pack`, `Some(false)` for `NoPack`, or `None` if neither was given.
+    pub fn pack(&self) -> Option<bool> {
+        self.pack
+    }
+}
+
+impl FromStr for NodeDistribution {
+    type Err = SbatchOptionError;
+
+    /// Parses a node distribution method such as `block` or `plane=4`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SbatchOptionError::InvalidDistribution(s.to_string());
+        let lower = s.to_ascii_lowercase();
+        match lower.as_str() {
+            "*" => Ok(NodeDistribution::Default),
+            "block" => Ok(NodeDistribution::Block),
+            "cyclic" => Ok(NodeDistribution::Cyclic),
+            "arbitrary" => Ok(NodeDistribution::Arbitrary),
+            _ => {
+                let size = lower.strip_prefix("plane=").ok_or_else(invalid)?;
+                if size.is_empty() || !size.chars().all(|c| c.is_ascii_digit()) {
+                    return Err(invalid());
+                }
+                size.parse()
+                    .map(NodeDistribution::Plane)
+                    .map_err(|_| invalid())
+            }
+        }
+    }
+}
+
+impl FromStr for CpuDistribution {
+    type Err = SbatchOptionError;
+
+    /// Parses a CPU distribution method such as `cyclic` or `fcyclic`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "*" => Ok(CpuDistribution::Default),
+            "block" => Ok(CpuDistribution::Block),
+            "cyclic" => Ok(CpuDistribution::Cyclic),
+            "fcyclic" => Ok(CpuDistribution::FCyclic),
+            _ => Err(SbatchOptionError::InvalidDistribution(s.to_string())),
+        }
+    }
+}
+
+impl FromStr for Distribution {
+    type Err = SbatchOptionError;
+
+    /// Parses a distribution such as `block:cyclic` or `plane=4,Pack`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SbatchOptionError::InvalidDistribution(s.to_string());
+
+        let (methods, pack) = match s.split_once(',') {
+            Some((methods, pack)) => match pack.to_ascii_lowercase().as_str() {
+                "pack" => (methods, Some(true)),
+                "nopack" => (methods, Some(false)),
+                _ => return Err(invalid()),
+            },
+            None => (s, None),
+        };
+
+        let mut levels = methods.split(':');
+        let nodes = levels
+            .next()
+            .unwrap_or_default()
+            .parse()
+            .map_err(|_| invalid())?;
+        let mut cpu_level = || {
+            levels
+                .next()
+                .map(|level| level.parse().map_err(|_| invalid()))
+                .transpose()
+        };
+        let sockets = cpu_level()?;
+        let cores = cpu_level()?;
+        if levels.next().is_some() {
+            return Err(invalid());
+        }
+
+        Ok(Distribution {
+            nodes,
+            sockets,
+            cores,
+            pack,
+        })
+    }
+}
+
+impl std::fmt::Display for NodeDistribution {
+    /// Displays the node distribution method, e.g. `block` or `plane=4`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NodeDistribution::Default => write!(f, "*"),
+            NodeDistribution::Block => write!(f, "block"),
+            NodeDistribution::Cyclic => write!(f, "cyclic"),
+            NodeDistribution::Arbitrary => write!(f, "arbitrary"),
+            NodeDistribution::Plane(size) => write!(f, "plane={}", size),
+        }
+    }
+}
+
+impl std::fmt::Display for CpuDistribution {
+    /// Displays the CPU distribution method, e.g. `fcyclic`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CpuDistribution::Default => write!(f, "*"),
+            CpuDistribution::Block => write!(f, "block"),
+            CpuDistribution::Cyclic => write!(f, "cyclic"),
+            CpuDistribution::FCyclic => write!(f, "fcyclic"),
+        }
+    }
+}
+
+impl std::fmt::Display for Distribution {
+    /// Displays the distribution in its canonical form, e.g. `block:cyclic,Pack`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.nodes)?;
+        for level in [self.sockets, self.cores].into_iter().flatten() {
+            write!(f, ":{}", level)?;
+        }
+        match self.pack {
+            Some(true) => write!(f, ",Pack"),
+            Some(false) => write!(f, ",NoPack"),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//! `SBATCH_*` input environment variables for `SbatchOption`
+
+use super::{SbatchOption, SbatchOptionError};
+
+// Long flag names with the input environment variable sbatch reads in their place.
+// Options that take an optional value, such as `--exclusive`, are left out, as their bare form has no
+// value to put in the variable.
+const ENV_VARS: &[(&str, &str)] = &[
+    ("account", "SBATCH_ACCOUNT"),
+    ("acctg-freq", "SBATCH_ACCTG_FREQ"),
+    ("array", "SBATCH_ARRAY_INX"),
+    ("batch", "SBATCH_BATCH"),
+    ("cluster-constraint", "SBATCH_CLUSTER_CONSTRAINT"),
+    ("clusters", "SBATCH_CLUSTERS"),
+    ("constraint", "SBATCH_CONSTRAINT"),
+    ("container", "SBATCH_CONTAINER"),
+    ("container-id", "SBATCH_CONTAINER_ID"),
+    ("core-spec", "SBATCH_CORE_SPEC"),
+    ("cpus-per-gpu", "SBATCH_CPUS_PER_GPU"),
+    ("delay-boot", "SBATCH_DELAY_BOOT"),
+    ("distribution", "SBATCH_DISTRIBUTION"),
+    ("error", "SBATCH_ERROR"),
+    ("export", "SBATCH_EXPORT"),
+    ("gpu-bind", "SBATCH_GPU_BIND"),
+    ("gpu-freq", "SBATCH_GPU_FREQ"),
+    ("gpus", "SBATCH_GPUS"),
+    ("gpus-per-node", "SBATCH_GPUS_PER_NODE"),
+    ("gpus-per-task", "SBATCH_GPUS_PER_TASK"),
+    ("gres", "SBATCH_GRES"),
+    ("gres-flags", "SBATCH_GRES_FLAGS"),
+    ("hint", "SBATCH_HINT"),
+    ("ignore-pbs", "SBATCH_IGNORE_PBS"),
+    ("input", "SBATCH_INPUT"),
+    ("job-name", "SBATCH_JOB_NAME"),
+    ("mem", "SBATCH_MEM_PER_NODE"),
+    ("mem-bind", "SBATCH_MEM_BIND"),
+    ("mem-per-cpu", "SBATCH_MEM_PER_CPU"),
+    ("mem-per-gpu", "SBATCH_MEM_PER_GPU"),
+    ("network", "SBATCH_NETWORK"),
+    ("no-requeue", "SBATCH_NO_REQUEUE"),
+    ("open-mode", "SBATCH_OPEN_MODE"),
+    ("output", "SBATCH_OUTPUT"),
+    ("overcommit", "SBATCH_OVERCOMMIT"),
+    ("partition", "SBATCH_PARTITION"),
+    ("profile", "SBATCH_PROFILE"),
+    ("qos", "SBATCH_QOS"),
+    ("requeue", "SBATCH_REQUEUE"),
+    ("reservation", "SBATCH_RESERVATION"),
+    ("signal", "SBATCH_SIGNAL"),
+    ("spread-job", "SBATCH_SPREAD_JOB"),
+    ("thread-spec", "SBATCH_THREAD_SPEC"),
+    ("threads-per-core", "SBATCH_THREADS_PER_CORE"),
+    ("time", "SBATCH_TIMELIMIT"),
+    ("tres-bind", "SBATCH_TRES_BIND"),
+    ("tres-per-task", "SBATCH_TRES_PER_TASK"),
+    ("use-min-nodes", "SBATCH_USE_MIN_NODES"),
+    ("wait", "SBATCH_WAIT"),
+    ("wait-all-nodes", "SBATCH_WAIT_ALL_NODES"),
+    ("wckey", "SBATCH_WCKEY"),
+];
+
+impl SbatchOption {
+    /// Returns the `SBATCH_*` input environment variable that sets this option, and its value.
+    ///
+    /// sbatch reads these variables when the matching flag is not given on the command line, so they can
+    /// be exported by a wrapper script instead of passing arguments. Flags without a value, such as
+    /// `--requeue`, are set to `1`.
+    ///
+    /// # Returns
+    ///
+    /// This function returns the variable name and value, or `None` if the option has no environment
+    /// variable equivalent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::SbatchOption;
+    ///
+    /// let option = SbatchOption::Time("90".parse().unwrap());
+    /// assert_eq!(
+    ///     option.to_env(),
+    ///     Some(("SBATCH_TIMELIMIT".to_string(), "0-01:30:00".to_string()))
+    /// );
+    ///
+    /// assert_eq!(SbatchOption::Hold.to_env(), None);
+    /// ```
+    pub fn to_env(&self) -> Option<(String, String)> {
+        let spec = self.to_spec();
+        let (_, name) = ENV_VARS.iter().find(|(flag, _)| *flag == spec.name)?;
+        let value = spec.value.unwrap_or_else(|| "1".to_string());
+        Some((name.to_string(), value))
+    }
+
+    /// Creates an `SbatchOption` from an `SBATCH_*` input environment variable, as sbatch does.
+    ///
+    /// This is the inverse of `to_env`. Flags without a value, such as `SBATCH_REQUEUE`, are set whatever
+    /// the value of the variable.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the variable, e.g. `SBATCH_PARTITION`.
+    /// * `value` - The value of the variable.
+    ///
+    /// # Returns
+    ///
+    /// This function returns the matching `SbatchOption`, or `None` if the variable is not recognized.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchOptionError` if the value is not valid for the option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::SbatchOption;
+    ///
+    /// let option = SbatchOption::from_env("SBATCH_PARTITION", "general").unwrap();
+    /// assert_eq!(option, Some(SbatchOption::Partition("general".to_string())));
+    ///
+    /// assert_eq!(SbatchOption::from_env("SBATCH_NOT_A_VARIABLE", "x").unwrap(), None);
+    /// assert!(SbatchOption::from_env("SBATCH_TIMELIMIT", "soon").is_err());
+    /// ```
+    pub fn from_env(name: &str, value: &str) -> Result<Option<SbatchOption>, SbatchOptionError> {
+        let Some((flag, _)) = ENV_VARS.iter().find(|(_, env)| *env == name) else {
+            return Ok(None);
+        };
+        let value = (SbatchOption::requires_value(flag) == Some(true)).then_some(value);
+        let option = SbatchOption::from_key_value(flag, value)?;
+        option.validate()?;
+        Ok(Some(option))
+    }
+}
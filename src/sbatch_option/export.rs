@@ -0,0 +1,128 @@
+//! Typed environment propagation for the `--export` option
+
+use std::str::FromStr;
+
+use super::SbatchOptionError;
+
+/// Which environment variables are propagated to the job
+///
+/// - `All`: The whole submission environment (`ALL`), plus any variables listed after it (`ALL,FOO=bar`).
+/// - `None`: No variables from the submission environment (`NONE`).
+/// - `Named`: Only the listed variables (`FOO,BAR=1`).
+///
+/// Each variable is a name, with a value when it is set explicitly (`NAME=value`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ExportMode {
+    All(Vec<(String, Option<String>)>),
+    None,
+    Named(Vec<(String, Option<String>)>),
+}
+
+/// Environment export list
+///
+/// Represents the value of the `--export` option: `ALL`, `NONE`, or a comma-separated list of variables,
+/// optionally preceded by `ALL`. Variable names must be shell identifiers (ASCII letters, digits, and `_`,
+/// not starting with a digit), and may be followed by `=value`. `ALL` and `NONE` are matched
+/// case-insensitively, and `NONE` cannot be combined with variables.
+///
+/// The value is displayed in its canonical form, e.g. `ALL,FOO=bar`.
+///
+/// # Examples
+///
+/// ```
+/// use sbatch_rs::{Export, ExportMode};
+///
+/// let export: Export = "ALL,FOO=bar,HOME".parse().unwrap();
+/// assert!(matches!(export.mode(), ExportMode::All(_)));
+/// assert_eq!(
+///     export.variables(),
+///     [("FOO".to_string(), Some("bar".to_string())), ("HOME".to_string(), None)]
+/// );
+/// assert_eq!(export.to_string(), "ALL,FOO=bar,HOME");
+///
+/// assert!("NONE,FOO".parse::<Export>().is_err());
+/// assert!("1FOO=bar".parse::<Export>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Export {
+    mode: ExportMode,
+}
+
+impl Export {
+    /// Returns which environment variables are propagated.
+    pub fn mode(&self) -> &ExportMode {
+        &self.mode
+    }
+
+    /// Returns the listed variables and their values, in the order they were specified.
+    ///
+    /// This is empty for `ALL` and `NONE` on their own.
+    pub fn variables(&self) -> &[(String, Option<String>)] {
+        match &self.mode {
+            ExportMode::All(variables) | ExportMode::Named(variables) => variables,
+            ExportMode::None => &[],
+        }
+    }
+}
+
+// Helper function to check whether a string is a valid shell variable name.
+// `ALL` and `NONE` are keywords, so they cannot be used as variable names.
+fn is_variable_name(s: &str) -> bool {
+    s.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !s.eq_ignore_ascii_case("ALL")
+        && !s.eq_ignore_ascii_case("NONE")
+}
+
+impl FromStr for Export {
+    type Err = SbatchOptionError;
+
+    /// Parses an export list such as `ALL`, `NONE`, or `ALL,FOO=bar`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SbatchOptionError::InvalidExport(s.to_string());
+
+        let mut entries = s.split(',').peekable();
+        let all = entries.next_if(|entry| entry.eq_ignore_ascii_case("ALL"));
+        if all.is_none() && s.eq_ignore_ascii_case("NONE") {
+            return Ok(Export {
+                mode: ExportMode::None,
+            });
+        }
+
+        let variables = entries
+            .map(|entry| {
+                let (name, value) = match entry.split_once('=') {
+                    Some((name, value)) => (name, Some(value.to_string())),
+                    None => (entry, None),
+                };
+                is_variable_name(name)
+                    .then(|| (name.to_string(), value))
+                    .ok_or_else(invalid)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mode = match all {
+            Some(_) => ExportMode::All(variables),
+            None => ExportMode::Named(variables),
+        };
+        Ok(Export { mode })
+    }
+}
+
+impl std::fmt::Display for Export {
+    /// Displays the export list in its canonical form, e.g. `ALL,FOO=bar`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut entries = match self.mode {
+            ExportMode::All(_) => vec!["ALL".to_string()],
+            ExportMode::None => vec!["NONE".to_string()],
+            ExportMode::Named(_) => Vec::new(),
+        };
+        entries.extend(self.variables().iter().map(|(name, value)| match value {
+            Some(value) => format!("{}={}", name, value),
+            None => name.clone(),
+        }));
+        write!(f, "{}", entries.join(","))
+    }
+}
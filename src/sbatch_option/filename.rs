@@ -0,0 +1,124 @@
+//! Filename pattern substitution for the `--output` and `--error` options
+
+use super::SbatchOption;
+
+/// Values substituted into `--output` and `--error` filename patterns
+///
+/// - `job_id`: Replaces `%j`, and the job part of `%J`.
+/// - `job_name`: Replaces `%x`.
+/// - `array_job_id`: Replaces `%A`.
+/// - `array_task_id`: Replaces `%a`, and `%b` with its last digit.
+/// - `step_id`: Replaces `%s`, and the step part of `%J`.
+/// - `node_name`: Replaces `%N`.
+/// - `node_id`: Replaces `%n`.
+/// - `task_id`: Replaces `%t`.
+/// - `user`: Replaces `%u`.
+///
+/// Tokens whose value is not set are left unchanged.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FilenameContext {
+    pub job_id: Option<u32>,
+    pub job_name: Option<String>,
+    pub array_job_id: Option<u32>,
+    pub array_task_id: Option<u32>,
+    pub step_id: Option<u32>,
+    pub node_name: Option<String>,
+    pub node_id: Option<u32>,
+    pub task_id: Option<u32>,
+    pub user: Option<String>,
+}
+
+impl FilenameContext {
+    // Helper function to get the replacement for a token, zero-padding numbers to `width` digits.
+    fn replacement(&self, token: char, width: usize) -> Option<String> {
+        let number = |value: Option<u32>| value.map(|value| format!("{value:0width$}"));
+        match token {
+            'j' => number(self.job_id),
+            'J' => match self.step_id {
+                Some(step_id) => Some(format!("{}.{}", number(self.job_id)?, step_id)),
+                None => number(self.job_id),
+            },
+            'x' => self.job_name.clone(),
+            'A' => number(self.array_job_id),
+            'a' => number(self.array_task_id),
+            'b' => number(self.array_task_id.map(|id| id % 10)),
+            's' => number(self.step_id),
+            'N' => self.node_name.clone(),
+            'n' => number(self.node_id),
+            't' => number(self.task_id),
+            'u' => self.user.clone(),
+            _ => None,
+        }
+    }
+}
+
+impl SbatchOption {
+    /// Substitutes the `%` tokens of an `--output` or `--error` filename pattern.
+    ///
+    /// This is useful for previewing where the logs of a job will be written. `%%` becomes a literal
+    /// `%`, and a pattern containing a backslash is returned unchanged, as Slurm does not substitute it.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The values to substitute.
+    ///
+    /// # Returns
+    ///
+    /// This function returns the rendered filename, or `None` if the option is not `Output` or `Error`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{FilenameContext, SbatchOption};
+    ///
+    /// let ctx = FilenameContext {
+    ///     job_id: Some(1234),
+    ///     job_name: Some("test".to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let output = SbatchOption::Output("logs/%x-%j.out".to_string());
+    /// assert_eq!(output.render_filename(&ctx).unwrap(), "logs/test-1234.out");
+    ///
+    /// let output = SbatchOption::Output("slurm-%A_%a.out".to_string());
+    /// assert_eq!(output.render_filename(&ctx).unwrap(), "slurm-%A_%a.out");
+    /// ```
+    pub fn render_filename(&self, ctx: &FilenameContext) -> Option<String> {
+        let pattern = match self {
+            SbatchOption::Output(pattern) | SbatchOption::Error(pattern) => pattern,
+            _ => return None,
+        };
+        if pattern.contains('\\') {
+            return Some(pattern.clone());
+        }
+
+        let mut rendered = String::with_capacity(pattern.len());
+        let mut chars = pattern.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                rendered.push(c);
+                continue;
+            }
+            let mut width = String::new();
+            while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+                width.push(digit);
+            }
+            match chars.next() {
+                Some('%') if width.is_empty() => rendered.push('%'),
+                Some(token) => match ctx.replacement(token, width.parse().unwrap_or(0)) {
+                    Some(value) => rendered.push_str(&value),
+                    None => {
+                        rendered.push('%');
+                        rendered.push_str(&width);
+                        rendered.push(token);
+                    }
+                },
+                None => {
+                    rendered.push('%');
+                    rendered.push_str(&width);
+                }
+            }
+        }
+        Some(rendered)
+    }
+}
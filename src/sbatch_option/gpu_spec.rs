@@ -0,0 +1,91 @@
+//! Typed GPU count for the `--gpus` family of options
+
+use std::num::NonZeroU32;
+use std::str::FromStr;
+
+use super::SbatchOptionError;
+
+/// GPU count
+///
+/// Represents the value of the `--gpus`, `--gpus-per-node`, `--gpus-per-socket`, and `--gpus-per-task`
+/// options: `[type:]count`, where the count is a positive integer and the optional type may contain
+/// ASCII letters, digits, `_`, `-`, and `.`.
+///
+/// The value is displayed in its canonical form, e.g. `tesla:4`.
+///
+/// # Examples
+///
+/// ```
+/// use sbatch_rs::GpuSpec;
+///
+/// let gpus: GpuSpec = "tesla:4".parse().unwrap();
+/// assert_eq!(gpus.gpu_type(), Some("tesla"));
+/// assert_eq!(gpus.count().get(), 4);
+/// assert_eq!(gpus.to_string(), "tesla:4");
+///
+/// let gpus: GpuSpec = "2".parse().unwrap();
+/// assert_eq!(gpus.gpu_type(), None);
+/// assert_eq!(gpus.count().get(), 2);
+///
+/// assert!("tesla:".parse::<GpuSpec>().is_err());
+/// assert!("-2".parse::<GpuSpec>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GpuSpec {
+    gpu_type: Option<String>,
+    count: NonZeroU32,
+}
+
+impl GpuSpec {
+    /// Returns the type of GPU requested, such as `tesla`, if given.
+    pub fn gpu_type(&self) -> Option<&str> {
+        self.gpu_type.as_deref()
+    }
+
+    /// Returns the number of GPUs requested.
+    pub fn count(&self) -> NonZeroU32 {
+        self.count
+    }
+}
+
+impl FromStr for GpuSpec {
+    type Err = SbatchOptionError;
+
+    /// Parses a GPU count such as `2` or `tesla:4`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SbatchOptionError::InvalidGpuSpec(s.to_string());
+
+        let (gpu_type, count) = match s.split_once(':') {
+            Some((gpu_type, count)) => (Some(gpu_type), count),
+            None => (None, s),
+        };
+        if gpu_type.is_some_and(|gpu_type| {
+            gpu_type.is_empty()
+                || !gpu_type
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+        }) {
+            return Err(invalid());
+        }
+        if count.is_empty() || !count.chars().all(|c| c.is_ascii_digit()) {
+            return Err(invalid());
+        }
+        let count = count.parse().map_err(|_| invalid())?;
+
+        Ok(GpuSpec {
+            gpu_type: gpu_type.map(str::to_string),
+            count,
+        })
+    }
+}
+
+impl std::fmt::Display for GpuSpec {
+    /// Displays the GPU count in the form `[type:]count`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.gpu_type {
+            Some(gpu_type) => write!(f, "{}:{}", gpu_type, self.count),
+            None => write!(f, "{}", self.count),
+        }
+    }
+}
@@ -0,0 +1,168 @@
+//! Typed generic resources for the `--gres` option
+
+use std::num::NonZeroU32;
+use std::str::FromStr;
+
+use super::SbatchOptionError;
+
+/// A single generic resource request, such as `gpu:tesla:2`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GresEntry {
+    name: String,
+    gres_type: Option<String>,
+    count: Option<NonZeroU32>,
+}
+
+impl GresEntry {
+    /// Returns the name of the resource, such as `gpu`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the type of the resource, such as `tesla`, if given.
+    pub fn gres_type(&self) -> Option<&str> {
+        self.gres_type.as_deref()
+    }
+
+    /// Returns the number of resources requested, if given.
+    pub fn count(&self) -> Option<NonZeroU32> {
+        self.count
+    }
+}
+
+/// Generic resources
+///
+/// Represents the value of the `--gres` option: comma-separated entries of the form
+/// `<name>[[:type]:count]`. Names and types may contain ASCII letters, digits, `_`, `-`, and `.`,
+/// and the count must be a positive integer. When an entry has two parts, the second is the count if
+/// it is numeric and the type otherwise.
+///
+/// The value is displayed in its canonical form, e.g. `gpu:tesla:2,mps:50`.
+///
+/// # Examples
+///
+/// ```
+/// use sbatch_rs::Gres;
+///
+/// let gres: Gres = "gpu:tesla:2,mps:50".parse().unwrap();
+/// let entries = gres.entries();
+/// assert_eq!(entries[0].name(), "gpu");
+/// assert_eq!(entries[0].gres_type(), Some("tesla"));
+/// assert_eq!(entries[0].count().unwrap().get(), 2);
+/// assert_eq!(entries[1].name(), "mps");
+/// assert_eq!(entries[1].gres_type(), None);
+/// assert_eq!(entries[1].count().unwrap().get(), 50);
+/// assert_eq!(gres.to_string(), "gpu:tesla:2,mps:50");
+///
+/// assert!("gpu:".parse::<Gres>().is_err());
+/// assert!("gpu:tesla:0".parse::<Gres>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Gres {
+    entries: Vec<GresEntry>,
+}
+
+impl Gres {
+    /// Returns the requested resources, in the order they were specified.
+    pub fn entries(&self) -> &[GresEntry] {
+        &self.entries
+    }
+}
+
+impl Extend<GresEntry> for Gres {
+    /// Appends resource requests, such as the entries of another `Gres`.
+    fn extend<I: IntoIterator<Item = GresEntry>>(&mut self, iter: I) {
+        self.entries.extend(iter);
+    }
+}
+
+// Helper function to check whether a string is a valid resource name or type.
+fn is_valid_name(s: &str) -> bool {
+    !s.is_empty()
+        && s.chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+}
+
+// Helper function to parse a resource count, which must be a positive integer.
+fn parse_count(s: &str) -> Option<NonZeroU32> {
+    if s.is_empty() || !s.chars().all(|c| c.is_ascii_digit()) {
+        None
+    } else {
+        s.parse().ok()
+    }
+}
+
+impl FromStr for GresEntry {
+    type Err = SbatchOptionError;
+
+    /// Parses a single resource request such as `gpu`, `gpu:2`, or `gpu:tesla:2`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SbatchOptionError::InvalidGres(s.to_string());
+        let parts = s.split(':').collect::<Vec<_>>();
+        let (name, gres_type, count) = match parts.as_slice() {
+            [name] => (*name, None, None),
+            [name, count] if !count.is_empty() && count.chars().all(|c| c.is_ascii_digit()) => {
+                (*name, None, Some(parse_count(count).ok_or_else(invalid)?))
+            }
+            [name, gres_type] => (*name, Some(*gres_type), None),
+            [name, gres_type, count] => (
+                *name,
+                Some(*gres_type),
+                Some(parse_count(count).ok_or_else(invalid)?),
+            ),
+            _ => return Err(invalid()),
+        };
+        if !is_valid_name(name) || gres_type.is_some_and(|gres_type| !is_valid_name(gres_type)) {
+            return Err(invalid());
+        }
+
+        Ok(GresEntry {
+            name: name.to_string(),
+            gres_type: gres_type.map(str::to_string),
+            count,
+        })
+    }
+}
+
+impl FromStr for Gres {
+    type Err = SbatchOptionError;
+
+    /// Parses a comma-separated list of resource requests such as `gpu:tesla:2,mps:50`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let entries = s
+            .split(',')
+            .map(|entry| entry.parse())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| SbatchOptionError::InvalidGres(s.to_string()))?;
+        Ok(Gres { entries })
+    }
+}
+
+impl std::fmt::Display for GresEntry {
+    /// Displays the resource request in the form `<name>[:type][:count]`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(gres_type) = &self.gres_type {
+            write!(f, ":{}", gres_type)?;
+        }
+        if let Some(count) = self.count {
+            write!(f, ":{}", count)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for Gres {
+    /// Displays the resource requests separated by commas.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, entry) in self.entries.iter().enumerate() {
+            if index > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}", entry)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+//! Typed memory value for the `--mem`, `--mem-per-cpu`, and `--mem-per-gpu` options
+
+use std::str::FromStr;
+
+use super::SbatchOptionError;
+
+/// Unit suffix of a memory value
+///
+/// Slurm interprets a memory value without a suffix as megabytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MemoryUnit {
+    /// Maps to the `K` suffix
+    Kilobytes,
+    /// Maps to the `M` suffix
+    Megabytes,
+    /// Maps to the `G` suffix
+    Gigabytes,
+    /// Maps to the `T` suffix
+    Terabytes,
+}
+
+impl MemoryUnit {
+    // Helper function to get the number of bytes in one unit.
+    fn multiplier(&self) -> u64 {
+        match self {
+            MemoryUnit::Kilobytes => 1 << 10,
+            MemoryUnit::Megabytes => 1 << 20,
+            MemoryUnit::Gigabytes => 1 << 30,
+            MemoryUnit::Terabytes => 1 << 40,
+        }
+    }
+
+    // Helper function to get the suffix of the unit.
+    fn suffix(&self) -> char {
+        match self {
+            MemoryUnit::Kilobytes => 'K',
+            MemoryUnit::Megabytes => 'M',
+            MemoryUnit::Gigabytes => 'G',
+            MemoryUnit::Terabytes => 'T',
+        }
+    }
+}
+
+/// Memory value
+///
+/// Represents a Slurm memory specification: a non-negative integer followed by an optional
+/// `K`, `M`, `G`, or `T` suffix (case-insensitive). A value of `0` requests all of the memory on a node.
+///
+/// # Examples
+///
+/// ```
+/// use sbatch_rs::{Memory, MemoryUnit};
+///
+/// let memory: Memory = "4g".parse().unwrap();
+/// assert_eq!(memory.as_bytes(), 4 * 1024 * 1024 * 1024);
+/// assert_eq!(memory.unit(), Some(MemoryUnit::Gigabytes));
+/// assert_eq!(memory.to_string(), "4G");
+///
+/// // Values without a suffix are megabytes
+/// let memory: Memory = "512".parse().unwrap();
+/// assert_eq!(memory.as_bytes(), 512 * 1024 * 1024);
+/// assert_eq!(memory.to_string(), "512");
+///
+/// assert!("-4G".parse::<Memory>().is_err());
+/// assert!("4X".parse::<Memory>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Memory {
+    bytes: u64,
+    unit: Option<MemoryUnit>,
+}
+
+impl Memory {
+    /// Returns the memory value in bytes.
+    pub fn as_bytes(&self) -> u64 {
+        self.bytes
+    }
+
+    /// Returns the unit suffix the value was specified with, if any.
+    pub fn unit(&self) -> Option<MemoryUnit> {
+        self.unit
+    }
+
+    /// Returns `true` if the value is `0`, which Slurm treats as all of the memory on a node.
+    pub fn is_zero(&self) -> bool {
+        self.bytes == 0
+    }
+}
+
+impl FromStr for Memory {
+    type Err = SbatchOptionError;
+
+    /// Parses a memory value such as `4G`, `512M`, or `0`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SbatchOptionError::InvalidMemory(s.to_string());
+
+        // Split the number from the optional unit suffix
+        let (amount, unit) = match s.char_indices().last() {
+            Some((i, c)) if c.is_ascii_alphabetic() => {
+                let unit = match c.to_ascii_uppercase() {
+                    'K' => MemoryUnit::Kilobytes,
+                    'M' => MemoryUnit::Megabytes,
+                    'G' => MemoryUnit::Gigabytes,
+                    'T' => MemoryUnit::Terabytes,
+                    _ => return Err(invalid()),
+                };
+                (&s[..i], Some(unit))
+            }
+            _ => (s, None),
+        };
+
+        if amount.is_empty() || !amount.chars().all(|c| c.is_ascii_digit()) {
+            return Err(invalid());
+        }
+        let bytes = amount
+            .parse::<u64>()
+            .ok()
+            .and_then(|amount| {
+                amount.checked_mul(unit.unwrap_or(MemoryUnit::Megabytes).multiplier())
+            })
+            .ok_or_else(invalid)?;
+
+        Ok(Memory { bytes, unit })
+    }
+}
+
+impl std::fmt::Display for Memory {
+    /// Displays the memory value in its canonical form, e.g. `4G`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let unit = self.unit.unwrap_or(MemoryUnit::Megabytes);
+        write!(f, "{}", self.bytes / unit.multiplier())?;
+        match self.unit {
+            Some(unit) => write!(f, "{}", unit.suffix()),
+            None => Ok(()),
+        }
+    }
+}
@@ -1,43 +1,91 @@
+mod array_spec;
+mod begin_time;
+mod constraint;
+mod cpu_freq;
 mod display;
+mod distribution;
+mod env;
+mod export;
+mod filename;
+mod gpu_spec;
+mod gres;
+mod memory;
+mod name;
+mod node_list;
+mod open_mode;
+mod parse;
+mod signal_spec;
+mod spec;
+mod time_limit;
 mod validate;
 
+pub use array_spec::ArraySpec;
+pub use begin_time::{BeginTime, BeginUnit};
+pub use constraint::{Constraint, ConstraintExpr};
+pub use cpu_freq::{CpuFreq, CpuFrequency, CpuGovernor};
+pub use display::{DisplayStyle, shell_quote};
+pub use distribution::{CpuDistribution, Distribution, NodeDistribution};
+pub use export::{Export, ExportMode};
+pub use filename::FilenameContext;
+pub use gpu_spec::GpuSpec;
+pub use gres::{Gres, GresEntry};
+pub use memory::{Memory, MemoryUnit};
+pub use node_list::NodeList;
+pub use open_mode::OpenMode;
+pub use parse::{LocatedError, Normalization, ParseOptions, UnknownPolicy, Warning};
+pub use signal_spec::{SignalScope, SignalSpec};
+pub use spec::{OptionKind, OptionSpec};
+pub use time_limit::TimeLimit;
+
+use std::cmp::Ordering;
+use std::num::NonZeroU32;
 use thiserror::Error;
 
 /// Represents an sbatch option
 ///
 /// For a full description of the sbatch options, see the slurm documentation: <https://slurm.schedmd.com/sbatch.html>
 /// The `SbatchOption` enum variants store Nothing, `String`, or Option<`String`> where appropriate.
-/// For more complex options, such as `--dependency`, the helper `Dependency` struct can be used to format the string.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// Options with a well-defined grammar, such as `--array`, `--begin`, `--constraint`, `--cpu-freq`, `--distribution`, `--export`, `--gpus`, `--gres`, `--mem`, `--nodelist`, `--signal`, and `--time`,
+/// store typed values (`ArraySpec`, `BeginTime`, `Constraint`, `CpuFreq`, `Distribution`, `Export`, `GpuSpec`, `Gres`, `Memory`, `NodeList`, `OpenMode`, `SignalSpec`, `TimeLimit`), which are validated when they are parsed. The counts
+/// `--cores-per-socket`, `--ntasks`, `--ntasks-per-node`, and `--sockets-per-node` store a `NonZeroU32`,
+/// `--core-spec` stores a `u32` since `0` is a valid core count, and the `--nice` adjustment stores an `i32`.
+/// For more complex options, such as `--dependency`, the helper `Dependency` struct can be used to format the string,
+/// or set directly with `Sbatch::set_dependency`.
+/// Options that are not yet modeled can be passed through unchanged with `SbatchOption::Raw`.
+///
+/// Options are ordered by flag name and then by value, so sorting a set of options places each flag
+/// at the same position whatever its value.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SbatchOption {
     Account(String),
     AcctgFreq(String),
-    Array(String),
+    Array(ArraySpec),
     Batch(String),
     Bb(String),
     Bbf(String),
-    Begin(String),
+    Begin(BeginTime),
     Chdir(String),
     ClusterConstraint(String),
     Clusters(String),
     Comment(String),
-    Constraint(String),
+    Constraint(Constraint),
     Container(String),
     ContainerID(String),
     Contiguous,
-    CoreSpec(String),
-    CoresPerSocket(String),
-    CPUFreq(String),
+    CoreSpec(u32),
+    CoresPerSocket(NonZeroU32),
+    CPUFreq(CpuFreq),
     CPUsPerGPU(String),
     CPUsPerTask(String),
     Deadline(String),
     DelayBoot(String),
     Dependency(String),
-    Distribution(String),
+    Distribution(Distribution),
     Error(String),
-    Exclude(String),
+    Exclude(NodeList),
     Exclusive(Option<String>),
-    Export(String),
+    Export(Export),
     ExportFile(String),
     Extra(String),
     ExtraNodeInfo(String),
@@ -45,11 +93,11 @@ pub enum SbatchOption {
     GID(String),
     GPUBind(String),
     GPUFreq(String),
-    GPUs(String),
-    GPUsPerNode(String),
-    GPUsPerSocket(String),
-    GPUsPerTask(String),
-    Gres(String),
+    GPUs(GpuSpec),
+    GPUsPerNode(GpuSpec),
+    GPUsPerSocket(GpuSpec),
+    GPUsPerTask(GpuSpec),
+    Gres(Gres),
     GresFlags(String),
     Help,
     Hint(String),
@@ -62,25 +110,25 @@ pub enum SbatchOption {
     MailType(String),
     MailUser(String),
     McsLabel(String),
-    Mem(String),
+    Mem(Memory),
     MemBind(String),
-    MemPerCPU(String),
-    MemPerGPU(String),
+    MemPerCPU(Memory),
+    MemPerGPU(Memory),
     MinCPUs(String),
     Network(String),
-    Nice(Option<String>),
+    Nice(Option<i32>),
     NoKill(Option<String>),
     NoRequeue,
     NodeFile(String),
-    NodeList(String),
+    NodeList(NodeList),
     Nodes(String),
-    NTasks(String),
+    NTasks(NonZeroU32),
     NTasksPerCore(String),
     NTasksPerGPU(String),
-    NTasksPerNode(String),
+    NTasksPerNode(NonZeroU32),
     NTasksPerSocket(String),
     OOMKillStep(Option<String>),
-    OpenMode(String),
+    OpenMode(OpenMode),
     Output(String),
     Overcommit,
     Oversubscribe,
@@ -92,21 +140,23 @@ pub enum SbatchOption {
     Propagate(Option<String>),
     Qos(String),
     Quiet,
+    /// An option not otherwise modeled by `SbatchOption` (e.g. `--newfangled-flag=x`). The flag is emitted verbatim and its value is quoted
+    Raw(String),
     Reboot,
     Requeue,
     Reservation(String),
     ResvPorts(Option<String>),
     Segment(String),
-    Signal(String),
-    SocketsPerNode(String),
+    Signal(SignalSpec),
+    SocketsPerNode(NonZeroU32),
     SpreadJob,
     Stepmgr,
     Switches(String),
     TestOnly,
     ThreadSpec(String),
     ThreadsPerCore(String),
-    Time(String),
-    TimeMin(String),
+    Time(TimeLimit),
+    TimeMin(TimeLimit),
     Tmp(String),
     TresBind(String),
     TresPerTask(String),
@@ -121,10 +171,171 @@ pub enum SbatchOption {
     Wrap(String),
 }
 
+impl SbatchOption {
+    /// Checks whether two options are the same variant, ignoring their values.
+    ///
+    /// `Raw` options are the same variant when they contain the same flag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::SbatchOption;
+    ///
+    /// let a = SbatchOption::JobName("a".to_string());
+    /// assert!(a.is_same_variant(&SbatchOption::JobName("b".to_string())));
+    /// assert!(!a.is_same_variant(&SbatchOption::Output("a".to_string())));
+    ///
+    /// let raw = SbatchOption::Raw("--x=1".to_string());
+    /// assert!(raw.is_same_variant(&SbatchOption::Raw("--x=2".to_string())));
+    /// assert!(!raw.is_same_variant(&SbatchOption::Raw("--y=1".to_string())));
+    /// ```
+    pub fn is_same_variant(&self, other: &SbatchOption) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+            && self.sort_name() == other.sort_name()
+    }
+
+    /// Checks whether the option holds a comma-separated list that a second option of the same variant
+    /// can be merged into, such as `--gres` and `--licenses`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::SbatchOption;
+    ///
+    /// assert!(SbatchOption::Licenses("matlab:1".to_string()).is_mergeable());
+    /// assert!(!SbatchOption::JobName("test".to_string()).is_mergeable());
+    /// ```
+    pub fn is_mergeable(&self) -> bool {
+        matches!(self, SbatchOption::Gres(_) | SbatchOption::Licenses(_))
+    }
+
+    /// Combines two options of the same mergeable variant into one, appending the values of `other`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The option whose values are appended.
+    ///
+    /// # Returns
+    ///
+    /// This function returns the combined option, or `None` if the options are not the same variant or the
+    /// variant is not mergeable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::SbatchOption;
+    ///
+    /// let licenses = SbatchOption::Licenses("matlab:1".to_string())
+    ///     .merge_value(SbatchOption::Licenses("fluent:2".to_string()));
+    /// assert_eq!(licenses, Some(SbatchOption::Licenses("matlab:1,fluent:2".to_string())));
+    ///
+    /// let job_name = SbatchOption::JobName("a".to_string())
+    ///     .merge_value(SbatchOption::JobName("b".to_string()));
+    /// assert_eq!(job_name, None);
+    /// ```
+    pub fn merge_value(self, other: SbatchOption) -> Option<SbatchOption> {
+        match (self, other) {
+            (SbatchOption::Gres(mut gres), SbatchOption::Gres(other)) => {
+                gres.extend(other.entries().iter().cloned());
+                Some(SbatchOption::Gres(gres))
+            }
+            (SbatchOption::Licenses(licenses), SbatchOption::Licenses(other)) => {
+                Some(SbatchOption::Licenses(format!("{},{}", licenses, other)))
+            }
+            _ => None,
+        }
+    }
+
+    // Helper function to get the flag name used for ordering.
+    // Raw options are ordered by the flag they contain, e.g. `newfangled-flag` for `--newfangled-flag=x`.
+    fn sort_name(&self) -> &str {
+        match self {
+            SbatchOption::Raw(value) => value
+                .trim_start_matches('-')
+                .split(['=', ' '])
+                .next()
+                .unwrap_or_default(),
+            _ => self.canonical_name(),
+        }
+    }
+}
+
+impl Ord for SbatchOption {
+    /// Orders options by flag name, then by value.
+    ///
+    /// A `Raw` option sorts after a modeled option with the same flag and value.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sort_name()
+            .cmp(other.sort_name())
+            .then_with(|| self.to_string().cmp(&other.to_string()))
+            .then_with(|| {
+                matches!(self, SbatchOption::Raw(_)).cmp(&matches!(other, SbatchOption::Raw(_)))
+            })
+    }
+}
+
+impl PartialOrd for SbatchOption {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum SbatchOptionError {
     #[error("Empty string")]
     EmptyString,
     #[error("Leading or trailing spaces")]
     LeadingOrTrailingSpaces,
+    #[error("Invalid memory value: {0}")]
+    InvalidMemory(String),
+    #[error("Invalid time limit: {0}")]
+    InvalidTimeLimit(String),
+    #[error("Invalid begin time: {0}")]
+    InvalidBeginTime(String),
+    #[error("Invalid constraint: {0}")]
+    InvalidConstraint(String),
+    #[error("Invalid CPU frequency: {0}")]
+    InvalidCpuFreq(String),
+    #[error("Invalid distribution: {0}")]
+    InvalidDistribution(String),
+    #[error("Invalid exclusive value: {0}")]
+    InvalidExclusiveValue(String),
+    #[error("Invalid export list: {0}")]
+    InvalidExport(String),
+    #[error("Invalid GPU count: {0}")]
+    InvalidGpuSpec(String),
+    #[error("Invalid generic resources: {0}")]
+    InvalidGres(String),
+    #[error("Invalid mail type: {0}")]
+    InvalidMailType(String),
+    #[error("Comment contains a newline: {0:?}")]
+    InvalidComment(String),
+    #[error("Invalid array specification: {0}")]
+    InvalidArraySpec(String),
+    #[error("Array specification expands to more than {0} task ids")]
+    ArrayTooLarge(usize),
+    #[error("Invalid count: {0}")]
+    InvalidCount(String),
+    #[error("Invalid nice value: {0}")]
+    InvalidNice(String),
+    #[error("Nice value out of range: {0}")]
+    NiceOutOfRange(i32),
+    #[error("Invalid signal: {0}")]
+    InvalidSignal(String),
+    #[error("Invalid filename pattern: {0}")]
+    InvalidFilenamePattern(String),
+    #[error("Invalid node list: {0}")]
+    InvalidNodeList(String),
+    #[error("Node list expands to more than {0} names")]
+    NodeListTooLarge(usize),
+    #[error("Invalid open mode: {0}")]
+    InvalidOpenMode(String),
+    #[error("Invalid wait-all-nodes value, expected 0 or 1: {0}")]
+    InvalidWaitAllNodes(String),
+    #[error("Invalid raw option, expected a flag starting with '-': {0}")]
+    InvalidRawOption(String),
+    #[error("Unknown argument: {0}")]
+    UnknownArgument(String),
+    #[error("Missing value for argument: {0}")]
+    MissingValue(String),
 }
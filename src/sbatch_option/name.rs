@@ -0,0 +1,183 @@
+//! Flag names for `SbatchOption`
+
+use super::SbatchOption;
+
+impl SbatchOption {
+    /// Returns the long flag name of the option, without the leading dashes.
+    ///
+    /// `SbatchOption::Raw` has no fixed name, so an empty string is returned for it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::SbatchOption;
+    ///
+    /// let option = SbatchOption::JobName("test".to_string());
+    /// assert_eq!(option.canonical_name(), "job-name");
+    /// assert_eq!(SbatchOption::Raw("--x".to_string()).canonical_name(), "");
+    /// ```
+    pub fn canonical_name(&self) -> &'static str {
+        match self {
+            SbatchOption::Account(_) => "account",
+            SbatchOption::AcctgFreq(_) => "acctg-freq",
+            SbatchOption::Array(_) => "array",
+            SbatchOption::Batch(_) => "batch",
+            SbatchOption::Bb(_) => "bb",
+            SbatchOption::Bbf(_) => "bbf",
+            SbatchOption::Begin(_) => "begin",
+            SbatchOption::Chdir(_) => "chdir",
+            SbatchOption::ClusterConstraint(_) => "cluster-constraint",
+            SbatchOption::Clusters(_) => "clusters",
+            SbatchOption::Comment(_) => "comment",
+            SbatchOption::Constraint(_) => "constraint",
+            SbatchOption::Container(_) => "container",
+            SbatchOption::ContainerID(_) => "container-id",
+            SbatchOption::Contiguous => "contiguous",
+            SbatchOption::CoreSpec(_) => "core-spec",
+            SbatchOption::CoresPerSocket(_) => "cores-per-socket",
+            SbatchOption::CPUFreq(_) => "cpu-freq",
+            SbatchOption::CPUsPerGPU(_) => "cpus-per-gpu",
+            SbatchOption::CPUsPerTask(_) => "cpus-per-task",
+            SbatchOption::Deadline(_) => "deadline",
+            SbatchOption::DelayBoot(_) => "delay-boot",
+            SbatchOption::Dependency(_) => "dependency",
+            SbatchOption::Distribution(_) => "distribution",
+            SbatchOption::Error(_) => "error",
+            SbatchOption::Exclude(_) => "exclude",
+            SbatchOption::Exclusive(_) => "exclusive",
+            SbatchOption::Export(_) => "export",
+            SbatchOption::ExportFile(_) => "export-file",
+            SbatchOption::Extra(_) => "extra",
+            SbatchOption::ExtraNodeInfo(_) => "extra-node-info",
+            SbatchOption::GetUserEnv(_) => "get-user-env",
+            SbatchOption::GID(_) => "gid",
+            SbatchOption::GPUBind(_) => "gpu-bind",
+            SbatchOption::GPUFreq(_) => "gpu-freq",
+            SbatchOption::GPUs(_) => "gpus",
+            SbatchOption::GPUsPerNode(_) => "gpus-per-node",
+            SbatchOption::GPUsPerSocket(_) => "gpus-per-socket",
+            SbatchOption::GPUsPerTask(_) => "gpus-per-task",
+            SbatchOption::Gres(_) => "gres",
+            SbatchOption::GresFlags(_) => "gres-flags",
+            SbatchOption::Help => "help",
+            SbatchOption::Hint(_) => "hint",
+            SbatchOption::Hold => "hold",
+            SbatchOption::IgnorePbs => "ignore-pbs",
+            SbatchOption::Input(_) => "input",
+            SbatchOption::JobName(_) => "job-name",
+            SbatchOption::KillOnInvalidDep(_) => "kill-on-invalid-dep",
+            SbatchOption::Licenses(_) => "licenses",
+            SbatchOption::MailType(_) => "mail-type",
+            SbatchOption::MailUser(_) => "mail-user",
+            SbatchOption::McsLabel(_) => "mcs-label",
+            SbatchOption::Mem(_) => "mem",
+            SbatchOption::MemBind(_) => "mem-bind",
+            SbatchOption::MemPerCPU(_) => "mem-per-cpu",
+            SbatchOption::MemPerGPU(_) => "mem-per-gpu",
+            SbatchOption::MinCPUs(_) => "min-cpus",
+            SbatchOption::Network(_) => "network",
+            SbatchOption::Nice(_) => "nice",
+            SbatchOption::NoKill(_) => "no-kill",
+            SbatchOption::NoRequeue => "no-requeue",
+            SbatchOption::NodeFile(_) => "nodefile",
+            SbatchOption::NodeList(_) => "nodelist",
+            SbatchOption::Nodes(_) => "nodes",
+            SbatchOption::NTasks(_) => "ntasks",
+            SbatchOption::NTasksPerCore(_) => "ntasks-per-core",
+            SbatchOption::NTasksPerGPU(_) => "ntasks-per-gpu",
+            SbatchOption::NTasksPerNode(_) => "ntasks-per-node",
+            SbatchOption::NTasksPerSocket(_) => "ntasks-per-socket",
+            SbatchOption::OOMKillStep(_) => "oom-kill-step",
+            SbatchOption::OpenMode(_) => "open-mode",
+            SbatchOption::Output(_) => "output",
+            SbatchOption::Overcommit => "overcommit",
+            SbatchOption::Oversubscribe => "oversubscribe",
+            SbatchOption::Parsable => "parsable",
+            SbatchOption::Partition(_) => "partition",
+            SbatchOption::Prefer(_) => "prefer",
+            SbatchOption::Priority(_) => "priority",
+            SbatchOption::Profile(_) => "profile",
+            SbatchOption::Propagate(_) => "propagate",
+            SbatchOption::Qos(_) => "qos",
+            SbatchOption::Quiet => "quiet",
+            SbatchOption::Reboot => "reboot",
+            SbatchOption::Requeue => "requeue",
+            SbatchOption::Reservation(_) => "reservation",
+            SbatchOption::ResvPorts(_) => "resv-ports",
+            SbatchOption::Segment(_) => "segment",
+            SbatchOption::Signal(_) => "signal",
+            SbatchOption::SocketsPerNode(_) => "sockets-per-node",
+            SbatchOption::SpreadJob => "spread-job",
+            SbatchOption::Stepmgr => "stepmgr",
+            SbatchOption::Switches(_) => "switches",
+            SbatchOption::TestOnly => "test-only",
+            SbatchOption::ThreadSpec(_) => "thread-spec",
+            SbatchOption::ThreadsPerCore(_) => "threads-per-core",
+            SbatchOption::Time(_) => "time",
+            SbatchOption::TimeMin(_) => "time-min",
+            SbatchOption::Tmp(_) => "tmp",
+            SbatchOption::TresBind(_) => "tres-bind",
+            SbatchOption::TresPerTask(_) => "tres-per-task",
+            SbatchOption::UID(_) => "uid",
+            SbatchOption::Usage => "usage",
+            SbatchOption::UseMinNodes => "use-min-nodes",
+            SbatchOption::Verbose => "verbose",
+            SbatchOption::Version => "version",
+            SbatchOption::Wait => "wait",
+            SbatchOption::WaitAllNodes(_) => "wait-all-nodes",
+            SbatchOption::WCKey(_) => "wckey",
+            SbatchOption::Wrap(_) => "wrap",
+            SbatchOption::Raw(_) => "",
+        }
+    }
+
+    /// Returns the single-character short flag of the option, if sbatch defines one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::SbatchOption;
+    ///
+    /// assert_eq!(SbatchOption::JobName("test".to_string()).short_flag(), Some('J'));
+    /// assert_eq!(SbatchOption::Comment("test".to_string()).short_flag(), None);
+    /// ```
+    pub fn short_flag(&self) -> Option<char> {
+        match self {
+            SbatchOption::Account(_) => Some('A'),
+            SbatchOption::Array(_) => Some('a'),
+            SbatchOption::Begin(_) => Some('b'),
+            SbatchOption::Chdir(_) => Some('D'),
+            SbatchOption::Clusters(_) => Some('M'),
+            SbatchOption::Constraint(_) => Some('C'),
+            SbatchOption::CoreSpec(_) => Some('S'),
+            SbatchOption::CPUsPerTask(_) => Some('c'),
+            SbatchOption::Dependency(_) => Some('d'),
+            SbatchOption::Distribution(_) => Some('m'),
+            SbatchOption::Error(_) => Some('e'),
+            SbatchOption::Exclude(_) => Some('x'),
+            SbatchOption::ExtraNodeInfo(_) => Some('B'),
+            SbatchOption::GPUs(_) => Some('G'),
+            SbatchOption::Help => Some('h'),
+            SbatchOption::Hold => Some('H'),
+            SbatchOption::Input(_) => Some('i'),
+            SbatchOption::JobName(_) => Some('J'),
+            SbatchOption::Licenses(_) => Some('L'),
+            SbatchOption::NoKill(_) => Some('k'),
+            SbatchOption::NodeFile(_) => Some('F'),
+            SbatchOption::NodeList(_) => Some('w'),
+            SbatchOption::Nodes(_) => Some('N'),
+            SbatchOption::NTasks(_) => Some('n'),
+            SbatchOption::Output(_) => Some('o'),
+            SbatchOption::Overcommit => Some('O'),
+            SbatchOption::Oversubscribe => Some('s'),
+            SbatchOption::Partition(_) => Some('p'),
+            SbatchOption::Qos(_) => Some('q'),
+            SbatchOption::Quiet => Some('Q'),
+            SbatchOption::Time(_) => Some('t'),
+            SbatchOption::Verbose => Some('v'),
+            SbatchOption::Version => Some('V'),
+            SbatchOption::Wait => Some('W'),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,236 @@
+//! Typed hostlist expression for the `--nodelist` and `--exclude` options
+
+use std::str::FromStr;
+
+use super::SbatchOptionError;
+
+/// An inclusive range of node numbers inside brackets, zero-padded to `width` digits
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct HostRange {
+    start: u32,
+    end: u32,
+    width: usize,
+}
+
+/// A piece of a host name: literal text, or a bracketed list of ranges
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum HostPart {
+    Text(String),
+    Ranges(Vec<HostRange>),
+}
+
+/// Node list
+///
+/// Represents the value of the `--nodelist` and `--exclude` options in Slurm hostlist syntax:
+/// comma-separated host names, where bracketed lists of numbers and ranges stand for several hosts
+/// (`node[1-4,7]`). A name may contain several brackets (`rack[1-2]node[01-02]`), and ranges whose
+/// lower bound has leading zeros are zero-padded to its width.
+///
+/// A file name given in place of a list is kept as a single entry.
+///
+/// # Examples
+///
+/// ```
+/// use sbatch_rs::NodeList;
+///
+/// let nodes: NodeList = "node[1-4,7]".parse().unwrap();
+/// assert_eq!(nodes.expand().unwrap(), vec!["node1", "node2", "node3", "node4", "node7"]);
+/// assert_eq!(nodes.to_string(), "node[1-4,7]");
+///
+/// let nodes: NodeList = "rack[1-2]node[01-02]".parse().unwrap();
+/// assert_eq!(
+///     nodes.expand().unwrap(),
+///     vec!["rack1node01", "rack1node02", "rack2node01", "rack2node02"]
+/// );
+///
+/// assert!("node[1-".parse::<NodeList>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NodeList {
+    hosts: Vec<Vec<HostPart>>,
+}
+
+impl NodeList {
+    /// The default maximum number of node names expanded by `expand`.
+    pub const DEFAULT_NODE_LIMIT: usize = 100_000;
+
+    /// Expands the node list into the concrete node names, in the order they were specified.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchOptionError::NodeListTooLarge` if the node list expands to
+    /// more than `NodeList::DEFAULT_NODE_LIMIT` names.
+    pub fn expand(&self) -> Result<Vec<String>, SbatchOptionError> {
+        self.expand_with_limit(Self::DEFAULT_NODE_LIMIT)
+    }
+
+    /// Expands the node list into the concrete node names, erroring past `limit` names.
+    ///
+    /// The number of names is checked before any of them are built.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchOptionError::NodeListTooLarge` if the node list expands to
+    /// more than `limit` names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::NodeList;
+    ///
+    /// let nodes: NodeList = "node[0-999]".parse().unwrap();
+    /// assert_eq!(nodes.expand_with_limit(1000).unwrap().len(), 1000);
+    /// assert!(nodes.expand_with_limit(999).is_err());
+    /// ```
+    pub fn expand_with_limit(&self, limit: usize) -> Result<Vec<String>, SbatchOptionError> {
+        let count = self.hosts.iter().try_fold(0usize, |total, parts| {
+            let count = parts.iter().try_fold(1usize, |product, part| match part {
+                HostPart::Text(_) => Some(product),
+                HostPart::Ranges(ranges) => product.checked_mul(
+                    ranges
+                        .iter()
+                        .map(|range| (range.end - range.start) as usize + 1)
+                        .sum(),
+                ),
+            })?;
+            total.checked_add(count)
+        });
+        if count.is_none_or(|count| count > limit) {
+            return Err(SbatchOptionError::NodeListTooLarge(limit));
+        }
+
+        let mut names = Vec::new();
+        for parts in &self.hosts {
+            let mut prefixes = vec![String::new()];
+            for part in parts {
+                prefixes = match part {
+                    HostPart::Text(text) => {
+                        prefixes.into_iter().map(|prefix| prefix + text).collect()
+                    }
+                    HostPart::Ranges(ranges) => prefixes
+                        .iter()
+                        .flat_map(|prefix| {
+                            ranges.iter().flat_map(move |range| {
+                                (range.start..=range.end).map(move |number| {
+                                    format!("{prefix}{number:0width$}", width = range.width)
+                                })
+                            })
+                        })
+                        .collect(),
+                };
+            }
+            names.extend(prefixes);
+        }
+        Ok(names)
+    }
+}
+
+// Helper function to parse a bracketed list of ranges such as `1-4,7` or `01-10`.
+fn parse_ranges(s: &str) -> Option<Vec<HostRange>> {
+    let parse = |s: &str| {
+        if s.is_empty() || !s.chars().all(|c| c.is_ascii_digit()) {
+            None
+        } else {
+            s.parse::<u32>().ok()
+        }
+    };
+
+    s.split(',')
+        .map(|range| {
+            let (start, end) = range.split_once('-').unwrap_or((range, range));
+            let width = start.len();
+            let (start, end) = (parse(start)?, parse(end)?);
+            (start <= end).then_some(HostRange { start, end, width })
+        })
+        .collect()
+}
+
+// Helper function to parse a single host name such as `node[1-4]` into its parts.
+fn parse_host(s: &str) -> Option<Vec<HostPart>> {
+    let mut parts = Vec::new();
+    let mut rest = s;
+    while !rest.is_empty() {
+        match rest.strip_prefix('[') {
+            Some(inner) => {
+                let (ranges, after) = inner.split_once(']')?;
+                parts.push(HostPart::Ranges(parse_ranges(ranges)?));
+                rest = after;
+            }
+            None => {
+                let end = rest.find('[').unwrap_or(rest.len());
+                let text = &rest[..end];
+                if text.contains([']', ',']) || text.contains(char::is_whitespace) {
+                    return None;
+                }
+                parts.push(HostPart::Text(text.to_string()));
+                rest = &rest[end..];
+            }
+        }
+    }
+    (!parts.is_empty()).then_some(parts)
+}
+
+impl FromStr for NodeList {
+    type Err = SbatchOptionError;
+
+    /// Parses a hostlist expression such as `node[1-4,7]` or `a01,b[1-2]`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Split on the commas that are outside brackets
+        let mut names = Vec::new();
+        let mut depth = 0;
+        let mut start = 0;
+        for (index, c) in s.char_indices() {
+            match c {
+                '[' => depth += 1,
+                ']' => depth -= 1,
+                ',' if depth == 0 => {
+                    names.push(&s[start..index]);
+                    start = index + 1;
+                }
+                _ => {}
+            }
+        }
+        names.push(&s[start..]);
+
+        let hosts = names
+            .into_iter()
+            .map(parse_host)
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| SbatchOptionError::InvalidNodeList(s.to_string()))?;
+        Ok(NodeList { hosts })
+    }
+}
+
+impl std::fmt::Display for NodeList {
+    /// Displays the node list in Slurm hostlist syntax.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, parts) in self.hosts.iter().enumerate() {
+            if index > 0 {
+                write!(f, ",")?;
+            }
+            for part in parts {
+                match part {
+                    HostPart::Text(text) => write!(f, "{}", text)?,
+                    HostPart::Ranges(ranges) => {
+                        let ranges = ranges
+                            .iter()
+                            .map(|HostRange { start, end, width }| {
+                                if start == end {
+                                    format!("{start:0width$}")
+                                } else {
+                                    format!("{start:0width$}-{end:0width$}")
+                                }
+                            })
+                            .collect::<Vec<_>>()
+                            .join(",");
+                        write!(f, "[{}]", ranges)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+//! Typed value for the `--open-mode` option
+
+use std::str::FromStr;
+
+use super::SbatchOptionError;
+
+/// Mode used to open the output and error files
+///
+/// Represents the value of the `--open-mode` option, either `append` or `truncate` (case-insensitive).
+///
+/// # Examples
+///
+/// ```
+/// use sbatch_rs::OpenMode;
+///
+/// let mode: OpenMode = "Append".parse().unwrap();
+/// assert_eq!(mode, OpenMode::Append);
+/// assert_eq!(mode.to_string(), "append");
+///
+/// assert!("overwrite".parse::<OpenMode>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OpenMode {
+    /// Maps to `append`, adding to existing files
+    Append,
+    /// Maps to `truncate`, replacing existing files
+    Truncate,
+}
+
+impl FromStr for OpenMode {
+    type Err = SbatchOptionError;
+
+    /// Parses an open mode such as `append` or `truncate`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "append" => Ok(OpenMode::Append),
+            "truncate" => Ok(OpenMode::Truncate),
+            _ => Err(SbatchOptionError::InvalidOpenMode(s.to_string())),
+        }
+    }
+}
+
+impl std::fmt::Display for OpenMode {
+    /// Displays the open mode as `append` or `truncate`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OpenMode::Append => write!(f, "append"),
+            OpenMode::Truncate => write!(f, "truncate"),
+        }
+    }
+}
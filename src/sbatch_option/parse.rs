@@ -0,0 +1,899 @@
+//! Parsing implementation for `SbatchOption`
+
+use std::num::NonZeroU32;
+use std::ops::Range;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use super::{SbatchOption, SbatchOptionError};
+
+/// Options controlling how strictly sbatch options are parsed
+///
+/// The default is strict parsing, which is what `SbatchOption::from_str` uses.
+/// Lenient parsing is useful when ingesting hand-written or newer scripts.
+///
+/// - `normalize_underscores`: Treat underscores in long option names as dashes (e.g. `--job_name`).
+/// - `case_insensitive`: Match long option names case-insensitively (e.g. `--Job-Name`).
+/// - `join_comment_lines`: Replace newlines in `--comment` values with spaces instead of returning an error.
+/// - `on_unknown`: What to do with an unknown option.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParseOptions {
+    pub normalize_underscores: bool,
+    pub case_insensitive: bool,
+    pub join_comment_lines: bool,
+    pub on_unknown: UnknownPolicy,
+}
+
+/// What to do with an option that is not recognized
+///
+/// - `Error`: Fail with the parse error, as `SbatchOption::from_str` and `Sbatch::from_script` do.
+/// - `Passthrough`: Accept the option as `SbatchOption::Raw`.
+/// - `Skip`: Ignore the job script directive. A single option parsed with `SbatchOption::from_str_with`
+///   still fails, as there is nothing to return in its place.
+/// - `Collect`: Ignore the job script directive, but return its line so it can be reported.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum UnknownPolicy {
+    #[default]
+    Error,
+    Passthrough,
+    Skip,
+    Collect,
+}
+
+impl ParseOptions {
+    /// Creates strict parse options, rejecting anything that is not an exact, known option.
+    pub fn strict() -> Self {
+        Self::default()
+    }
+
+    /// Creates lenient parse options, enabling every normalization and accepting unknown options.
+    pub fn lenient() -> Self {
+        ParseOptions {
+            normalize_underscores: true,
+            case_insensitive: true,
+            join_comment_lines: true,
+            on_unknown: UnknownPolicy::Passthrough,
+        }
+    }
+
+    // Helper function to normalize a long option name.
+    fn normalize_key(&self, key: &str) -> String {
+        let mut key = key.to_string();
+        if self.normalize_underscores {
+            key = key.replace('_', "-");
+        }
+        if self.case_insensitive {
+            key = key.to_ascii_lowercase();
+        }
+        key
+    }
+}
+
+/// Record of an option name adjusted by lenient parsing
+///
+/// Useful for warning users about non-canonical spellings, e.g. `--job_name` instead of `--job-name`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Normalization {
+    pub original: String,
+    pub canonical: String,
+}
+
+/// Warning about an option spelling that Slurm deprecated or discourages
+///
+/// The option is still parsed, into the variant of its replacement.
+///
+/// - `flag`: The flag as it was given, e.g. `--workdir`.
+/// - `replacement`: The flag to use instead, e.g. `--chdir`.
+/// - `message`: A description of the problem.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warning {
+    pub flag: String,
+    pub replacement: String,
+    pub message: String,
+}
+
+/// Error from `SbatchOption::parse_located`, with the location of the offending characters
+///
+/// - `message`: The description of the underlying `SbatchOptionError`.
+/// - `span`: The byte range of the input that caused the error, such as the value of `--time=abc`
+///   or the name of an unknown flag.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("{message} (at {}..{})", span.start, span.end)]
+pub struct LocatedError {
+    pub message: String,
+    pub span: Range<usize>,
+}
+
+// Helper function to get the byte range of a substring within the string it was sliced from.
+fn span_within(s: &str, part: &str) -> Range<usize> {
+    let start = part.as_ptr() as usize - s.as_ptr() as usize;
+    start..start + part.len()
+}
+
+// Long flag names that Slurm deprecated or discourages, with the flag replacing them and the reason.
+const DEPRECATED_LONG_FLAGS: &[(&str, &str, &str)] = &[
+    (
+        "tasks-per-node",
+        "ntasks-per-node",
+        "--tasks-per-node is an alias of --ntasks-per-node",
+    ),
+    (
+        "workdir",
+        "chdir",
+        "--workdir is deprecated and was removed from Slurm in favor of --chdir",
+    ),
+];
+
+// Helper function to get the flag replacing a deprecated long flag name.
+fn replacement_flag(key: &str) -> Option<&'static str> {
+    DEPRECATED_LONG_FLAGS
+        .iter()
+        .find(|(flag, _, _)| *flag == key)
+        .map(|(_, replacement, _)| *replacement)
+}
+
+// Helper function to split a short option with an attached value, such as `Jname`, into its flag and value.
+// Only flags that take a value are split, so a lone flag such as `H` is left alone.
+fn split_attached_short(arg: &str) -> Option<(&str, &str)> {
+    let flag = arg.chars().next()?;
+    let (key, value) = arg.split_at(flag.len_utf8());
+    let attached = !value.is_empty() && !value.starts_with(char::is_whitespace);
+    (attached && SbatchOption::requires_value(key) == Some(true)).then_some((key, value))
+}
+
+// Helper function to split an option string into its key, value, and whether it is a long option.
+fn split_option(s: &str) -> Result<(&str, Option<&str>, bool), SbatchOptionError> {
+    if let Some(arg) = s.strip_prefix("--") {
+        // Long options are separated from their value by `=` or whitespace
+        Ok(match arg.find(|c: char| c == '=' || c.is_whitespace()) {
+            Some(i) if arg[i..].starts_with('=') => (&arg[..i], Some(&arg[i + 1..]), true),
+            Some(i) => (&arg[..i], Some(arg[i..].trim()), true),
+            None => (arg, None, true),
+        })
+    } else if let Some(arg) = s.strip_prefix('-') {
+        // Short options that take a value may have it attached directly, e.g. `-Jname` or `-c4`
+        if let Some((key, value)) = split_attached_short(arg) {
+            return Ok((key, Some(value), false));
+        }
+
+        // Otherwise short options are separated from their value by whitespace
+        let (key, value) = match arg.split_once(char::is_whitespace) {
+            Some((key, value)) => (key, Some(value.trim())),
+            None => (arg, None),
+        };
+        if key.chars().count() != 1 {
+            return Err(SbatchOptionError::UnknownArgument(s.to_string()));
+        }
+        Ok((key, value, false))
+    } else {
+        Err(SbatchOptionError::UnknownArgument(s.to_string()))
+    }
+}
+
+// Helper function to join the non-blank lines of a value with single spaces.
+fn join_lines(s: &str) -> String {
+    s.split(['\n', '\r'])
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// Helper function to parse a positive count, such as `--ntasks` or `--sockets-per-node`.
+fn parse_count(s: &str) -> Result<NonZeroU32, SbatchOptionError> {
+    s.parse()
+        .map_err(|_| SbatchOptionError::InvalidCount(s.to_string()))
+}
+
+// Helper function to parse a `--nice` adjustment, with an optional explicit `+` or `-` sign.
+fn parse_nice(s: &str) -> Result<i32, SbatchOptionError> {
+    let invalid = || SbatchOptionError::InvalidNice(s.to_string());
+    let (negative, digits) = match s.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let value: i64 = digits.parse().map_err(|_| invalid())?;
+    let value = if negative { -value } else { value };
+    i32::try_from(value).map_err(|_| invalid())
+}
+
+// Helper function to strip one pair of matching surrounding quotes from a value.
+// Inside single quotes, the `'\''` sequence written by `Display` is turned back into a single quote.
+// Double quotes are not written by `Display`, but are accepted for hand-written values such as
+// `--wrap="echo hello"`, with escaped backslashes and double quotes unescaped.
+fn unquote(s: &str) -> String {
+    if let Some(inner) = s.strip_prefix('"').and_then(|rest| rest.strip_suffix('"')) {
+        let mut unescaped = String::with_capacity(inner.len());
+        let mut chars = inner.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c == '\\' && matches!(chars.peek(), Some('\\' | '"')) {
+                unescaped.extend(chars.next());
+            } else {
+                unescaped.push(c);
+            }
+        }
+        unescaped
+    } else if let Some(inner) = s
+        .strip_prefix('\'')
+        .and_then(|rest| rest.strip_suffix('\''))
+    {
+        inner.replace(r"'\''", "'")
+    } else {
+        s.to_string()
+    }
+}
+
+// Helper function to unquote the value of an unknown option kept as `SbatchOption::Raw`.
+// `Display` quotes the value after the flag, so it is stored unquoted to survive a round trip.
+fn unquote_raw(s: &str) -> String {
+    match s.find(['=', ' ']) {
+        Some(index) => {
+            let (flag, value) = s.split_at(index + 1);
+            format!("{}{}", flag, unquote(value.trim_start()))
+        }
+        None => s.to_string(),
+    }
+}
+
+// Long flag names recognized by `SbatchOption::from_key_value`.
+const KNOWN_LONG_FLAGS: &[&str] = &[
+    "account",
+    "acctg-freq",
+    "array",
+    "batch",
+    "bb",
+    "bbf",
+    "begin",
+    "chdir",
+    "cluster-constraint",
+    "clusters",
+    "comment",
+    "constraint",
+    "container",
+    "container-id",
+    "contiguous",
+    "core-spec",
+    "cores-per-socket",
+    "cpu-freq",
+    "cpus-per-gpu",
+    "cpus-per-task",
+    "deadline",
+    "delay-boot",
+    "dependency",
+    "distribution",
+    "error",
+    "exclude",
+    "exclusive",
+    "export",
+    "export-file",
+    "extra",
+    "extra-node-info",
+    "get-user-env",
+    "gid",
+    "gpu-bind",
+    "gpu-freq",
+    "gpus",
+    "gpus-per-node",
+    "gpus-per-socket",
+    "gpus-per-task",
+    "gres",
+    "gres-flags",
+    "help",
+    "hint",
+    "hold",
+    "ignore-pbs",
+    "input",
+    "job-name",
+    "kill-on-invalid-dep",
+    "licenses",
+    "mail-type",
+    "mail-user",
+    "mcs-label",
+    "mem",
+    "mem-bind",
+    "mem-per-cpu",
+    "mem-per-gpu",
+    "min-cpus",
+    "network",
+    "nice",
+    "no-kill",
+    "no-requeue",
+    "nodefile",
+    "nodelist",
+    "nodes",
+    "ntasks",
+    "ntasks-per-core",
+    "ntasks-per-gpu",
+    "ntasks-per-node",
+    "ntasks-per-socket",
+    "oom-kill-step",
+    "open-mode",
+    "output",
+    "overcommit",
+    "oversubscribe",
+    "parsable",
+    "partition",
+    "prefer",
+    "priority",
+    "profile",
+    "propagate",
+    "qos",
+    "quiet",
+    "reboot",
+    "requeue",
+    "reservation",
+    "resv-ports",
+    "segment",
+    "signal",
+    "sockets-per-node",
+    "spread-job",
+    "stepmgr",
+    "switches",
+    "test-only",
+    "thread-spec",
+    "threads-per-core",
+    "time",
+    "time-min",
+    "tmp",
+    "tres-bind",
+    "tres-per-task",
+    "uid",
+    "usage",
+    "use-min-nodes",
+    "verbose",
+    "version",
+    "wait",
+    "wait-all-nodes",
+    "wckey",
+    "wrap",
+];
+
+// Short flag letters recognized by `SbatchOption::from_key_value`.
+const KNOWN_SHORT_FLAGS: &[char] = &[
+    'A', 'a', 'b', 'D', 'M', 'C', 'S', 'c', 'd', 'm', 'e', 'x', 'B', 'G', 'h', 'H', 'i', 'J', 'L',
+    'k', 'F', 'w', 'N', 'n', 'o', 'O', 's', 'p', 'q', 'Q', 't', 'v', 'V', 'W',
+];
+
+impl SbatchOption {
+    /// Returns the long flag names recognized by the parser, without dashes (e.g. `job-name`).
+    ///
+    /// This is useful for generating shell completions or suggesting corrections for unknown flags.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::SbatchOption;
+    ///
+    /// assert!(SbatchOption::known_long_flags().contains(&"job-name"));
+    /// assert!(!SbatchOption::known_long_flags().contains(&"not-an-option"));
+    /// ```
+    pub fn known_long_flags() -> &'static [&'static str] {
+        KNOWN_LONG_FLAGS
+    }
+
+    /// Returns the short flag letters recognized by the parser (e.g. `J` for `-J`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::SbatchOption;
+    ///
+    /// assert!(SbatchOption::known_short_flags().contains(&'J'));
+    /// ```
+    pub fn known_short_flags() -> &'static [char] {
+        KNOWN_SHORT_FLAGS
+    }
+
+    /// Returns whether the named sbatch option requires a value.
+    ///
+    /// # Arguments
+    ///
+    /// * `long_name` - The long flag name without dashes (e.g. `job-name`). Short flag letters (e.g. `J`) are also accepted.
+    ///
+    /// # Returns
+    ///
+    /// This function returns `Some(true)` if the option requires a value, `Some(false)` if the option
+    /// is a flag or takes an optional value, and `None` if the option is not recognized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::SbatchOption;
+    ///
+    /// assert_eq!(SbatchOption::requires_value("job-name"), Some(true));
+    /// assert_eq!(SbatchOption::requires_value("exclusive"), Some(false));
+    /// assert_eq!(SbatchOption::requires_value("hold"), Some(false));
+    /// assert_eq!(SbatchOption::requires_value("not-an-option"), None);
+    /// ```
+    pub fn requires_value(long_name: &str) -> Option<bool> {
+        match replacement_flag(long_name).unwrap_or(long_name) {
+            "account" | "A" => Some(true),
+            "acctg-freq" => Some(true),
+            "array" | "a" => Some(true),
+            "batch" => Some(true),
+            "bb" => Some(true),
+            "bbf" => Some(true),
+            "begin" | "b" => Some(true),
+            "chdir" | "D" => Some(true),
+            "cluster-constraint" => Some(true),
+            "clusters" | "M" => Some(true),
+            "comment" => Some(true),
+            "constraint" | "C" => Some(true),
+            "container" => Some(true),
+            "container-id" => Some(true),
+            "core-spec" | "S" => Some(true),
+            "cores-per-socket" => Some(true),
+            "cpu-freq" => Some(true),
+            "cpus-per-gpu" => Some(true),
+            "cpus-per-task" | "c" => Some(true),
+            "deadline" => Some(true),
+            "delay-boot" => Some(true),
+            "dependency" | "d" => Some(true),
+            "distribution" | "m" => Some(true),
+            "error" | "e" => Some(true),
+            "exclude" | "x" => Some(true),
+            "export" => Some(true),
+            "export-file" => Some(true),
+            "extra" => Some(true),
+            "extra-node-info" | "B" => Some(true),
+            "gid" => Some(true),
+            "gpu-bind" => Some(true),
+            "gpu-freq" => Some(true),
+            "gpus" | "G" => Some(true),
+            "gpus-per-node" => Some(true),
+            "gpus-per-socket" => Some(true),
+            "gpus-per-task" => Some(true),
+            "gres" => Some(true),
+            "gres-flags" => Some(true),
+            "hint" => Some(true),
+            "input" | "i" => Some(true),
+            "job-name" | "J" => Some(true),
+            "kill-on-invalid-dep" => Some(true),
+            "licenses" | "L" => Some(true),
+            "mail-type" => Some(true),
+            "mail-user" => Some(true),
+            "mcs-label" => Some(true),
+            "mem" => Some(true),
+            "mem-bind" => Some(true),
+            "mem-per-cpu" => Some(true),
+            "mem-per-gpu" => Some(true),
+            "min-cpus" => Some(true),
+            "network" => Some(true),
+            "nodefile" | "F" => Some(true),
+            "nodelist" | "w" => Some(true),
+            "nodes" | "N" => Some(true),
+            "ntasks" | "n" => Some(true),
+            "ntasks-per-core" => Some(true),
+            "ntasks-per-gpu" => Some(true),
+            "ntasks-per-node" => Some(true),
+            "ntasks-per-socket" => Some(true),
+            "open-mode" => Some(true),
+            "output" | "o" => Some(true),
+            "partition" | "p" => Some(true),
+            "prefer" => Some(true),
+            "priority" => Some(true),
+            "profile" => Some(true),
+            "qos" | "q" => Some(true),
+            "reservation" => Some(true),
+            "segment" => Some(true),
+            "signal" => Some(true),
+            "sockets-per-node" => Some(true),
+            "switches" => Some(true),
+            "thread-spec" => Some(true),
+            "threads-per-core" => Some(true),
+            "time" | "t" => Some(true),
+            "time-min" => Some(true),
+            "tmp" => Some(true),
+            "tres-bind" => Some(true),
+            "tres-per-task" => Some(true),
+            "uid" => Some(true),
+            "wait-all-nodes" => Some(true),
+            "wckey" => Some(true),
+            "wrap" => Some(true),
+            "contiguous" => Some(false),
+            "exclusive" => Some(false),
+            "get-user-env" => Some(false),
+            "help" | "h" => Some(false),
+            "hold" | "H" => Some(false),
+            "ignore-pbs" => Some(false),
+            "nice" => Some(false),
+            "no-kill" | "k" => Some(false),
+            "no-requeue" => Some(false),
+            "oom-kill-step" => Some(false),
+            "overcommit" | "O" => Some(false),
+            "oversubscribe" | "s" => Some(false),
+            "parsable" => Some(false),
+            "propagate" => Some(false),
+            "quiet" | "Q" => Some(false),
+            "reboot" => Some(false),
+            "requeue" => Some(false),
+            "resv-ports" => Some(false),
+            "spread-job" => Some(false),
+            "stepmgr" => Some(false),
+            "test-only" => Some(false),
+            "usage" => Some(false),
+            "use-min-nodes" => Some(false),
+            "verbose" | "v" => Some(false),
+            "version" | "V" => Some(false),
+            "wait" | "W" => Some(false),
+            _ => None,
+        }
+    }
+
+    /// Returns a warning if an option string uses a flag that Slurm deprecated or discourages.
+    ///
+    /// Such flags are still parsed, into the variant of the flag replacing them, so this is how callers
+    /// find out that a script or command line should be updated.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The option string, in any of the forms accepted by `from_str` (e.g. `--workdir=/tmp`).
+    ///
+    /// # Returns
+    ///
+    /// This function returns a `Warning` naming the replacement flag, or `None` if the flag is current
+    /// or the string is not an option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::SbatchOption;
+    ///
+    /// let warning = SbatchOption::deprecation_warning("--workdir=/tmp").unwrap();
+    /// assert_eq!(warning.flag, "--workdir");
+    /// assert_eq!(warning.replacement, "--chdir");
+    ///
+    /// assert!(SbatchOption::deprecation_warning("--chdir=/tmp").is_none());
+    /// ```
+    pub fn deprecation_warning(s: &str) -> Option<Warning> {
+        let (key, _, long) = split_option(s.trim()).ok()?;
+        let (flag, replacement, message) = DEPRECATED_LONG_FLAGS
+            .iter()
+            .find(|(flag, _, _)| long && *flag == key)?;
+        Some(Warning {
+            flag: format!("--{flag}"),
+            replacement: format!("--{replacement}"),
+            message: message.to_string(),
+        })
+    }
+
+    /// Creates an `SbatchOption` from a flag name and an optional value.
+    ///
+    /// Deprecated flag names, such as `workdir`, are parsed as the flag replacing them.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The long flag name without dashes (e.g. `job-name`) or the short flag letter (e.g. `J`).
+    /// * `value` - The value passed to the flag, if any. A single pair of surrounding quotes is removed.
+    ///
+    /// # Returns
+    ///
+    /// This function returns the matching `SbatchOption`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchOptionError::MissingValue` if the flag requires a value but none was given,
+    /// and a `SbatchOptionError::UnknownArgument` if the flag is not recognized or does not accept the given value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::SbatchOption;
+    ///
+    /// let option = SbatchOption::from_key_value("job-name", Some("test")).unwrap();
+    /// assert_eq!(option, SbatchOption::JobName("test".to_string()));
+    ///
+    /// let option = SbatchOption::from_key_value("H", None).unwrap();
+    /// assert_eq!(option, SbatchOption::Hold);
+    /// ```
+    pub fn from_key_value(key: &str, value: Option<&str>) -> Result<Self, SbatchOptionError> {
+        let key = replacement_flag(key).unwrap_or(key);
+        let value = value.map(unquote);
+        let option = match (key, value) {
+            ("account" | "A", Some(value)) => SbatchOption::Account(value),
+            ("acctg-freq", Some(value)) => SbatchOption::AcctgFreq(value),
+            ("array" | "a", Some(value)) => SbatchOption::Array(value.parse()?),
+            ("batch", Some(value)) => SbatchOption::Batch(value),
+            ("bb", Some(value)) => SbatchOption::Bb(value),
+            ("bbf", Some(value)) => SbatchOption::Bbf(value),
+            ("begin" | "b", Some(value)) => SbatchOption::Begin(value.parse()?),
+            ("chdir" | "D", Some(value)) => SbatchOption::Chdir(value),
+            ("cluster-constraint", Some(value)) => SbatchOption::ClusterConstraint(value),
+            ("clusters" | "M", Some(value)) => SbatchOption::Clusters(value),
+            ("comment", Some(value)) => SbatchOption::Comment(value),
+            ("constraint" | "C", Some(value)) => SbatchOption::Constraint(value.parse()?),
+            ("container", Some(value)) => SbatchOption::Container(value),
+            ("container-id", Some(value)) => SbatchOption::ContainerID(value),
+            ("contiguous", None) => SbatchOption::Contiguous,
+            ("core-spec" | "S", Some(value)) => SbatchOption::CoreSpec(
+                value
+                    .parse()
+                    .map_err(|_| SbatchOptionError::InvalidCount(value.clone()))?,
+            ),
+            ("cores-per-socket", Some(value)) => SbatchOption::CoresPerSocket(parse_count(&value)?),
+            ("cpu-freq", Some(value)) => SbatchOption::CPUFreq(value.parse()?),
+            ("cpus-per-gpu", Some(value)) => SbatchOption::CPUsPerGPU(value),
+            ("cpus-per-task" | "c", Some(value)) => SbatchOption::CPUsPerTask(value),
+            ("deadline", Some(value)) => SbatchOption::Deadline(value),
+            ("delay-boot", Some(value)) => SbatchOption::DelayBoot(value),
+            ("dependency" | "d", Some(value)) => SbatchOption::Dependency(value),
+            ("distribution" | "m", Some(value)) => SbatchOption::Distribution(value.parse()?),
+            ("error" | "e", Some(value)) => SbatchOption::Error(value),
+            ("exclude" | "x", Some(value)) => SbatchOption::Exclude(value.parse()?),
+            ("exclusive", value) => SbatchOption::Exclusive(value),
+            ("export", Some(value)) => SbatchOption::Export(value.parse()?),
+            ("export-file", Some(value)) => SbatchOption::ExportFile(value),
+            ("extra", Some(value)) => SbatchOption::Extra(value),
+            ("extra-node-info" | "B", Some(value)) => SbatchOption::ExtraNodeInfo(value),
+            ("get-user-env", value) => SbatchOption::GetUserEnv(value),
+            ("gid", Some(value)) => SbatchOption::GID(value),
+            ("gpu-bind", Some(value)) => SbatchOption::GPUBind(value),
+            ("gpu-freq", Some(value)) => SbatchOption::GPUFreq(value),
+            ("gpus" | "G", Some(value)) => SbatchOption::GPUs(value.parse()?),
+            ("gpus-per-node", Some(value)) => SbatchOption::GPUsPerNode(value.parse()?),
+            ("gpus-per-socket", Some(value)) => SbatchOption::GPUsPerSocket(value.parse()?),
+            ("gpus-per-task", Some(value)) => SbatchOption::GPUsPerTask(value.parse()?),
+            ("gres", Some(value)) => SbatchOption::Gres(value.parse()?),
+            ("gres-flags", Some(value)) => SbatchOption::GresFlags(value),
+            ("help" | "h", None) => SbatchOption::Help,
+            ("hint", Some(value)) => SbatchOption::Hint(value),
+            ("hold" | "H", None) => SbatchOption::Hold,
+            ("ignore-pbs", None) => SbatchOption::IgnorePbs,
+            ("input" | "i", Some(value)) => SbatchOption::Input(value),
+            ("job-name" | "J", Some(value)) => SbatchOption::JobName(value),
+            ("kill-on-invalid-dep", Some(value)) => SbatchOption::KillOnInvalidDep(value),
+            ("licenses" | "L", Some(value)) => SbatchOption::Licenses(value),
+            ("mail-type", Some(value)) => SbatchOption::MailType(value),
+            ("mail-user", Some(value)) => SbatchOption::MailUser(value),
+            ("mcs-label", Some(value)) => SbatchOption::McsLabel(value),
+            ("mem", Some(value)) => SbatchOption::Mem(value.parse()?),
+            ("mem-bind", Some(value)) => SbatchOption::MemBind(value),
+            ("mem-per-cpu", Some(value)) => SbatchOption::MemPerCPU(value.parse()?),
+            ("mem-per-gpu", Some(value)) => SbatchOption::MemPerGPU(value.parse()?),
+            ("min-cpus", Some(value)) => SbatchOption::MinCPUs(value),
+            ("network", Some(value)) => SbatchOption::Network(value),
+            ("nice", value) => SbatchOption::Nice(value.as_deref().map(parse_nice).transpose()?),
+            ("no-kill" | "k", value) => SbatchOption::NoKill(value),
+            ("no-requeue", None) => SbatchOption::NoRequeue,
+            ("nodefile" | "F", Some(value)) => SbatchOption::NodeFile(value),
+            ("nodelist" | "w", Some(value)) => SbatchOption::NodeList(value.parse()?),
+            ("nodes" | "N", Some(value)) => SbatchOption::Nodes(value),
+            ("ntasks" | "n", Some(value)) => SbatchOption::NTasks(parse_count(&value)?),
+            ("ntasks-per-core", Some(value)) => SbatchOption::NTasksPerCore(value),
+            ("ntasks-per-gpu", Some(value)) => SbatchOption::NTasksPerGPU(value),
+            ("ntasks-per-node", Some(value)) => SbatchOption::NTasksPerNode(parse_count(&value)?),
+            ("ntasks-per-socket", Some(value)) => SbatchOption::NTasksPerSocket(value),
+            ("oom-kill-step", value) => SbatchOption::OOMKillStep(value),
+            ("open-mode", Some(value)) => SbatchOption::OpenMode(value.parse()?),
+            ("output" | "o", Some(value)) => SbatchOption::Output(value),
+            ("overcommit" | "O", None) => SbatchOption::Overcommit,
+            ("oversubscribe" | "s", None) => SbatchOption::Oversubscribe,
+            ("parsable", None) => SbatchOption::Parsable,
+            ("partition" | "p", Some(value)) => SbatchOption::Partition(value),
+            ("prefer", Some(value)) => SbatchOption::Prefer(value),
+            ("priority", Some(value)) => SbatchOption::Priority(value),
+            ("profile", Some(value)) => SbatchOption::Profile(value),
+            ("propagate", value) => SbatchOption::Propagate(value),
+            ("qos" | "q", Some(value)) => SbatchOption::Qos(value),
+            ("quiet" | "Q", None) => SbatchOption::Quiet,
+            ("reboot", None) => SbatchOption::Reboot,
+            ("requeue", None) => SbatchOption::Requeue,
+            ("reservation", Some(value)) => SbatchOption::Reservation(value),
+            ("resv-ports", value) => SbatchOption::ResvPorts(value),
+            ("segment", Some(value)) => SbatchOption::Segment(value),
+            ("signal", Some(value)) => SbatchOption::Signal(value.parse()?),
+            ("sockets-per-node", Some(value)) => SbatchOption::SocketsPerNode(parse_count(&value)?),
+            ("spread-job", None) => SbatchOption::SpreadJob,
+            ("stepmgr", None) => SbatchOption::Stepmgr,
+            ("switches", Some(value)) => SbatchOption::Switches(value),
+            ("test-only", None) => SbatchOption::TestOnly,
+            ("thread-spec", Some(value)) => SbatchOption::ThreadSpec(value),
+            ("threads-per-core", Some(value)) => SbatchOption::ThreadsPerCore(value),
+            ("time" | "t", Some(value)) => SbatchOption::Time(value.parse()?),
+            ("time-min", Some(value)) => SbatchOption::TimeMin(value.parse()?),
+            ("tmp", Some(value)) => SbatchOption::Tmp(value),
+            ("tres-bind", Some(value)) => SbatchOption::TresBind(value),
+            ("tres-per-task", Some(value)) => SbatchOption::TresPerTask(value),
+            ("uid", Some(value)) => SbatchOption::UID(value),
+            ("usage", None) => SbatchOption::Usage,
+            ("use-min-nodes", None) => SbatchOption::UseMinNodes,
+            ("verbose" | "v", None) => SbatchOption::Verbose,
+            ("version" | "V", None) => SbatchOption::Version,
+            ("wait" | "W", None) => SbatchOption::Wait,
+            ("wait-all-nodes", Some(value)) => SbatchOption::WaitAllNodes(value),
+            ("wckey", Some(value)) => SbatchOption::WCKey(value),
+            ("wrap", Some(value)) => SbatchOption::Wrap(value),
+            (key, None) if SbatchOption::requires_value(key) == Some(true) => {
+                return Err(SbatchOptionError::MissingValue(key.to_string()));
+            }
+            (key, _) => return Err(SbatchOptionError::UnknownArgument(key.to_string())),
+        };
+        Ok(option)
+    }
+
+    /// Parses a single sbatch option from a string using the given `ParseOptions`.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The option string, in any of the forms accepted by `from_str`.
+    /// * `options` - The `ParseOptions` controlling how strictly the string is parsed.
+    ///
+    /// # Returns
+    ///
+    /// This function returns the parsed and validated `SbatchOption`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchOptionError` if the option cannot be parsed or is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{ParseOptions, SbatchOption};
+    ///
+    /// let option = SbatchOption::from_str_with("--Job_Name=test", &ParseOptions::lenient()).unwrap();
+    /// assert_eq!(option, SbatchOption::JobName("test".to_string()));
+    ///
+    /// let option = SbatchOption::from_str_with("--newfangled-flag=x", &ParseOptions::lenient()).unwrap();
+    /// assert_eq!(option, SbatchOption::Raw("--newfangled-flag=x".to_string()));
+    ///
+    /// assert!(SbatchOption::from_str_with("--newfangled-flag=x", &ParseOptions::strict()).is_err());
+    /// ```
+    pub fn from_str_with(s: &str, options: &ParseOptions) -> Result<Self, SbatchOptionError> {
+        SbatchOption::from_str_with_normalizations(s, options).map(|(option, _)| option)
+    }
+
+    /// Parses a single sbatch option like `from_str_with`, also reporting how the option name was normalized.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The option string, in any of the forms accepted by `from_str`.
+    /// * `options` - The `ParseOptions` controlling how strictly the string is parsed.
+    ///
+    /// # Returns
+    ///
+    /// This function returns the parsed and validated `SbatchOption`, along with a `Normalization`
+    /// for each option name that was not already in its canonical form.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SbatchOptionError` if the option cannot be parsed or is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{Normalization, ParseOptions, SbatchOption};
+    ///
+    /// let (option, normalizations) =
+    ///     SbatchOption::from_str_with_normalizations("--job_name=test", &ParseOptions::lenient()).unwrap();
+    /// assert_eq!(option, SbatchOption::JobName("test".to_string()));
+    /// assert_eq!(
+    ///     normalizations,
+    ///     vec![Normalization { original: "--job_name".to_string(), canonical: "--job-name".to_string() }]
+    /// );
+    /// ```
+    pub fn from_str_with_normalizations(
+        s: &str,
+        options: &ParseOptions,
+    ) -> Result<(Self, Vec<Normalization>), SbatchOptionError> {
+        let s = s.trim();
+        let mut normalizations = Vec::new();
+        let parsed = split_option(s).and_then(|(key, value, long)| {
+            if !long {
+                return SbatchOption::from_key_value(key, value);
+            }
+            let canonical = options.normalize_key(key);
+            let option = SbatchOption::from_key_value(&canonical, value)?;
+            if canonical != key {
+                normalizations.push(Normalization {
+                    original: format!("--{key}"),
+                    canonical: format!("--{canonical}"),
+                });
+            }
+            Ok(option)
+        });
+
+        // Unknown options are passed through unchanged, and comments joined onto one line, when allowed
+        let option = match parsed {
+            Err(SbatchOptionError::UnknownArgument(_))
+                if options.on_unknown == UnknownPolicy::Passthrough && s.starts_with('-') =>
+            {
+                SbatchOption::Raw(unquote_raw(s))
+            }
+            Ok(SbatchOption::Comment(value)) if options.join_comment_lines => {
+                SbatchOption::Comment(join_lines(&value))
+            }
+            result => result?,
+        };
+        option.validate()?;
+        Ok((option, normalizations))
+    }
+
+    /// Parses a single sbatch option like `from_str`, reporting where in the string parsing failed.
+    ///
+    /// This is useful for editors and linters that highlight the offending characters. An unknown flag,
+    /// or a flag missing its value, is located at the flag name. An invalid value is located at the value.
+    /// When the string is not an option at all, the whole trimmed string is reported.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The option string, in any of the forms accepted by `from_str`.
+    ///
+    /// # Returns
+    ///
+    /// This function returns the parsed and validated `SbatchOption`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `LocatedError` with the message of the underlying `SbatchOptionError` and
+    /// the byte range of `s` that caused it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::SbatchOption;
+    ///
+    /// let error = SbatchOption::parse_located("--time=abc").unwrap_err();
+    /// assert_eq!(error.span, 7..10);
+    ///
+    /// let error = SbatchOption::parse_located("--not-an-option=1").unwrap_err();
+    /// assert_eq!(error.span, 2..15);
+    /// ```
+    pub fn parse_located(s: &str) -> Result<Self, LocatedError> {
+        s.parse().map_err(|error: SbatchOptionError| {
+            let trimmed = s.trim();
+            let span = match split_option(trimmed) {
+                Ok((key, value, _)) => match (&error, value) {
+                    (
+                        SbatchOptionError::UnknownArgument(_) | SbatchOptionError::MissingValue(_),
+                        _,
+                    )
+                    | (_, None) => span_within(s, key),
+                    (_, Some(value)) => span_within(s, value),
+                },
+                Err(_) => span_within(s, trimmed),
+            };
+            LocatedError {
+                message: error.to_string(),
+                span,
+            }
+        })
+    }
+}
+
+impl FromStr for SbatchOption {
+    type Err = SbatchOptionError;
+
+    /// Parses a single sbatch option from a string.
+    ///
+    /// The following forms are accepted:
+    /// - `--long-flag=value` and `--long-flag value`
+    /// - `--long-flag`
+    /// - `-s value` and `-s`
+    ///
+    /// The parsed option is validated before it is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::SbatchOption;
+    ///
+    /// let option: SbatchOption = "--job-name=test".parse().unwrap();
+    /// assert_eq!(option, SbatchOption::JobName("test".to_string()));
+    ///
+    /// let option: SbatchOption = "-J test".parse().unwrap();
+    /// assert_eq!(option, SbatchOption::JobName("test".to_string()));
+    ///
+    /// let option: SbatchOption = "--wrap=\"echo hello\"".parse().unwrap();
+    /// assert_eq!(option, SbatchOption::Wrap("echo hello".to_string()));
+    ///
+    /// assert!("--not-an-option".parse::<SbatchOption>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SbatchOption::from_str_with(s, &ParseOptions::strict())
+    }
+}
@@ -0,0 +1,141 @@
+//! Typed signal specification for the `--signal` option
+
+use std::str::FromStr;
+
+use super::SbatchOptionError;
+
+// Signal names accepted by Slurm, without the `SIG` prefix
+const SIGNAL_NAMES: &[&str] = &[
+    "HUP", "INT", "QUIT", "ILL", "TRAP", "ABRT", "BUS", "FPE", "KILL", "USR1", "SEGV", "USR2",
+    "PIPE", "ALRM", "TERM", "STKFLT", "CHLD", "CONT", "STOP", "TSTP", "TTIN", "TTOU", "URG",
+    "XCPU", "XFSZ", "VTALRM", "PROF", "WINCH", "IO", "PWR", "SYS",
+];
+
+// Highest signal number accepted, covering the real-time signals
+const MAX_SIGNAL_NUMBER: u8 = 64;
+
+/// Which processes of a job a signal is sent to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SignalScope {
+    /// Maps to the `B:` prefix, signalling only the batch shell
+    Batch,
+    /// Maps to the `R:` prefix, also signalling the job when its reservation ends
+    Reservation,
+}
+
+/// Signal specification
+///
+/// Represents the value of the `--signal` option: `[{R|B}:]<sig_num|sig_name>[@sig_time]`.
+/// The signal is either a number or a name such as `USR1` (case-insensitive, with an optional `SIG` prefix),
+/// and `sig_time` is the number of seconds before the end of the time limit at which the signal is sent.
+///
+/// The value is displayed in its canonical form, e.g. `B:USR1@60`.
+///
+/// # Examples
+///
+/// ```
+/// use sbatch_rs::{SignalScope, SignalSpec};
+///
+/// let signal: SignalSpec = "b:sigusr1@60".parse().unwrap();
+/// assert_eq!(signal.scope(), Some(SignalScope::Batch));
+/// assert_eq!(signal.signal(), "USR1");
+/// assert_eq!(signal.time(), Some(60));
+/// assert_eq!(signal.to_string(), "B:USR1@60");
+///
+/// assert!("10".parse::<SignalSpec>().is_ok());
+/// assert!("USR1@-5".parse::<SignalSpec>().is_err());
+/// assert!("FOO".parse::<SignalSpec>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SignalSpec {
+    scope: Option<SignalScope>,
+    signal: String,
+    time: Option<u16>,
+}
+
+impl SignalSpec {
+    /// Returns the `R:` or `B:` scope prefix, if any.
+    pub fn scope(&self) -> Option<SignalScope> {
+        self.scope
+    }
+
+    /// Returns the signal in its canonical form, either a name without the `SIG` prefix or a number.
+    pub fn signal(&self) -> &str {
+        &self.signal
+    }
+
+    /// Returns the number of seconds before the end of the time limit at which the signal is sent, if given.
+    pub fn time(&self) -> Option<u16> {
+        self.time
+    }
+}
+
+impl FromStr for SignalSpec {
+    type Err = SbatchOptionError;
+
+    /// Parses a signal specification such as `USR1`, `10@120`, or `B:USR1@60`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SbatchOptionError::InvalidSignal(s.to_string());
+
+        // Split off the optional scope prefix and time suffix
+        let (scope, rest) = match s.split_once(':') {
+            Some((prefix, rest)) => match prefix.to_ascii_uppercase().as_str() {
+                "B" => (Some(SignalScope::Batch), rest),
+                "R" => (Some(SignalScope::Reservation), rest),
+                _ => return Err(invalid()),
+            },
+            None => (None, s),
+        };
+        let (signal, time) = match rest.split_once('@') {
+            Some((signal, time)) => {
+                if time.is_empty() || !time.chars().all(|c| c.is_ascii_digit()) {
+                    return Err(invalid());
+                }
+                (signal, Some(time.parse().map_err(|_| invalid())?))
+            }
+            None => (rest, None),
+        };
+
+        // The signal is either a number or a known name
+        let signal = if !signal.is_empty() && signal.chars().all(|c| c.is_ascii_digit()) {
+            signal
+                .parse::<u8>()
+                .ok()
+                .filter(|number| (1..=MAX_SIGNAL_NUMBER).contains(number))
+                .ok_or_else(invalid)?
+                .to_string()
+        } else {
+            let name = signal.to_ascii_uppercase();
+            let name = name.strip_prefix("SIG").unwrap_or(&name);
+            SIGNAL_NAMES
+                .iter()
+                .find(|known| **known == name)
+                .ok_or_else(invalid)?
+                .to_string()
+        };
+
+        Ok(SignalSpec {
+            scope,
+            signal,
+            time,
+        })
+    }
+}
+
+impl std::fmt::Display for SignalSpec {
+    /// Displays the signal specification in its canonical form, e.g. `B:USR1@60`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.scope {
+            Some(SignalScope::Batch) => write!(f, "B:")?,
+            Some(SignalScope::Reservation) => write!(f, "R:")?,
+            None => {}
+        }
+        write!(f, "{}", self.signal)?;
+        match self.time {
+            Some(time) => write!(f, "@{}", time),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,76 @@
+//! Structured description of `SbatchOption` values, for rendering options outside of a command line
+
+use super::{DisplayStyle, SbatchOption};
+
+/// The kind of value an option takes
+///
+/// - `Flag`: The option is given without a value (e.g. `--contiguous`).
+/// - `String`: The option takes a free-form or structured value (e.g. `--job-name=test` or `--time=1:00:00`).
+/// - `Numeric`: The option takes an integer value (e.g. `--sockets-per-node=2` or `--nice=10`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OptionKind {
+    Flag,
+    String,
+    Numeric,
+}
+
+/// Structured description of a single option
+///
+/// - `name`: The long flag name without the leading dashes (e.g. `job-name`), or an empty string for `SbatchOption::Raw`.
+/// - `value`: The unquoted value, or `None` for a flag. For `SbatchOption::Raw`, this is the whole raw option.
+/// - `kind`: The kind of value the option takes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OptionSpec {
+    pub name: &'static str,
+    pub value: Option<String>,
+    pub kind: OptionKind,
+}
+
+impl SbatchOption {
+    /// Returns a structured description of the option, as an alternative to its command line form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{OptionKind, OptionSpec, SbatchOption};
+    ///
+    /// let spec = SbatchOption::Comment("hello world".to_string()).to_spec();
+    /// assert_eq!(
+    ///     spec,
+    ///     OptionSpec { name: "comment", value: Some("hello world".to_string()), kind: OptionKind::String }
+    /// );
+    ///
+    /// let spec = SbatchOption::Contiguous.to_spec();
+    /// assert_eq!(spec, OptionSpec { name: "contiguous", value: None, kind: OptionKind::Flag });
+    /// ```
+    pub fn to_spec(&self) -> OptionSpec {
+        let name = self.canonical_name();
+        if let SbatchOption::Raw(raw) = self {
+            return OptionSpec {
+                name,
+                value: Some(raw.clone()),
+                kind: OptionKind::String,
+            };
+        }
+
+        // The value is whatever follows `--name=` in the unquoted form
+        let value = self
+            .display_with(DisplayStyle::Unquoted)
+            .strip_prefix(&format!("--{name}="))
+            .map(str::to_string);
+        let kind = match (self, &value) {
+            (_, None) => OptionKind::Flag,
+            (
+                SbatchOption::CoreSpec(_)
+                | SbatchOption::CoresPerSocket(_)
+                | SbatchOption::Nice(_)
+                | SbatchOption::NTasks(_)
+                | SbatchOption::NTasksPerNode(_)
+                | SbatchOption::SocketsPerNode(_),
+                Some(_),
+            ) => OptionKind::Numeric,
+            (_, Some(_)) => OptionKind::String,
+        };
+        OptionSpec { name, value, kind }
+    }
+}
@@ -0,0 +1,167 @@
+//! Typed time limit for the `--time` and `--time-min` options
+
+use std::str::FromStr;
+use std::time::Duration;
+
+use super::SbatchOptionError;
+
+/// Time limit value
+///
+/// Represents a Slurm time limit. The following forms are accepted:
+/// `minutes`, `minutes:seconds`, `hours:minutes:seconds`, `days-hours`,
+/// `days-hours:minutes`, and `days-hours:minutes:seconds`.
+/// The special tokens `UNLIMITED` and `INFINITE` (case-insensitive) request no time limit.
+///
+/// The value is displayed in the canonical `D-HH:MM:SS` form.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use sbatch_rs::TimeLimit;
+///
+/// let time: TimeLimit = "1-12:30".parse().unwrap();
+/// assert_eq!(time.as_duration(), Duration::from_secs(36 * 3600 + 30 * 60));
+/// assert_eq!(time.to_string(), "1-12:30:00");
+///
+/// let time: TimeLimit = "90".parse().unwrap();
+/// assert_eq!(time.to_string(), "0-01:30:00");
+///
+/// let time: TimeLimit = "UNLIMITED".parse().unwrap();
+/// assert!(time.is_unlimited());
+///
+/// assert!("99:99".parse::<TimeLimit>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeLimit {
+    seconds: Option<u64>,
+}
+
+impl TimeLimit {
+    /// Creates a time limit from a number of seconds.
+    pub fn from_secs(seconds: u64) -> Self {
+        TimeLimit {
+            seconds: Some(seconds),
+        }
+    }
+
+    /// Creates a time limit representing no limit.
+    pub fn unlimited() -> Self {
+        TimeLimit { seconds: None }
+    }
+
+    /// Returns `true` if the time limit is `UNLIMITED`.
+    pub fn is_unlimited(&self) -> bool {
+        self.seconds.is_none()
+    }
+
+    /// Returns the time limit as a `Duration`.
+    ///
+    /// An unlimited time limit is returned as `Duration::MAX`.
+    pub fn as_duration(&self) -> Duration {
+        self.seconds.map_or(Duration::MAX, Duration::from_secs)
+    }
+
+    /// Returns `true` if the time limit is longer than `limit`.
+    ///
+    /// An unlimited time limit exceeds every finite limit, and nothing exceeds an unlimited one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::TimeLimit;
+    ///
+    /// let max: TimeLimit = "2-00:00:00".parse().unwrap();
+    /// assert!("2-00:00:01".parse::<TimeLimit>().unwrap().exceeds(&max));
+    /// assert!(!"48:00:00".parse::<TimeLimit>().unwrap().exceeds(&max));
+    /// assert!(TimeLimit::unlimited().exceeds(&max));
+    /// ```
+    pub fn exceeds(&self, limit: &TimeLimit) -> bool {
+        self.as_duration() > limit.as_duration()
+    }
+}
+
+// Helper function to parse a single numeric field, optionally bounded above.
+fn parse_field(s: &str, max: Option<u64>) -> Option<u64> {
+    if s.is_empty() || !s.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    s.parse()
+        .ok()
+        .filter(|value| max.is_none_or(|max| *value <= max))
+}
+
+impl FromStr for TimeLimit {
+    type Err = SbatchOptionError;
+
+    /// Parses a Slurm time limit such as `30`, `1:30:00`, or `2-00:00:00`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("UNLIMITED") || s.eq_ignore_ascii_case("INFINITE") {
+            return Ok(TimeLimit::unlimited());
+        }
+
+        let seconds = match s.split_once('-') {
+            // days-hours[:minutes[:seconds]]
+            Some((days, rest)) => {
+                let fields: Vec<&str> = rest.split(':').collect();
+                let (hours, minutes, seconds) = match fields.as_slice() {
+                    [h] => (parse_field(h, Some(23)), Some(0), Some(0)),
+                    [h, m] => (parse_field(h, Some(23)), parse_field(m, Some(59)), Some(0)),
+                    [h, m, s] => (
+                        parse_field(h, Some(23)),
+                        parse_field(m, Some(59)),
+                        parse_field(s, Some(59)),
+                    ),
+                    _ => (None, None, None),
+                };
+                match (parse_field(days, None), hours, minutes, seconds) {
+                    (Some(d), Some(h), Some(m), Some(s)) => d
+                        .checked_mul(86400)
+                        .and_then(|d| d.checked_add(h * 3600 + m * 60 + s)),
+                    _ => None,
+                }
+            }
+            // minutes, minutes:seconds, or hours:minutes:seconds
+            None => match s.split(':').collect::<Vec<&str>>().as_slice() {
+                [m] => parse_field(m, None).and_then(|m| m.checked_mul(60)),
+                [m, s] => match (parse_field(m, None), parse_field(s, Some(59))) {
+                    (Some(m), Some(s)) => m.checked_mul(60).and_then(|m| m.checked_add(s)),
+                    _ => None,
+                },
+                [h, m, s] => match (
+                    parse_field(h, None),
+                    parse_field(m, Some(59)),
+                    parse_field(s, Some(59)),
+                ) {
+                    (Some(h), Some(m), Some(s)) => {
+                        h.checked_mul(3600).and_then(|h| h.checked_add(m * 60 + s))
+                    }
+                    _ => None,
+                },
+                _ => None,
+            },
+        };
+
+        seconds
+            .map(TimeLimit::from_secs)
+            .ok_or_else(|| SbatchOptionError::InvalidTimeLimit(s.to_string()))
+    }
+}
+
+impl std::fmt::Display for TimeLimit {
+    /// Displays the time limit in the canonical `D-HH:MM:SS` form, or `UNLIMITED`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.seconds {
+            Some(seconds) => write!(
+                f,
+                "{}-{:02}:{:02}:{:02}",
+                seconds / 86400,
+                seconds % 86400 / 3600,
+                seconds % 3600 / 60,
+                seconds % 60
+            ),
+            None => write!(f, "UNLIMITED"),
+        }
+    }
+}
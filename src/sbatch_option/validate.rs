@@ -12,6 +12,127 @@ fn validate_str(s: &str) -> Result<(), SbatchOptionError> {
     }
 }
 
+// Event names accepted by `--mail-type`.
+const MAIL_TYPES: &[&str] = &[
+    "NONE",
+    "BEGIN",
+    "END",
+    "FAIL",
+    "REQUEUE",
+    "ALL",
+    "INVALID_DEPEND",
+    "STAGE_OUT",
+    "TIME_LIMIT",
+    "TIME_LIMIT_90",
+    "TIME_LIMIT_80",
+    "TIME_LIMIT_50",
+    "ARRAY_TASKS",
+];
+
+// Helper function to validate a `--mail-type` value.
+// This function checks that every comma-separated event name is a known Slurm event (case-insensitive).
+fn validate_mail_type(s: &str) -> Result<(), SbatchOptionError> {
+    validate_str(s)?;
+    match s
+        .split(',')
+        .find(|token| !MAIL_TYPES.contains(&token.to_ascii_uppercase().as_str()))
+    {
+        Some(token) => Err(SbatchOptionError::InvalidMailType(token.to_string())),
+        None => Ok(()),
+    }
+}
+
+// Helper function to validate a `--comment` value.
+// This function checks that the comment fits on a single line, so it cannot break the command.
+fn validate_comment(s: &str) -> Result<(), SbatchOptionError> {
+    validate_str(s)?;
+    if s.contains(['\n', '\r']) {
+        Err(SbatchOptionError::InvalidComment(s.to_string()))
+    } else {
+        Ok(())
+    }
+}
+
+// Values accepted by `--exclusive=`.
+const EXCLUSIVE_VALUES: &[&str] = &["user", "mcs", "topo"];
+
+// Helper function to validate an `--exclusive` value.
+// This function checks that the value is one of the sharing modes accepted by Slurm.
+fn validate_exclusive(s: &str) -> Result<(), SbatchOptionError> {
+    if EXCLUSIVE_VALUES.contains(&s) {
+        Ok(())
+    } else {
+        Err(SbatchOptionError::InvalidExclusiveValue(s.to_string()))
+    }
+}
+
+// Helper function to validate a `--wait-all-nodes` value.
+// Slurm only accepts `0` (start as soon as the allocation is made) or `1` (wait until all nodes are ready).
+fn validate_wait_all_nodes(s: &str) -> Result<(), SbatchOptionError> {
+    match s {
+        "0" | "1" => Ok(()),
+        _ => Err(SbatchOptionError::InvalidWaitAllNodes(s.to_string())),
+    }
+}
+
+// Helper function to validate a raw option.
+// The flag must start with `-` and contain only letters, digits, `-` and `_`, so it cannot inject shell syntax.
+// Anything after the first `=` or space is a value, which is quoted when displayed.
+fn validate_raw(s: &str) -> Result<(), SbatchOptionError> {
+    validate_str(s)?;
+    let flag = s.split(['=', ' ']).next().unwrap_or_default();
+    let name = flag.trim_start_matches('-');
+    if !flag.starts_with('-')
+        || name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(SbatchOptionError::InvalidRawOption(s.to_string()));
+    }
+    Ok(())
+}
+
+// Tokens accepted after `%` in `--output` and `--error` filename patterns.
+const FILENAME_TOKENS: &str = "AabJjNnstux";
+
+// Helper function to validate an `--output` or `--error` filename pattern.
+// This function checks that every `%` starts a known token, optionally preceded by a zero-padding width,
+// or is escaped as `%%`. A pattern containing a backslash is not substituted by Slurm, so it is not checked.
+fn validate_filename_pattern(s: &str) -> Result<(), SbatchOptionError> {
+    validate_str(s)?;
+    if s.contains('\\') {
+        return Ok(());
+    }
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            continue;
+        }
+        let has_width = chars.next_if(char::is_ascii_digit).is_some();
+        while chars.next_if(char::is_ascii_digit).is_some() {}
+        match chars.next() {
+            Some('%') if !has_width => {}
+            Some(token) if FILENAME_TOKENS.contains(token) => {}
+            _ => return Err(SbatchOptionError::InvalidFilenamePattern(s.to_string())),
+        }
+    }
+    Ok(())
+}
+
+// Largest nice adjustment accepted by Slurm, in either direction.
+const MAX_NICE: i32 = 2_147_483_645;
+
+// Helper function to validate a `--nice` adjustment.
+// This function checks that the adjustment is within the bounds accepted by Slurm.
+fn validate_nice(value: i32) -> Result<(), SbatchOptionError> {
+    if (-MAX_NICE..=MAX_NICE).contains(&value) {
+        Ok(())
+    } else {
+        Err(SbatchOptionError::NiceOutOfRange(value))
+    }
+}
+
 impl SbatchOption {
     /// Validates the sbatch option.
     ///
@@ -25,6 +146,13 @@ impl SbatchOption {
     /// The following are considered invalid:
     /// - An empty string
     /// - A string that contains leading or trailing spaces
+    /// - A `--mail-type` event name that Slurm does not recognize
+    /// - A `--comment` containing a newline
+    /// - An `--output` or `--error` filename pattern with a `%` that does not start a known token (e.g. `%j`) or `%%`
+    /// - A `--nice` adjustment outside of ±2147483645
+    /// - An `--exclusive` value other than `user`, `mcs`, or `topo`
+    /// - A `--wait-all-nodes` value other than `0` or `1`
+    /// - A raw option whose flag does not start with `-` or contains characters other than letters, digits, `-`, and `_`
     ///
     /// # Examples
     ///
@@ -47,33 +175,33 @@ impl SbatchOption {
         match self {
             SbatchOption::Account(value) => validate_str(value),
             SbatchOption::AcctgFreq(value) => validate_str(value),
-            SbatchOption::Array(value) => validate_str(value),
+            SbatchOption::Array(_) => Ok(()),
             SbatchOption::Batch(value) => validate_str(value),
             SbatchOption::Bb(value) => validate_str(value),
             SbatchOption::Bbf(value) => validate_str(value),
-            SbatchOption::Begin(value) => validate_str(value),
+            SbatchOption::Begin(_) => Ok(()),
             SbatchOption::Chdir(value) => validate_str(value),
             SbatchOption::ClusterConstraint(value) => validate_str(value),
             SbatchOption::Clusters(value) => validate_str(value),
-            SbatchOption::Comment(value) => validate_str(value),
-            SbatchOption::Constraint(value) => validate_str(value),
+            SbatchOption::Comment(value) => validate_comment(value),
+            SbatchOption::Constraint(_) => Ok(()),
             SbatchOption::Container(value) => validate_str(value),
             SbatchOption::ContainerID(value) => validate_str(value),
             SbatchOption::Contiguous => Ok(()),
-            SbatchOption::CoreSpec(value) => validate_str(value),
-            SbatchOption::CoresPerSocket(value) => validate_str(value),
-            SbatchOption::CPUFreq(value) => validate_str(value),
+            SbatchOption::CoreSpec(_) => Ok(()),
+            SbatchOption::CoresPerSocket(_) => Ok(()),
+            SbatchOption::CPUFreq(_) => Ok(()),
             SbatchOption::CPUsPerGPU(value) => validate_str(value),
             SbatchOption::CPUsPerTask(value) => validate_str(value),
             SbatchOption::Deadline(value) => validate_str(value),
             SbatchOption::DelayBoot(value) => validate_str(value),
             SbatchOption::Dependency(value) => validate_str(value),
-            SbatchOption::Distribution(value) => validate_str(value),
-            SbatchOption::Error(value) => validate_str(value),
-            SbatchOption::Exclude(value) => validate_str(value),
-            SbatchOption::Exclusive(Some(value)) => validate_str(value),
+            SbatchOption::Distribution(_) => Ok(()),
+            SbatchOption::Error(value) => validate_filename_pattern(value),
+            SbatchOption::Exclude(_) => Ok(()),
+            SbatchOption::Exclusive(Some(value)) => validate_exclusive(value),
             SbatchOption::Exclusive(None) => Ok(()),
-            SbatchOption::Export(value) => validate_str(value),
+            SbatchOption::Export(_) => Ok(()),
             SbatchOption::ExportFile(value) => validate_str(value),
             SbatchOption::Extra(value) => validate_str(value),
             SbatchOption::ExtraNodeInfo(value) => validate_str(value),
@@ -82,11 +210,11 @@ impl SbatchOption {
             SbatchOption::GID(value) => validate_str(value),
             SbatchOption::GPUBind(value) => validate_str(value),
             SbatchOption::GPUFreq(value) => validate_str(value),
-            SbatchOption::GPUs(value) => validate_str(value),
-            SbatchOption::GPUsPerNode(value) => validate_str(value),
-            SbatchOption::GPUsPerSocket(value) => validate_str(value),
-            SbatchOption::GPUsPerTask(value) => validate_str(value),
-            SbatchOption::Gres(value) => validate_str(value),
+            SbatchOption::GPUs(_) => Ok(()),
+            SbatchOption::GPUsPerNode(_) => Ok(()),
+            SbatchOption::GPUsPerSocket(_) => Ok(()),
+            SbatchOption::GPUsPerTask(_) => Ok(()),
+            SbatchOption::Gres(_) => Ok(()),
             SbatchOption::GresFlags(value) => validate_str(value),
             SbatchOption::Help => Ok(()),
             SbatchOption::Hint(value) => validate_str(value),
@@ -96,32 +224,32 @@ impl SbatchOption {
             SbatchOption::JobName(value) => validate_str(value),
             SbatchOption::KillOnInvalidDep(value) => validate_str(value),
             SbatchOption::Licenses(value) => validate_str(value),
-            SbatchOption::MailType(value) => validate_str(value),
+            SbatchOption::MailType(value) => validate_mail_type(value),
             SbatchOption::MailUser(value) => validate_str(value),
             SbatchOption::McsLabel(value) => validate_str(value),
-            SbatchOption::Mem(value) => validate_str(value),
+            SbatchOption::Mem(_) => Ok(()),
             SbatchOption::MemBind(value) => validate_str(value),
-            SbatchOption::MemPerCPU(value) => validate_str(value),
-            SbatchOption::MemPerGPU(value) => validate_str(value),
+            SbatchOption::MemPerCPU(_) => Ok(()),
+            SbatchOption::MemPerGPU(_) => Ok(()),
             SbatchOption::MinCPUs(value) => validate_str(value),
             SbatchOption::Network(value) => validate_str(value),
-            SbatchOption::Nice(Some(value)) => validate_str(value),
+            SbatchOption::Nice(Some(value)) => validate_nice(*value),
             SbatchOption::Nice(None) => Ok(()),
             SbatchOption::NoKill(Some(value)) => validate_str(value),
             SbatchOption::NoKill(None) => Ok(()),
             SbatchOption::NoRequeue => Ok(()),
             SbatchOption::NodeFile(value) => validate_str(value),
-            SbatchOption::NodeList(value) => validate_str(value),
+            SbatchOption::NodeList(_) => Ok(()),
             SbatchOption::Nodes(value) => validate_str(value),
-            SbatchOption::NTasks(value) => validate_str(value),
+            SbatchOption::NTasks(_) => Ok(()),
             SbatchOption::NTasksPerCore(value) => validate_str(value),
             SbatchOption::NTasksPerGPU(value) => validate_str(value),
-            SbatchOption::NTasksPerNode(value) => validate_str(value),
+            SbatchOption::NTasksPerNode(_) => Ok(()),
             SbatchOption::NTasksPerSocket(value) => validate_str(value),
             SbatchOption::OOMKillStep(Some(value)) => validate_str(value),
             SbatchOption::OOMKillStep(None) => Ok(()),
-            SbatchOption::OpenMode(value) => validate_str(value),
-            SbatchOption::Output(value) => validate_str(value),
+            SbatchOption::OpenMode(_) => Ok(()),
+            SbatchOption::Output(value) => validate_filename_pattern(value),
             SbatchOption::Overcommit => Ok(()),
             SbatchOption::Oversubscribe => Ok(()),
             SbatchOption::Parsable => Ok(()),
@@ -133,22 +261,23 @@ impl SbatchOption {
             SbatchOption::Propagate(None) => Ok(()),
             SbatchOption::Qos(value) => validate_str(value),
             SbatchOption::Quiet => Ok(()),
+            SbatchOption::Raw(value) => validate_raw(value),
             SbatchOption::Reboot => Ok(()),
             SbatchOption::Requeue => Ok(()),
             SbatchOption::Reservation(value) => validate_str(value),
             SbatchOption::ResvPorts(Some(value)) => validate_str(value),
             SbatchOption::ResvPorts(None) => Ok(()),
             SbatchOption::Segment(value) => validate_str(value),
-            SbatchOption::Signal(value) => validate_str(value),
-            SbatchOption::SocketsPerNode(value) => validate_str(value),
+            SbatchOption::Signal(_) => Ok(()),
+            SbatchOption::SocketsPerNode(_) => Ok(()),
             SbatchOption::SpreadJob => Ok(()),
             SbatchOption::Stepmgr => Ok(()),
             SbatchOption::Switches(value) => validate_str(value),
             SbatchOption::TestOnly => Ok(()),
             SbatchOption::ThreadSpec(value) => validate_str(value),
             SbatchOption::ThreadsPerCore(value) => validate_str(value),
-            SbatchOption::Time(value) => validate_str(value),
-            SbatchOption::TimeMin(value) => validate_str(value),
+            SbatchOption::Time(_) => Ok(()),
+            SbatchOption::TimeMin(_) => Ok(()),
             SbatchOption::Tmp(value) => validate_str(value),
             SbatchOption::TresBind(value) => validate_str(value),
             SbatchOption::TresPerTask(value) => validate_str(value),
@@ -158,7 +287,7 @@ impl SbatchOption {
             SbatchOption::Verbose => Ok(()),
             SbatchOption::Version => Ok(()),
             SbatchOption::Wait => Ok(()),
-            SbatchOption::WaitAllNodes(value) => validate_str(value),
+            SbatchOption::WaitAllNodes(value) => validate_wait_all_nodes(value),
             SbatchOption::WCKey(value) => validate_str(value),
             SbatchOption::Wrap(_) => Ok(()), // Allow --wrap="" to be empty
         }
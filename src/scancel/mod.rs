@@ -0,0 +1,195 @@
+//! This module provides a builder for the `scancel` command in Slurm.
+
+use thiserror::Error;
+
+// Job states accepted by `scancel --state`, in long and short form.
+const STATES: &[&str] = &["PENDING", "PD", "RUNNING", "R", "SUSPENDED", "S"];
+
+/// scancel command builder
+///
+/// Job ids are kept as strings, so shell variables such as `$JOB_ID` or `${JOB_ID}` can be used.
+/// Values are written to the command as-is, so they may not contain whitespace.
+///
+/// # Examples
+///
+/// ```
+/// use sbatch_rs::Scancel;
+///
+/// let command = Scancel::new()
+///     .set_job_name("test").unwrap()
+///     .add_job_id("1234").unwrap()
+///     .add_job_id("${JOB_ID}").unwrap()
+///     .build();
+///
+/// assert_eq!(command.unwrap(), "scancel --name=test 1234 ${JOB_ID}");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Scancel {
+    job_ids: Vec<String>,
+    job_name: Option<String>,
+    partition: Option<String>,
+    signal: Option<String>,
+    state: Option<String>,
+    user: Option<String>,
+}
+
+/// Represents an error that can occur when building a `scancel` command.
+///
+/// - `NoJobsOrFilters`: Indicates that neither a job id nor a filter was provided.
+/// - `EmptyValue`: Indicates that a value is empty.
+/// - `InvalidValue`: Indicates that a value contains whitespace.
+/// - `InvalidState`: Indicates that a job state is not one `scancel` accepts.
+#[derive(Debug, Error)]
+pub enum ScancelError {
+    #[error("No job ids or filters provided")]
+    NoJobsOrFilters,
+    #[error("Empty value")]
+    EmptyValue,
+    #[error("Value contains whitespace: {0:?}")]
+    InvalidValue(String),
+    #[error("Invalid job state: {0}")]
+    InvalidState(String),
+}
+
+// Helper function to validate a value.
+// This function checks that the value is not empty and contains no whitespace.
+fn validate_value(value: &str) -> Result<String, ScancelError> {
+    if value.is_empty() {
+        Err(ScancelError::EmptyValue)
+    } else if value.contains(char::is_whitespace) {
+        Err(ScancelError::InvalidValue(value.to_string()))
+    } else {
+        Ok(value.to_string())
+    }
+}
+
+impl Scancel {
+    /// Creates a new `Scancel` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::Scancel;
+    ///
+    /// // Create a new `Scancel` instance
+    /// let scancel = Scancel::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a job id to cancel, such as `1234`, `1234_5` for an array task, or `$JOB_ID`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `ScancelError` if the job id is empty or contains whitespace.
+    pub fn add_job_id(&mut self, job_id: &str) -> Result<&mut Self, ScancelError> {
+        self.job_ids.push(validate_value(job_id)?);
+        Ok(self)
+    }
+
+    /// Restricts the cancellation to jobs with the given name (`--name`).
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `ScancelError` if the name is empty or contains whitespace.
+    pub fn set_job_name(&mut self, job_name: &str) -> Result<&mut Self, ScancelError> {
+        self.job_name = Some(validate_value(job_name)?);
+        Ok(self)
+    }
+
+    /// Restricts the cancellation to jobs in the given partition (`--partition`).
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `ScancelError` if the partition is empty or contains whitespace.
+    pub fn set_partition(&mut self, partition: &str) -> Result<&mut Self, ScancelError> {
+        self.partition = Some(validate_value(partition)?);
+        Ok(self)
+    }
+
+    /// Sends the given signal instead of cancelling the jobs (`--signal`), such as `USR1` or `10`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `ScancelError` if the signal is empty or contains whitespace.
+    pub fn set_signal(&mut self, signal: &str) -> Result<&mut Self, ScancelError> {
+        self.signal = Some(validate_value(signal)?);
+        Ok(self)
+    }
+
+    /// Restricts the cancellation to jobs in the given state (`--state`).
+    ///
+    /// The state may be `PENDING`, `RUNNING`, or `SUSPENDED`, or their short forms `PD`, `R`, and `S`,
+    /// in any case.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `ScancelError::InvalidState` if the state is not one of these.
+    pub fn set_state(&mut self, state: &str) -> Result<&mut Self, ScancelError> {
+        if !STATES.contains(&state.to_ascii_uppercase().as_str()) {
+            return Err(ScancelError::InvalidState(state.to_string()));
+        }
+        self.state = Some(state.to_string());
+        Ok(self)
+    }
+
+    /// Restricts the cancellation to jobs of the given user (`--user`).
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `ScancelError` if the user is empty or contains whitespace.
+    pub fn set_user(&mut self, user: &str) -> Result<&mut Self, ScancelError> {
+        self.user = Some(validate_value(user)?);
+        Ok(self)
+    }
+
+    /// Builds the `scancel` command.
+    ///
+    /// Options are written in alphabetical order, followed by the job ids in the order they were added.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `ScancelError::NoJobsOrFilters` if no job id, name, partition, state, or user
+    /// was set, since `scancel` would have nothing to select.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::Scancel;
+    ///
+    /// let command = Scancel::new()
+    ///     .set_user("alice").unwrap()
+    ///     .set_state("pending").unwrap()
+    ///     .build();
+    /// assert_eq!(command.unwrap(), "scancel --state=pending --user=alice");
+    ///
+    /// // A signal alone does not select any jobs
+    /// assert!(Scancel::new().set_signal("USR1").unwrap().build().is_err());
+    /// ```
+    pub fn build(&self) -> Result<String, ScancelError> {
+        let options = [
+            ("name", &self.job_name),
+            ("partition", &self.partition),
+            ("signal", &self.signal),
+            ("state", &self.state),
+            ("user", &self.user),
+        ];
+        if self.job_ids.is_empty()
+            && options
+                .iter()
+                .all(|(flag, value)| *flag == "signal" || value.is_none())
+        {
+            return Err(ScancelError::NoJobsOrFilters);
+        }
+
+        let mut parts = vec!["scancel".to_string()];
+        for (flag, value) in options {
+            if let Some(value) = value {
+                parts.push(format!("--{}={}", flag, value));
+            }
+        }
+        parts.extend(self.job_ids.iter().cloned());
+        Ok(parts.join(" "))
+    }
+}
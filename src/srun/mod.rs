@@ -0,0 +1,232 @@
+//! This module provides a builder for the `srun` command in Slurm.
+
+use std::collections::BTreeSet;
+use thiserror::Error;
+
+use crate::{SbatchOption, SbatchOptionError};
+
+// Helper function to check whether an option is only accepted by sbatch.
+// `--wait` is also an srun option, but there it takes a number of seconds and means something else.
+fn is_sbatch_only(option: &SbatchOption) -> bool {
+    matches!(
+        option,
+        SbatchOption::Array(_)
+            | SbatchOption::Batch(_)
+            | SbatchOption::Begin(_)
+            | SbatchOption::Parsable
+            | SbatchOption::Wait
+            | SbatchOption::Wrap(_)
+    )
+}
+
+/// srun command builder
+///
+/// The options shared with `sbatch`, such as `--ntasks` or `--partition`, are given as `SbatchOption`s,
+/// so the same option values can drive both batch and interactive launches. Options that only `sbatch`
+/// accepts, such as `--array` or `--wrap`, are rejected.
+///
+/// # Examples
+///
+/// ```
+/// use sbatch_rs::{SbatchOption, Srun};
+///
+/// let command = Srun::new()
+///     .add_option(SbatchOption::NTasks("4".parse().unwrap())).unwrap()
+///     .add_option(SbatchOption::Partition("debug".to_string())).unwrap()
+///     .set_label(true)
+///     .set_command("hostname".to_string()).unwrap()
+///     .build();
+///
+/// assert_eq!(command.unwrap(), "srun --ntasks=4 --partition=debug --label hostname");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Srun {
+    options: Vec<SbatchOption>,
+    label: bool,
+    pty: bool,
+    command: Option<String>,
+}
+
+/// Represents an error that can occur when building an `srun` command.
+///
+/// - `NoCommand`: Indicates that no command to run was provided.
+/// - `CommandEmpty`: Indicates that the command is empty.
+/// - `UnsupportedOption`: Indicates that an option is only accepted by `sbatch`.
+/// - `DuplicateOption`: Indicates that an option of the same variant is already set to a different value.
+/// - `SbatchOptionError`: Indicates that an option is invalid.
+#[derive(Debug, Error)]
+pub enum SrunError {
+    #[error("No command provided")]
+    NoCommand,
+    #[error("Command is empty")]
+    CommandEmpty,
+    #[error("Option is not supported by srun: {0}")]
+    UnsupportedOption(SbatchOption),
+    #[error("Option {0} is already set, cannot add {1}")]
+    DuplicateOption(SbatchOption, SbatchOption),
+    #[error("Sbatch option error: {0}")]
+    SbatchOptionError(#[from] SbatchOptionError),
+}
+
+impl Srun {
+    /// Creates a new `Srun` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::Srun;
+    ///
+    /// // Create a new `Srun` instance
+    /// let srun = Srun::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an option shared with `sbatch`.
+    ///
+    /// Adding an option identical to one already present does nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - An `SbatchOption` to add to the `Srun` instance.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a mutable reference to the `Srun` instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SrunError::UnsupportedOption` if the option is only accepted by `sbatch`
+    /// (`--array`, `--batch`, `--begin`, `--parsable`, `--wait`, or `--wrap`), a `SrunError::DuplicateOption`
+    /// if an option of the same variant is already set to a different value, or a `SrunError::SbatchOptionError`
+    /// if the option is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{SbatchOption, Srun, SrunError};
+    ///
+    /// let mut srun = Srun::new();
+    /// assert!(srun.add_option(SbatchOption::NTasks("4".parse().unwrap())).is_ok());
+    /// assert!(matches!(
+    ///     srun.add_option(SbatchOption::Wrap("hostname".to_string())),
+    ///     Err(SrunError::UnsupportedOption(_))
+    /// ));
+    /// ```
+    pub fn add_option(&mut self, option: SbatchOption) -> Result<&mut Self, SrunError> {
+        if is_sbatch_only(&option) {
+            return Err(SrunError::UnsupportedOption(option));
+        }
+        option.validate()?;
+
+        let existing = self
+            .options
+            .iter()
+            .find(|existing| existing.is_same_variant(&option));
+        match existing {
+            Some(existing) if *existing != option => {
+                Err(SrunError::DuplicateOption(existing.clone(), option))
+            }
+            Some(_) => Ok(self),
+            None => {
+                self.options.push(option);
+                Ok(self)
+            }
+        }
+    }
+
+    /// Sets whether each line of output is prefixed with the task number (`--label`).
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - Whether to add the `--label` flag.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a mutable reference to the `Srun` instance.
+    pub fn set_label(&mut self, label: bool) -> &mut Self {
+        self.label = label;
+        self
+    }
+
+    /// Sets whether task zero is run in a pseudo terminal (`--pty`), as for an interactive shell.
+    ///
+    /// # Arguments
+    ///
+    /// * `pty` - Whether to add the `--pty` flag.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a mutable reference to the `Srun` instance.
+    pub fn set_pty(&mut self, pty: bool) -> &mut Self {
+        self.pty = pty;
+        self
+    }
+
+    /// Sets the command to run, including its arguments.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The command to run, such as `hostname` or `python train.py`.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a mutable reference to the `Srun` instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SrunError::CommandEmpty` if the command is empty.
+    pub fn set_command(&mut self, command: String) -> Result<&mut Self, SrunError> {
+        let command = command.trim().to_string();
+        if command.is_empty() {
+            return Err(SrunError::CommandEmpty);
+        }
+        self.command = Some(command);
+        Ok(self)
+    }
+
+    /// Builds the `srun` command.
+    ///
+    /// The shared options are sorted by flag name, as `Sbatch` does by default, and are followed by the
+    /// srun-specific flags and the command.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `SrunError::NoCommand` if no command was set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sbatch_rs::{SbatchOption, Srun};
+    ///
+    /// let command = Srun::new()
+    ///     .add_option(SbatchOption::Time("30".parse().unwrap())).unwrap()
+    ///     .set_pty(true)
+    ///     .set_command("bash -i".to_string()).unwrap()
+    ///     .build();
+    /// assert_eq!(command.unwrap(), "srun --time=0-00:30:00 --pty bash -i");
+    ///
+    /// assert!(Srun::new().build().is_err());
+    /// ```
+    pub fn build(&self) -> Result<String, SrunError> {
+        let command = self.command.as_ref().ok_or(SrunError::NoCommand)?;
+
+        let mut parts = vec!["srun".to_string()];
+        parts.extend(
+            self.options
+                .iter()
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .map(SbatchOption::to_string),
+        );
+        if self.label {
+            parts.push("--label".to_string());
+        }
+        if self.pty {
+            parts.push("--pty".to_string());
+        }
+        parts.push(command.clone());
+        Ok(parts.join(" "))
+    }
+}
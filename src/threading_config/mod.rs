@@ -0,0 +1,155 @@
+//! This module provides a helper for the threading-related sbatch options.
+
+use std::num::NonZeroU32;
+use thiserror::Error;
+
+use crate::SbatchOption;
+
+/// Value of the `--hint` option
+///
+/// - `ComputeBound`: Maps to `compute_bound`, using all cores in each socket with one thread per core.
+/// - `MemoryBound`: Maps to `memory_bound`, using only one core in each socket with one thread per core.
+/// - `Multithread`: Maps to `multithread`, using extra threads with in-core multi-threading.
+/// - `NoMultithread`: Maps to `nomultithread`, avoiding extra threads with in-core multi-threading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Hint {
+    ComputeBound,
+    MemoryBound,
+    Multithread,
+    NoMultithread,
+}
+
+impl std::fmt::Display for Hint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Hint::ComputeBound => write!(f, "compute_bound"),
+            Hint::MemoryBound => write!(f, "memory_bound"),
+            Hint::Multithread => write!(f, "multithread"),
+            Hint::NoMultithread => write!(f, "nomultithread"),
+        }
+    }
+}
+
+/// Threading configuration builder
+///
+/// Bundles the `--hint`, `--threads-per-core`, and `--ntasks-per-core` options, which interact with each other,
+/// and checks that the combination is coherent before producing the options.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroU32;
+/// use sbatch_rs::{Hint, SbatchOption, ThreadingConfig};
+///
+/// let mut config = ThreadingConfig::new();
+/// config
+///     .set_hint(Hint::Multithread)
+///     .set_threads_per_core(NonZeroU32::new(2).unwrap());
+/// assert_eq!(
+///     config.into_options().unwrap(),
+///     vec![
+///         SbatchOption::Hint("multithread".to_string()),
+///         SbatchOption::ThreadsPerCore("2".to_string()),
+///     ]
+/// );
+///
+/// // Disabling multithreading while asking for two threads per core is contradictory
+/// let mut config = ThreadingConfig::new();
+/// config
+///     .set_hint(Hint::NoMultithread)
+///     .set_threads_per_core(NonZeroU32::new(2).unwrap());
+/// assert!(config.into_options().is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ThreadingConfig {
+    hint: Option<Hint>,
+    threads_per_core: Option<NonZeroU32>,
+    ntasks_per_core: Option<NonZeroU32>,
+}
+
+/// Represents an error that can occur when validating a `ThreadingConfig`.
+///
+/// - `Contradictory`: Indicates that two of the threading options contradict each other.
+#[derive(Debug, Error)]
+pub enum ThreadingConfigError {
+    #[error("Contradictory threading options: {0}")]
+    Contradictory(String),
+}
+
+impl ThreadingConfig {
+    /// Creates a new, empty `ThreadingConfig` instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `--hint` option.
+    pub fn set_hint(&mut self, hint: Hint) -> &mut Self {
+        self.hint = Some(hint);
+        self
+    }
+
+    /// Sets the `--threads-per-core` option.
+    pub fn set_threads_per_core(&mut self, threads_per_core: NonZeroU32) -> &mut Self {
+        self.threads_per_core = Some(threads_per_core);
+        self
+    }
+
+    /// Sets the `--ntasks-per-core` option.
+    pub fn set_ntasks_per_core(&mut self, ntasks_per_core: NonZeroU32) -> &mut Self {
+        self.ntasks_per_core = Some(ntasks_per_core);
+        self
+    }
+
+    /// Validates that the threading options are coherent.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `ThreadingConfigError::Contradictory` if:
+    /// - `--hint=nomultithread` is combined with more than one thread or task per core
+    /// - `--hint=multithread` is combined with `--threads-per-core=1`
+    pub fn validate(&self) -> Result<(), ThreadingConfigError> {
+        match (self.hint, self.threads_per_core, self.ntasks_per_core) {
+            (Some(Hint::NoMultithread), Some(threads), _) if threads.get() > 1 => {
+                Err(ThreadingConfigError::Contradictory(format!(
+                    "--hint=nomultithread with --threads-per-core={threads}"
+                )))
+            }
+            (Some(Hint::NoMultithread), _, Some(ntasks)) if ntasks.get() > 1 => {
+                Err(ThreadingConfigError::Contradictory(format!(
+                    "--hint=nomultithread with --ntasks-per-core={ntasks}"
+                )))
+            }
+            (Some(Hint::Multithread), Some(threads), _) if threads.get() == 1 => {
+                Err(ThreadingConfigError::Contradictory(
+                    "--hint=multithread with --threads-per-core=1".to_string(),
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Validates the configuration and converts it into sbatch options.
+    ///
+    /// # Returns
+    ///
+    /// This function returns the `--hint`, `--threads-per-core`, and `--ntasks-per-core` options that were set.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `ThreadingConfigError` if the configuration is not coherent.
+    pub fn into_options(self) -> Result<Vec<SbatchOption>, ThreadingConfigError> {
+        self.validate()?;
+
+        let mut options = Vec::new();
+        if let Some(hint) = self.hint {
+            options.push(SbatchOption::Hint(hint.to_string()));
+        }
+        if let Some(threads) = self.threads_per_core {
+            options.push(SbatchOption::ThreadsPerCore(threads.to_string()));
+        }
+        if let Some(ntasks) = self.ntasks_per_core {
+            options.push(SbatchOption::NTasksPerCore(ntasks.to_string()));
+        }
+        Ok(options)
+    }
+}
@@ -1,2 +1,4 @@
+mod test_accessors;
 mod test_display;
+mod test_from_str;
 mod test_validate;
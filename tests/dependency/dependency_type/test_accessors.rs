@@ -0,0 +1,108 @@
+use rstest::rstest;
+use std::time::Duration;
+
+use sbatch_rs::DependencyType;
+
+#[rstest]
+#[case(DependencyType::After("1".to_string()), Some("1"), None)]
+#[case(DependencyType::AfterTimeDelay("2".to_string(), "10".to_string()), Some("2"), Some("10"))]
+#[case(DependencyType::AfterAny("3".to_string()), Some("3"), None)]
+#[case(DependencyType::AfterBurstBuffer("4".to_string()), Some("4"), None)]
+#[case(DependencyType::AfterCorr("5".to_string()), Some("5"), None)]
+#[case(DependencyType::AfterNotOk("6".to_string()), Some("6"), None)]
+#[case(DependencyType::AfterOk("7".to_string()), Some("7"), None)]
+#[case(DependencyType::Singleton, None, None)]
+fn test_job_id_and_time_delay(
+    #[case] dependency_type: DependencyType,
+    #[case] job_id: Option<&str>,
+    #[case] time_delay: Option<&str>,
+) {
+    assert_eq!(dependency_type.job_id(), job_id);
+    assert_eq!(dependency_type.time_delay(), time_delay);
+}
+
+#[rstest]
+#[case("123", Some(123))]
+#[case("4294967295", Some(u32::MAX))]
+#[case("0", None)]
+#[case("4294967296", None)]
+#[case("+123", None)]
+#[case("123_4", None)]
+#[case("$JOB_ID", None)]
+fn test_job_id_number(#[case] job_id: &str, #[case] expected: Option<u32>) {
+    let dependency_type = DependencyType::AfterOk(job_id.to_string());
+    assert_eq!(
+        dependency_type.job_id_number().map(|number| number.get()),
+        expected
+    );
+}
+
+#[rstest]
+#[case("$JOB_ID", Some("JOB_ID"))]
+#[case("${JOB_ID}", Some("JOB_ID"))]
+#[case("${STEP:-1}", Some("STEP"))]
+#[case("${JOB:-${FALLBACK:-1}}", Some("JOB"))]
+#[case("_x1", None)]
+#[case("123", None)]
+#[case("$1", None)]
+#[case("$JOB_ID_$TASK", None)]
+#[case("${A}${B}", None)]
+#[case("${A:-1}${B}", None)]
+#[case("${A", None)]
+#[case("${}", None)]
+fn test_job_id_variable(#[case] job_id: &str, #[case] expected: Option<&str>) {
+    let dependency_type = DependencyType::AfterOk(job_id.to_string());
+    assert_eq!(dependency_type.job_id_variable(), expected);
+}
+
+#[test]
+fn test_singleton_has_no_job_id() {
+    assert_eq!(DependencyType::Singleton.job_id_number(), None);
+    assert_eq!(DependencyType::Singleton.job_id_variable(), None);
+}
+
+#[rstest]
+#[case("10", Some(10))]
+#[case("4294967295", Some(u32::MAX))]
+#[case("0", None)]
+#[case("+10", None)]
+#[case("$DELAY", None)]
+fn test_time_delay_minutes(#[case] time_delay: &str, #[case] expected: Option<u32>) {
+    let dependency_type = DependencyType::AfterTimeDelay("1".to_string(), time_delay.to_string());
+    assert_eq!(
+        dependency_type
+            .time_delay_minutes()
+            .map(|minutes| minutes.get()),
+        expected
+    );
+    assert_eq!(
+        dependency_type.time_delay_duration(),
+        expected.map(|minutes| Duration::from_secs(u64::from(minutes) * 60))
+    );
+}
+
+#[rstest]
+#[case(DependencyType::AfterTimeDelay("1".to_string(), "10".to_string()), 5, Some(DependencyType::AfterTimeDelay("1".to_string(), "15".to_string())))]
+#[case(DependencyType::AfterTimeDelay("1".to_string(), "10".to_string()), 0, Some(DependencyType::AfterTimeDelay("1".to_string(), "10".to_string())))]
+#[case(DependencyType::After("1".to_string()), 5, Some(DependencyType::AfterTimeDelay("1".to_string(), "5".to_string())))]
+#[case(DependencyType::After("1".to_string()), 0, Some(DependencyType::After("1".to_string())))]
+#[case(DependencyType::AfterTimeDelay("1".to_string(), "4294967294".to_string()), 1, Some(DependencyType::AfterTimeDelay("1".to_string(), "4294967295".to_string())))]
+#[case(DependencyType::AfterTimeDelay("1".to_string(), "4294967295".to_string()), 1, None)]
+#[case(DependencyType::AfterTimeDelay("1".to_string(), "1".to_string()), u32::MAX, None)]
+#[case(DependencyType::AfterTimeDelay("1".to_string(), "$DELAY".to_string()), 5, None)]
+#[case(DependencyType::AfterOk("1".to_string()), 5, None)]
+#[case(DependencyType::Singleton, 5, None)]
+fn test_checked_add_time_delay(
+    #[case] dependency_type: DependencyType,
+    #[case] minutes: u32,
+    #[case] expected: Option<DependencyType>,
+) {
+    assert_eq!(dependency_type.checked_add_time_delay(minutes), expected);
+}
+
+#[test]
+fn test_time_delay_absent() {
+    let dependency_type = DependencyType::After("1".to_string());
+    assert_eq!(dependency_type.time_delay_minutes(), None);
+    assert_eq!(dependency_type.time_delay_duration(), None);
+}
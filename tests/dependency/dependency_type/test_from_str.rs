@@ -0,0 +1,55 @@
+use rstest::rstest;
+use sbatch_rs::{DependencyType, DependencyTypeError};
+
+#[rstest]
+#[case("123", DependencyType::After("123".to_string()))]
+#[case("$x", DependencyType::After("$x".to_string()))]
+#[case("${JOB_ID}", DependencyType::After("${JOB_ID}".to_string()))]
+#[case("123+10", DependencyType::AfterTimeDelay("123".to_string(), "10".to_string()))]
+#[case("after:123", DependencyType::After("123".to_string()))]
+#[case("after:123+10", DependencyType::AfterTimeDelay("123".to_string(), "10".to_string()))]
+#[case("afterany:123", DependencyType::AfterAny("123".to_string()))]
+#[case("afterburstbuffer:123", DependencyType::AfterBurstBuffer("123".to_string()))]
+#[case("aftercorr:123", DependencyType::AfterCorr("123".to_string()))]
+#[case("afternotok:123", DependencyType::AfterNotOk("123".to_string()))]
+#[case("afterok:123", DependencyType::AfterOk("123".to_string()))]
+#[case("singleton", DependencyType::Singleton)]
+#[case("afterok:${STEP:-1}", DependencyType::AfterOk("${STEP:-1}".to_string()))]
+#[case("${STEP:-1}", DependencyType::After("${STEP:-1}".to_string()))]
+#[case("after:${STEP:-1}+${DELAY:-10}", DependencyType::AfterTimeDelay("${STEP:-1}".to_string(), "${DELAY:-10}".to_string()))]
+#[case("afterany:${JOB:-${FALLBACK:-1}}", DependencyType::AfterAny("${JOB:-${FALLBACK:-1}}".to_string()))]
+fn test_from_str(#[case] input: &str, #[case] expected: DependencyType) {
+    assert_eq!(input.parse::<DependencyType>().unwrap(), expected);
+}
+
+#[rstest]
+#[case("afterfoo:123")]
+#[case("afterok:1:2")]
+#[case(":123")]
+#[case("afterok:${STEP:-1")]
+#[case("afterok:${STEP:-1}:2")]
+fn test_from_str_invalid(#[case] input: &str) {
+    assert!(matches!(
+        input.parse::<DependencyType>(),
+        Err(DependencyTypeError::InvalidDependencyType(_))
+    ));
+}
+
+#[rstest]
+#[case("")]
+#[case("afterok:")]
+#[case("after:123+")]
+fn test_from_str_empty(#[case] input: &str) {
+    assert!(matches!(
+        input.parse::<DependencyType>(),
+        Err(DependencyTypeError::EmptyDependencyType)
+    ));
+}
+
+#[test]
+fn test_from_str_spaces() {
+    assert!(matches!(
+        " 123".parse::<DependencyType>(),
+        Err(DependencyTypeError::LeadingOrTrailingSpaces)
+    ));
+}
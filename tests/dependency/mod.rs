@@ -1,6 +1,12 @@
 mod dependency_type;
 
 mod test_build;
+mod test_canonical_key;
+mod test_dedup;
+mod test_from_str;
+mod test_from_vec;
+mod test_remove;
+mod test_to_ordered_string;
 
 mod test_push_failure;
 mod test_push_success;
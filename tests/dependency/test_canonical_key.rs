@@ -0,0 +1,29 @@
+use rstest::rstest;
+use sbatch_rs::Dependency;
+
+#[rstest]
+#[case("after:1,afterok:2", "afterok:2,after:1")]
+#[case("afterok:1:2,after:3", "after:3,afterok:2,afterok:1")]
+#[case("after:1?after:2", "after:2?after:1?after:2")]
+#[case("after:1", "after:1?after:1")]
+#[case("singleton,after:1", "after:1,singleton,singleton")]
+fn test_canonical_key_is_order_independent(#[case] a: &str, #[case] b: &str) {
+    let a: Dependency = a.parse().unwrap();
+    let b: Dependency = b.parse().unwrap();
+    assert_eq!(a.canonical_key(), b.canonical_key());
+}
+
+#[rstest]
+#[case("after:1,after:2", "after:1?after:2")]
+#[case("after:1,after:2", "after:1,after:3")]
+#[case("after:1", "afterok:1")]
+fn test_canonical_key_distinguishes(#[case] a: &str, #[case] b: &str) {
+    let a: Dependency = a.parse().unwrap();
+    let b: Dependency = b.parse().unwrap();
+    assert_ne!(a.canonical_key(), b.canonical_key());
+}
+
+#[test]
+fn test_canonical_key_empty() {
+    assert_eq!(Dependency::new_and().canonical_key(), "");
+}
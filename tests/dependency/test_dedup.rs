@@ -0,0 +1,35 @@
+use rstest::rstest;
+use sbatch_rs::{Dependency, DependencyType};
+
+#[rstest]
+#[case(Dependency::And(vec![DependencyType::After("1".to_string()), DependencyType::After("1".to_string())]), "after:1")]
+#[case(Dependency::Or(vec![DependencyType::After("1".to_string()), DependencyType::After("1".to_string())]), "after:1")]
+#[case(Dependency::And(vec![DependencyType::After("1".to_string()), DependencyType::AfterOk("2".to_string()), DependencyType::After("1".to_string())]), "after:1,afterok:2")]
+#[case(Dependency::And(vec![DependencyType::Singleton, DependencyType::Singleton]), "singleton")]
+fn test_build_collapses_duplicates(#[case] dependency: Dependency, #[case] expected: &str) {
+    assert_eq!(dependency.build().unwrap(), expected);
+}
+
+#[test]
+fn test_dedup() {
+    let mut dependency = Dependency::new_or();
+    dependency
+        .push_after_ok("2")
+        .unwrap()
+        .push_after("1")
+        .unwrap()
+        .push_after_ok("2")
+        .unwrap()
+        .push_after("1")
+        .unwrap()
+        .dedup();
+
+    assert_eq!(
+        dependency,
+        Dependency::Or(vec![
+            DependencyType::AfterOk("2".to_string()),
+            DependencyType::After("1".to_string()),
+        ])
+    );
+    assert_eq!(dependency.build().unwrap(), "after:1?afterok:2");
+}
@@ -0,0 +1,76 @@
+use rstest::rstest;
+use sbatch_rs::{Dependency, DependencyError, DependencyType};
+
+#[rstest]
+#[case("after:1", Dependency::And(vec![DependencyType::After("1".to_string())]))]
+#[case("afterok:1,afterok:2", Dependency::And(vec![DependencyType::AfterOk("1".to_string()), DependencyType::AfterOk("2".to_string())]))]
+#[case("afterok:1:2", Dependency::And(vec![DependencyType::AfterOk("1".to_string()), DependencyType::AfterOk("2".to_string())]))]
+#[case("afternotok:1?afterany:2:3", Dependency::Or(vec![DependencyType::AfterNotOk("1".to_string()), DependencyType::AfterAny("2".to_string()), DependencyType::AfterAny("3".to_string())]))]
+#[case("after:1+10:2", Dependency::And(vec![DependencyType::AfterTimeDelay("1".to_string(), "10".to_string()), DependencyType::After("2".to_string())]))]
+#[case("afterburstbuffer:1,aftercorr:2,singleton", Dependency::And(vec![DependencyType::AfterBurstBuffer("1".to_string()), DependencyType::AfterCorr("2".to_string()), DependencyType::Singleton]))]
+#[case(" singleton ", Dependency::And(vec![DependencyType::Singleton]))]
+#[case("afterok:${STEP:-1}", Dependency::And(vec![DependencyType::AfterOk("${STEP:-1}".to_string())]))]
+#[case("afterok:${A:-1}:${B:-2}?after:3", Dependency::Or(vec![DependencyType::AfterOk("${A:-1}".to_string()), DependencyType::AfterOk("${B:-2}".to_string()), DependencyType::After("3".to_string())]))]
+#[case("afterok:${IDS:-1,2}", Dependency::And(vec![DependencyType::AfterOk("${IDS:-1,2}".to_string())]))]
+#[case("afterok:${jid}", Dependency::And(vec![DependencyType::AfterOk("${jid}".to_string())]))]
+#[case("afterok:1:${jid}", Dependency::And(vec![DependencyType::AfterOk("1".to_string()), DependencyType::AfterOk("${jid}".to_string())]))]
+#[case("afterok:$jid:2", Dependency::And(vec![DependencyType::AfterOk("$jid".to_string()), DependencyType::AfterOk("2".to_string())]))]
+#[case("afterany:${SLURM_JOB_ID}:$jid?singleton", Dependency::Or(vec![DependencyType::AfterAny("${SLURM_JOB_ID}".to_string()), DependencyType::AfterAny("$jid".to_string()), DependencyType::Singleton]))]
+fn test_from_str(#[case] input: &str, #[case] expected: Dependency) {
+    assert_eq!(input.parse::<Dependency>().unwrap(), expected);
+}
+
+#[rstest]
+#[case("after:123,after:456+10")]
+#[case("afterany:1?afterok:2?singleton")]
+#[case("after:${PREV:-2}+5,afterok:${STEP:-1}")]
+#[case("afterok:${jid},afterok:1")]
+fn test_from_str_round_trip(#[case] input: &str) {
+    assert_eq!(input.parse::<Dependency>().unwrap().build().unwrap(), input);
+}
+
+#[rstest]
+#[case("afterok")]
+#[case("afterok:")]
+#[case("afterok:1:")]
+#[case("afterok:1,")]
+#[case("after:1+")]
+#[case("after:+10")]
+#[case("afterfoo:1")]
+#[case("singleton:1")]
+#[case("afterok:${STEP:-1")]
+fn test_from_str_invalid(#[case] input: &str) {
+    assert!(matches!(
+        input.parse::<Dependency>(),
+        Err(DependencyError::InvalidDependency(_))
+    ));
+}
+
+#[rstest]
+#[case("after:1,after:2", Dependency::And(vec![DependencyType::After("1".to_string()), DependencyType::After("2".to_string())]))]
+#[case("after:1?after:2", Dependency::Or(vec![DependencyType::After("1".to_string()), DependencyType::After("2".to_string())]))]
+#[case("\"afterok:1?afterok:2\"", Dependency::Or(vec![DependencyType::AfterOk("1".to_string()), DependencyType::AfterOk("2".to_string())]))]
+#[case("'afterok:1,afterok:2'", Dependency::And(vec![DependencyType::AfterOk("1".to_string()), DependencyType::AfterOk("2".to_string())]))]
+#[case("afterok:${IDS:-1?2},after:3", Dependency::And(vec![DependencyType::AfterOk("${IDS:-1?2}".to_string()), DependencyType::After("3".to_string())]))]
+fn test_from_str_separator(#[case] input: &str, #[case] expected: Dependency) {
+    assert_eq!(input.parse::<Dependency>().unwrap(), expected);
+}
+
+#[rstest]
+#[case("afterok:1,afterok:2?afterok:3")]
+#[case("after:1,after:2?after:3")]
+#[case("\"after:1?after:2,after:3\"")]
+fn test_from_str_mixed_separators(#[case] input: &str) {
+    assert!(matches!(
+        input.parse::<Dependency>(),
+        Err(DependencyError::MixedSeparators(_))
+    ));
+}
+
+#[test]
+fn test_from_str_empty() {
+    assert!(matches!(
+        "".parse::<Dependency>(),
+        Err(DependencyError::NoDependencies)
+    ));
+}
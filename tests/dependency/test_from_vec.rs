@@ -0,0 +1,52 @@
+use rstest::rstest;
+use sbatch_rs::{Dependency, DependencyError, DependencyType};
+
+fn dependencies() -> Vec<DependencyType> {
+    vec![
+        DependencyType::AfterOk("1".to_string()),
+        DependencyType::AfterTimeDelay("2".to_string(), "10".to_string()),
+    ]
+}
+
+#[test]
+fn test_and() {
+    let dependency = Dependency::and(dependencies()).unwrap();
+    assert_eq!(dependency, Dependency::And(dependencies()));
+    assert_eq!(dependency.build().unwrap(), "after:2+10,afterok:1");
+}
+
+#[test]
+fn test_or() {
+    let dependency = Dependency::or(dependencies()).unwrap();
+    assert_eq!(dependency, Dependency::Or(dependencies()));
+    assert_eq!(dependency.build().unwrap(), "after:2+10?afterok:1");
+}
+
+#[rstest]
+#[case(Dependency::and(Vec::new()))]
+#[case(Dependency::or(Vec::new()))]
+fn test_empty(#[case] dependency: Result<Dependency, DependencyError>) {
+    let dependency = dependency.unwrap();
+    assert!(dependency.is_empty());
+    assert!(matches!(
+        dependency.build(),
+        Err(DependencyError::NoDependencies)
+    ));
+}
+
+#[rstest]
+#[case(DependencyType::After("".to_string()))]
+#[case(DependencyType::AfterOk(" 1".to_string()))]
+#[case(DependencyType::AfterTimeDelay("1".to_string(), "".to_string()))]
+fn test_invalid(#[case] invalid: DependencyType) {
+    let mut list = dependencies();
+    list.push(invalid);
+    assert!(matches!(
+        Dependency::and(list.clone()),
+        Err(DependencyError::DependencyTypeError(_))
+    ));
+    assert!(matches!(
+        Dependency::or(list),
+        Err(DependencyError::DependencyTypeError(_))
+    ));
+}
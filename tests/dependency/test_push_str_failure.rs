@@ -1,3 +1,4 @@
+use rstest::rstest;
 use sbatch_rs::Dependency;
 
 #[test]
@@ -48,3 +49,17 @@ fn test_push_after_ok_error() {
     let dependency = dependency.push_after_ok("123 ");
     assert!(dependency.is_err());
 }
+
+#[rstest]
+#[case("")]
+#[case("afterfoo:123")]
+#[case("afterok:1:2")]
+#[case("afterok:123 ")]
+#[case("afterok:")]
+#[case("after:123+")]
+#[case("afterok:123,afterok:456")]
+fn test_push_str_error(#[case] input: &str) {
+    let mut dependency = Dependency::new_and();
+    assert!(dependency.push_str(input).is_err());
+    assert!(dependency.is_empty());
+}
@@ -1,3 +1,4 @@
+use rstest::rstest;
 use sbatch_rs::Dependency;
 
 #[test]
@@ -79,3 +80,35 @@ fn test_push_singleton() {
         .unwrap();
     assert_eq!(dependency, "singleton");
 }
+
+#[rstest]
+#[case("after:123", "after:123")]
+#[case("123", "after:123")]
+#[case("after:123+10", "after:123+10")]
+#[case("afterany:123", "afterany:123")]
+#[case("afterburstbuffer:123", "afterburstbuffer:123")]
+#[case("aftercorr:123", "aftercorr:123")]
+#[case("afternotok:123", "afternotok:123")]
+#[case("afterok:123", "afterok:123")]
+#[case("afterok:$JOB_ID", "afterok:$JOB_ID")]
+#[case("singleton", "singleton")]
+fn test_push_str(#[case] input: &str, #[case] expected: &str) {
+    let dependency = Dependency::new_and()
+        .push_str(input)
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(dependency, expected);
+}
+
+#[test]
+fn test_push_str_accumulates() {
+    let dependency = Dependency::new_or()
+        .push_str("afterok:1")
+        .unwrap()
+        .push_str("afternotok:2")
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(dependency, "afternotok:2?afterok:1");
+}
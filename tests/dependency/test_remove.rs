@@ -0,0 +1,54 @@
+use sbatch_rs::{Dependency, DependencyType};
+
+#[test]
+fn test_remove() {
+    let mut dependency = Dependency::new_and();
+    dependency
+        .push_after_ok("1")
+        .unwrap()
+        .push_after_ok("2")
+        .unwrap()
+        .push_after_any("3")
+        .unwrap();
+    assert_eq!(dependency.len(), 3);
+
+    assert!(dependency.remove(&DependencyType::AfterOk("2".to_string())));
+    assert_eq!(dependency.len(), 2);
+    assert_eq!(
+        dependency.iter().collect::<Vec<_>>(),
+        vec![
+            &DependencyType::AfterOk("1".to_string()),
+            &DependencyType::AfterAny("3".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_remove_not_present() {
+    let mut dependency = Dependency::new_or();
+    dependency.push_after_ok("1").unwrap();
+
+    assert!(!dependency.remove(&DependencyType::AfterOk("2".to_string())));
+    assert!(!dependency.remove(&DependencyType::AfterAny("1".to_string())));
+    assert_eq!(dependency.len(), 1);
+}
+
+#[test]
+fn test_remove_duplicates() {
+    let mut dependency = Dependency::new_and();
+    dependency.push_after("1").unwrap().push_after("1").unwrap();
+    assert_eq!(dependency.len(), 2);
+
+    assert!(dependency.remove(&DependencyType::After("1".to_string())));
+    assert!(dependency.is_empty());
+}
+
+#[test]
+fn test_is_empty() {
+    let mut dependency = Dependency::new_and();
+    assert!(dependency.is_empty());
+    assert_eq!(dependency.iter().count(), 0);
+
+    dependency.push_singleton().unwrap();
+    assert!(!dependency.is_empty());
+}
@@ -0,0 +1,36 @@
+use rstest::rstest;
+use sbatch_rs::{Dependency, DependencyError, DependencyType};
+
+#[rstest]
+#[case(Dependency::And(vec![DependencyType::AfterOk("3".to_string()), DependencyType::After("1".to_string()), DependencyType::AfterAny("2".to_string())]), "afterok:3,after:1,afterany:2")]
+#[case(Dependency::Or(vec![DependencyType::AfterOk("3".to_string()), DependencyType::After("1".to_string())]), "afterok:3?after:1")]
+#[case(Dependency::And(vec![DependencyType::After("2".to_string()), DependencyType::After("1".to_string()), DependencyType::After("2".to_string())]), "after:2,after:1")]
+fn test_to_ordered_string(#[case] dependency: Dependency, #[case] expected: &str) {
+    assert_eq!(dependency.to_ordered_string().unwrap(), expected);
+}
+
+#[test]
+fn test_to_ordered_string_differs_from_build() {
+    let mut dependency = Dependency::new_and();
+    dependency
+        .push_singleton()
+        .unwrap()
+        .push_after_ok("2")
+        .unwrap()
+        .push_after("1")
+        .unwrap();
+
+    assert_eq!(dependency.build().unwrap(), "after:1,afterok:2,singleton");
+    assert_eq!(
+        dependency.to_ordered_string().unwrap(),
+        "singleton,afterok:2,after:1"
+    );
+}
+
+#[test]
+fn test_to_ordered_string_empty() {
+    assert!(matches!(
+        Dependency::new_and().to_ordered_string(),
+        Err(DependencyError::NoDependencies)
+    ));
+}
@@ -1,2 +1,21 @@
+mod test_array_spec;
+mod test_begin_time;
+mod test_canonical_name;
+mod test_constraint;
+mod test_cpu_freq;
 mod test_display;
+mod test_distribution;
+mod test_export;
+mod test_filename;
+mod test_from_str;
+mod test_from_str_with;
+mod test_gpu_spec;
+mod test_gres;
+mod test_mail_type;
+mod test_memory;
+mod test_node_list;
+mod test_open_mode;
+mod test_parse_located;
+mod test_signal_spec;
+mod test_time_limit;
 mod test_validate;
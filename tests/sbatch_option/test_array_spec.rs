@@ -0,0 +1,88 @@
+use rstest::rstest;
+use sbatch_rs::{ArraySpec, SbatchOption, SbatchOptionError};
+
+#[rstest]
+#[case("3", vec![3], None, "3")]
+#[case("1-5", vec![1, 2, 3, 4, 5], None, "1-5")]
+#[case("1-7:2", vec![1, 3, 5, 7], None, "1-7:2")]
+#[case("1-8:3", vec![1, 4, 7], None, "1-8:3")]
+#[case("1,3,5", vec![1, 3, 5], None, "1,3,5")]
+#[case("0-3%2", vec![0, 1, 2, 3], Some(2), "0-3%2")]
+#[case("5-5", vec![5], None, "5")]
+#[case("1-3:1", vec![1, 2, 3], None, "1-3")]
+#[case("0,10-12,20-24:2%4", vec![0, 10, 11, 12, 20, 22, 24], Some(4), "0,10-12,20-24:2%4")]
+fn test_array_spec_from_str(
+    #[case] input: &str,
+    #[case] indices: Vec<u32>,
+    #[case] max_concurrent: Option<u32>,
+    #[case] display: &str,
+) {
+    let spec: ArraySpec = input.parse().unwrap();
+    assert_eq!(spec.indices().unwrap(), indices);
+    assert_eq!(spec.max_concurrent().map(|n| n.get()), max_concurrent);
+    assert_eq!(spec.to_string(), display);
+}
+
+#[rstest]
+#[case("")]
+#[case("1-")]
+#[case("-5")]
+#[case("5-1")]
+#[case("1-7:0")]
+#[case("1-7:")]
+#[case("3:2")]
+#[case("1,,3")]
+#[case("1,")]
+#[case("0-15%")]
+#[case("0-15%0")]
+#[case("0-15%-1")]
+#[case("%4")]
+#[case("a-b")]
+#[case(" 1-5")]
+fn test_array_spec_from_str_error(#[case] input: &str) {
+    assert!(matches!(
+        input.parse::<ArraySpec>(),
+        Err(SbatchOptionError::InvalidArraySpec(_))
+    ));
+}
+
+#[test]
+fn test_array_spec_index_limit() {
+    let spec: ArraySpec = "0-4294967295".parse().unwrap();
+    assert!(matches!(
+        spec.indices(),
+        Err(SbatchOptionError::ArrayTooLarge(
+            ArraySpec::DEFAULT_INDEX_LIMIT
+        ))
+    ));
+
+    let spec: ArraySpec = "0-9,20-29".parse().unwrap();
+    assert_eq!(spec.indices_with_limit(20).unwrap().len(), 20);
+    assert!(spec.indices_with_limit(19).is_err());
+}
+
+#[rstest]
+#[case("7", 7)]
+#[case("0-1000", 1000)]
+#[case("1-8:3", 7)]
+#[case("50,1-10", 50)]
+#[case("0-4294967295%4", 4294967295)]
+fn test_array_spec_max_index(#[case] input: &str, #[case] expected: u32) {
+    assert_eq!(input.parse::<ArraySpec>().unwrap().max_index(), expected);
+}
+
+#[test]
+fn test_array_option_from_str() {
+    assert_eq!(
+        "--array=0-15%4".parse::<SbatchOption>().unwrap(),
+        SbatchOption::Array("0-15%4".parse().unwrap())
+    );
+    assert_eq!(
+        "-a 1,2,3".parse::<SbatchOption>().unwrap().to_string(),
+        "--array=1,2,3"
+    );
+    assert!(matches!(
+        "--array=1-".parse::<SbatchOption>(),
+        Err(SbatchOptionError::InvalidArraySpec(_))
+    ));
+}
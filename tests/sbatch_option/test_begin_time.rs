@@ -0,0 +1,119 @@
+use rstest::rstest;
+use sbatch_rs::{BeginTime, BeginUnit, SbatchOption, SbatchOptionError};
+
+#[rstest]
+#[case("now", "now")]
+#[case("NOW", "now")]
+#[case("now+60", "now+60seconds")]
+#[case("now+1hour", "now+1hours")]
+#[case("now+30min", "now+30minutes")]
+#[case("now+2Days", "now+2days")]
+#[case("now+1week", "now+1weeks")]
+#[case("midnight", "midnight")]
+#[case("Noon", "noon")]
+#[case("teatime", "teatime")]
+#[case("today", "today")]
+#[case("Tomorrow", "tomorrow")]
+#[case("elevenses", "elevenses")]
+#[case("FIKA", "fika")]
+#[case("16:00", "16:00")]
+#[case("09:30:15", "09:30:15")]
+#[case("04:00 PM", "04:00PM")]
+#[case("4:00 PM", "04:00PM")]
+#[case("9:30:15am", "09:30:15AM")]
+#[case("12:30:15am", "12:30:15AM")]
+#[case("012025", "012025")]
+#[case("0120", "0120")]
+#[case("01/20", "0120")]
+#[case("02/29", "0229")]
+#[case("01/20/25", "012025")]
+#[case("01.20.25", "012025")]
+#[case("2025-01-20", "2025-01-20")]
+#[case("2025-01-20T12:34", "2025-01-20T12:34")]
+#[case("2024-02-29T23:59:59", "2024-02-29T23:59:59")]
+fn test_begin_time_from_str(#[case] input: &str, #[case] expected: &str) {
+    let begin: BeginTime = input.parse().unwrap();
+    assert_eq!(begin.to_string(), expected);
+    assert_eq!(expected.parse::<BeginTime>().unwrap(), begin);
+}
+
+#[rstest]
+#[case("now+10min", "now+10minutes", "now+10m", "now+10minutes")]
+#[case("now+2hour", "now+2hours", "now+2h", "now+2hours")]
+#[case("now+3day", "now+3days", "now+3d", "now+3days")]
+fn test_begin_time_unit_spellings_are_equal(
+    #[case] short: &str,
+    #[case] long: &str,
+    #[case] letter: &str,
+    #[case] expected: &str,
+) {
+    let short: BeginTime = short.parse().unwrap();
+    let long: BeginTime = long.parse().unwrap();
+    let letter: BeginTime = letter.parse().unwrap();
+    assert_eq!(short, long);
+    assert_eq!(long, letter);
+    assert_eq!(short.to_string(), expected);
+    assert_eq!(long.to_string(), expected);
+    assert_eq!(letter.to_string(), expected);
+}
+
+#[rstest]
+#[case("")]
+#[case("now+")]
+#[case("now+hours")]
+#[case("now+1fortnight")]
+#[case("now-1hour")]
+#[case("today+1")]
+#[case("13:00 PM")]
+#[case("00:30 AM")]
+#[case("0:30 AM")]
+#[case("4:00")]
+#[case(":00 PM")]
+#[case("12:00 XM")]
+#[case("24:00")]
+#[case("12:60")]
+#[case("1:00")]
+#[case("12:00:00:00")]
+#[case("2025-13-01")]
+#[case("2025-02-29")]
+#[case("2025-1-20")]
+#[case("2025-01-20T25:00")]
+#[case("2025-01-20 12:00")]
+#[case("133125")]
+#[case("12312")]
+#[case("02/29/25")]
+#[case("01/20/2025")]
+#[case("1/20")]
+#[case("01/20/")]
+#[case("01/20.25")]
+#[case("ééé")]
+fn test_begin_time_from_str_error(#[case] input: &str) {
+    assert!(matches!(
+        input.parse::<BeginTime>(),
+        Err(SbatchOptionError::InvalidBeginTime(_))
+    ));
+}
+
+#[test]
+fn test_begin_time_relative() {
+    assert!(BeginTime::now().is_relative());
+    assert!(BeginTime::now_plus(5, BeginUnit::Minutes).is_relative());
+    assert!(!"noon".parse::<BeginTime>().unwrap().is_relative());
+    assert!(!"2025-01-20".parse::<BeginTime>().unwrap().is_relative());
+}
+
+#[test]
+fn test_begin_option_from_str() {
+    assert_eq!(
+        "--begin=now+1hour".parse::<SbatchOption>().unwrap(),
+        SbatchOption::Begin(BeginTime::now_plus(1, BeginUnit::Hours))
+    );
+    assert_eq!(
+        "-b 16:00".parse::<SbatchOption>().unwrap().to_string(),
+        "--begin=16:00"
+    );
+    assert!(matches!(
+        "--begin=now+".parse::<SbatchOption>(),
+        Err(SbatchOptionError::InvalidBeginTime(_))
+    ));
+}
@@ -0,0 +1,272 @@
+use rstest::rstest;
+use sbatch_rs::{OpenMode, SbatchOption, SbatchOptionError};
+use std::num::NonZeroU32;
+
+#[rstest]
+#[case(SbatchOption::Account("test".to_string()), "account", Some('A'))]
+#[case(SbatchOption::AcctgFreq("test".to_string()), "acctg-freq", None)]
+#[case(SbatchOption::Array("0-15%4".parse().unwrap()), "array", Some('a'))]
+#[case(SbatchOption::Batch("test".to_string()), "batch", None)]
+#[case(SbatchOption::Bb("test".to_string()), "bb", None)]
+#[case(SbatchOption::Bbf("test".to_string()), "bbf", None)]
+#[case(SbatchOption::Begin("now+1hour".parse().unwrap()), "begin", Some('b'))]
+#[case(SbatchOption::Chdir("test".to_string()), "chdir", Some('D'))]
+#[case(SbatchOption::ClusterConstraint("test".to_string()), "cluster-constraint", None)]
+#[case(SbatchOption::Clusters("test".to_string()), "clusters", Some('M'))]
+#[case(SbatchOption::Comment("test".to_string()), "comment", None)]
+#[case(SbatchOption::Constraint("test".parse().unwrap()), "constraint", Some('C'))]
+#[case(SbatchOption::Container("test".to_string()), "container", None)]
+#[case(SbatchOption::ContainerID("test".to_string()), "container-id", None)]
+#[case(SbatchOption::Contiguous, "contiguous", None)]
+#[case(SbatchOption::CoreSpec("4".parse().unwrap()), "core-spec", Some('S'))]
+#[case(SbatchOption::CoresPerSocket(NonZeroU32::new(8).unwrap()), "cores-per-socket", None)]
+#[case(SbatchOption::CPUFreq("Performance".parse().unwrap()), "cpu-freq", None)]
+#[case(SbatchOption::CPUsPerGPU("test".to_string()), "cpus-per-gpu", None)]
+#[case(SbatchOption::CPUsPerTask("test".to_string()), "cpus-per-task", Some('c'))]
+#[case(SbatchOption::Deadline("test".to_string()), "deadline", None)]
+#[case(SbatchOption::DelayBoot("test".to_string()), "delay-boot", None)]
+#[case(SbatchOption::Dependency("test".to_string()), "dependency", Some('d'))]
+#[case(SbatchOption::Distribution("block".parse().unwrap()), "distribution", Some('m'))]
+#[case(SbatchOption::Error("test".to_string()), "error", Some('e'))]
+#[case(SbatchOption::Exclude("test".parse().unwrap()), "exclude", Some('x'))]
+#[case(SbatchOption::Exclusive(Some("user".to_string())), "exclusive", None)]
+#[case(SbatchOption::Export("test".parse().unwrap()), "export", None)]
+#[case(SbatchOption::ExportFile("test".to_string()), "export-file", None)]
+#[case(SbatchOption::Extra("test".to_string()), "extra", None)]
+#[case(SbatchOption::ExtraNodeInfo("test".to_string()), "extra-node-info", Some('B'))]
+#[case(SbatchOption::GetUserEnv(Some("test".to_string())), "get-user-env", None)]
+#[case(SbatchOption::GID("test".to_string()), "gid", None)]
+#[case(SbatchOption::GPUBind("test".to_string()), "gpu-bind", None)]
+#[case(SbatchOption::GPUFreq("test".to_string()), "gpu-freq", None)]
+#[case(SbatchOption::GPUs("2".parse().unwrap()), "gpus", Some('G'))]
+#[case(SbatchOption::GPUsPerNode("2".parse().unwrap()), "gpus-per-node", None)]
+#[case(SbatchOption::GPUsPerSocket("2".parse().unwrap()), "gpus-per-socket", None)]
+#[case(SbatchOption::GPUsPerTask("2".parse().unwrap()), "gpus-per-task", None)]
+#[case(SbatchOption::Gres("gpu".parse().unwrap()), "gres", None)]
+#[case(SbatchOption::GresFlags("test".to_string()), "gres-flags", None)]
+#[case(SbatchOption::Help, "help", Some('h'))]
+#[case(SbatchOption::Hint("test".to_string()), "hint", None)]
+#[case(SbatchOption::Hold, "hold", Some('H'))]
+#[case(SbatchOption::IgnorePbs, "ignore-pbs", None)]
+#[case(SbatchOption::Input("test".to_string()), "input", Some('i'))]
+#[case(SbatchOption::JobName("test".to_string()), "job-name", Some('J'))]
+#[case(SbatchOption::KillOnInvalidDep("test".to_string()), "kill-on-invalid-dep", None)]
+#[case(SbatchOption::Licenses("test".to_string()), "licenses", Some('L'))]
+#[case(SbatchOption::MailType("test".to_string()), "mail-type", None)]
+#[case(SbatchOption::MailUser("test".to_string()), "mail-user", None)]
+#[case(SbatchOption::McsLabel("test".to_string()), "mcs-label", None)]
+#[case(SbatchOption::Mem("4G".parse().unwrap()), "mem", None)]
+#[case(SbatchOption::MemBind("test".to_string()), "mem-bind", None)]
+#[case(SbatchOption::MemPerCPU("512m".parse().unwrap()), "mem-per-cpu", None)]
+#[case(SbatchOption::MemPerGPU("1024".parse().unwrap()), "mem-per-gpu", None)]
+#[case(SbatchOption::MinCPUs("test".to_string()), "min-cpus", None)]
+#[case(SbatchOption::Network("test".to_string()), "network", None)]
+#[case(SbatchOption::Nice(Some(-100)), "nice", None)]
+#[case(SbatchOption::NoKill(Some("test".to_string())), "no-kill", Some('k'))]
+#[case(SbatchOption::NoRequeue, "no-requeue", None)]
+#[case(SbatchOption::NodeFile("test".to_string()), "nodefile", Some('F'))]
+#[case(SbatchOption::NodeList("test".parse().unwrap()), "nodelist", Some('w'))]
+#[case(SbatchOption::Nodes("test".to_string()), "nodes", Some('N'))]
+#[case(SbatchOption::NTasks("4".parse().unwrap()), "ntasks", Some('n'))]
+#[case(SbatchOption::NTasksPerCore("test".to_string()), "ntasks-per-core", None)]
+#[case(SbatchOption::NTasksPerGPU("test".to_string()), "ntasks-per-gpu", None)]
+#[case(SbatchOption::NTasksPerNode("4".parse().unwrap()), "ntasks-per-node", None)]
+#[case(SbatchOption::NTasksPerSocket("test".to_string()), "ntasks-per-socket", None)]
+#[case(SbatchOption::OOMKillStep(Some("test".to_string())), "oom-kill-step", None)]
+#[case(SbatchOption::OpenMode(OpenMode::Append), "open-mode", None)]
+#[case(SbatchOption::Output("test".to_string()), "output", Some('o'))]
+#[case(SbatchOption::Overcommit, "overcommit", Some('O'))]
+#[case(SbatchOption::Oversubscribe, "oversubscribe", Some('s'))]
+#[case(SbatchOption::Parsable, "parsable", None)]
+#[case(SbatchOption::Partition("test".to_string()), "partition", Some('p'))]
+#[case(SbatchOption::Prefer("test".to_string()), "prefer", None)]
+#[case(SbatchOption::Priority("test".to_string()), "priority", None)]
+#[case(SbatchOption::Profile("test".to_string()), "profile", None)]
+#[case(SbatchOption::Propagate(Some("test".to_string())), "propagate", None)]
+#[case(SbatchOption::Qos("test".to_string()), "qos", Some('q'))]
+#[case(SbatchOption::Quiet, "quiet", Some('Q'))]
+#[case(SbatchOption::Reboot, "reboot", None)]
+#[case(SbatchOption::Requeue, "requeue", None)]
+#[case(SbatchOption::Reservation("test".to_string()), "reservation", None)]
+#[case(SbatchOption::ResvPorts(Some("test".to_string())), "resv-ports", None)]
+#[case(SbatchOption::Segment("test".to_string()), "segment", None)]
+#[case(SbatchOption::Signal("b:sigusr1@60".parse().unwrap()), "signal", None)]
+#[case(SbatchOption::SocketsPerNode(NonZeroU32::new(2).unwrap()), "sockets-per-node", None)]
+#[case(SbatchOption::SpreadJob, "spread-job", None)]
+#[case(SbatchOption::Stepmgr, "stepmgr", None)]
+#[case(SbatchOption::Switches("test".to_string()), "switches", None)]
+#[case(SbatchOption::TestOnly, "test-only", None)]
+#[case(SbatchOption::ThreadSpec("test".to_string()), "thread-spec", None)]
+#[case(SbatchOption::ThreadsPerCore("test".to_string()), "threads-per-core", None)]
+#[case(SbatchOption::Time("1:30:00".parse().unwrap()), "time", Some('t'))]
+#[case(SbatchOption::TimeMin("UNLIMITED".parse().unwrap()), "time-min", None)]
+#[case(SbatchOption::Tmp("test".to_string()), "tmp", None)]
+#[case(SbatchOption::TresBind("test".to_string()), "tres-bind", None)]
+#[case(SbatchOption::TresPerTask("test".to_string()), "tres-per-task", None)]
+#[case(SbatchOption::UID("test".to_string()), "uid", None)]
+#[case(SbatchOption::Usage, "usage", None)]
+#[case(SbatchOption::UseMinNodes, "use-min-nodes", None)]
+#[case(SbatchOption::Verbose, "verbose", Some('v'))]
+#[case(SbatchOption::Version, "version", Some('V'))]
+#[case(SbatchOption::Wait, "wait", Some('W'))]
+#[case(SbatchOption::WaitAllNodes("1".to_string()), "wait-all-nodes", None)]
+#[case(SbatchOption::WCKey("test".to_string()), "wckey", None)]
+#[case(SbatchOption::Wrap(r#"echo "a""#.to_string()), "wrap", None)]
+#[case(SbatchOption::Raw("--newfangled-flag=x".to_string()), "", None)]
+fn test_canonical_name(
+    #[case] option: SbatchOption,
+    #[case] name: &str,
+    #[case] short: Option<char>,
+) {
+    assert_eq!(option.canonical_name(), name);
+    assert_eq!(option.short_flag(), short);
+    if !name.is_empty() {
+        assert!(SbatchOption::known_long_flags().contains(&name));
+    }
+    if let Some(short) = short {
+        assert!(SbatchOption::known_short_flags().contains(&short));
+    }
+}
+
+#[rstest]
+#[case(SbatchOption::Account("test".to_string()))]
+#[case(SbatchOption::AcctgFreq("test".to_string()))]
+#[case(SbatchOption::Array("0-15%4".parse().unwrap()))]
+#[case(SbatchOption::Batch("test".to_string()))]
+#[case(SbatchOption::Bb("test".to_string()))]
+#[case(SbatchOption::Bbf("test".to_string()))]
+#[case(SbatchOption::Begin("now+1hour".parse().unwrap()))]
+#[case(SbatchOption::Chdir("test".to_string()))]
+#[case(SbatchOption::ClusterConstraint("test".to_string()))]
+#[case(SbatchOption::Clusters("test".to_string()))]
+#[case(SbatchOption::Comment("test".to_string()))]
+#[case(SbatchOption::Constraint("test".parse().unwrap()))]
+#[case(SbatchOption::Container("test".to_string()))]
+#[case(SbatchOption::ContainerID("test".to_string()))]
+#[case(SbatchOption::Contiguous)]
+#[case(SbatchOption::CoreSpec("4".parse().unwrap()))]
+#[case(SbatchOption::CoresPerSocket(NonZeroU32::new(8).unwrap()))]
+#[case(SbatchOption::CPUFreq("Performance".parse().unwrap()))]
+#[case(SbatchOption::CPUsPerGPU("test".to_string()))]
+#[case(SbatchOption::CPUsPerTask("test".to_string()))]
+#[case(SbatchOption::Deadline("test".to_string()))]
+#[case(SbatchOption::DelayBoot("test".to_string()))]
+#[case(SbatchOption::Dependency("test".to_string()))]
+#[case(SbatchOption::Distribution("block".parse().unwrap()))]
+#[case(SbatchOption::Error("test".to_string()))]
+#[case(SbatchOption::Exclude("test".parse().unwrap()))]
+#[case(SbatchOption::Exclusive(Some("user".to_string())))]
+#[case(SbatchOption::Export("test".parse().unwrap()))]
+#[case(SbatchOption::ExportFile("test".to_string()))]
+#[case(SbatchOption::Extra("test".to_string()))]
+#[case(SbatchOption::ExtraNodeInfo("test".to_string()))]
+#[case(SbatchOption::GetUserEnv(Some("test".to_string())))]
+#[case(SbatchOption::GID("test".to_string()))]
+#[case(SbatchOption::GPUBind("test".to_string()))]
+#[case(SbatchOption::GPUFreq("test".to_string()))]
+#[case(SbatchOption::GPUs("2".parse().unwrap()))]
+#[case(SbatchOption::GPUsPerNode("2".parse().unwrap()))]
+#[case(SbatchOption::GPUsPerSocket("2".parse().unwrap()))]
+#[case(SbatchOption::GPUsPerTask("2".parse().unwrap()))]
+#[case(SbatchOption::Gres("gpu".parse().unwrap()))]
+#[case(SbatchOption::GresFlags("test".to_string()))]
+#[case(SbatchOption::Help)]
+#[case(SbatchOption::Hint("test".to_string()))]
+#[case(SbatchOption::Hold)]
+#[case(SbatchOption::IgnorePbs)]
+#[case(SbatchOption::Input("test".to_string()))]
+#[case(SbatchOption::JobName("test".to_string()))]
+#[case(SbatchOption::KillOnInvalidDep("test".to_string()))]
+#[case(SbatchOption::Licenses("test".to_string()))]
+#[case(SbatchOption::MailType("test".to_string()))]
+#[case(SbatchOption::MailUser("test".to_string()))]
+#[case(SbatchOption::McsLabel("test".to_string()))]
+#[case(SbatchOption::Mem("4G".parse().unwrap()))]
+#[case(SbatchOption::MemBind("test".to_string()))]
+#[case(SbatchOption::MemPerCPU("512m".parse().unwrap()))]
+#[case(SbatchOption::MemPerGPU("1024".parse().unwrap()))]
+#[case(SbatchOption::MinCPUs("test".to_string()))]
+#[case(SbatchOption::Network("test".to_string()))]
+#[case(SbatchOption::Nice(Some(-100)))]
+#[case(SbatchOption::NoKill(Some("test".to_string())))]
+#[case(SbatchOption::NoRequeue)]
+#[case(SbatchOption::NodeFile("test".to_string()))]
+#[case(SbatchOption::NodeList("test".parse().unwrap()))]
+#[case(SbatchOption::Nodes("test".to_string()))]
+#[case(SbatchOption::NTasks("4".parse().unwrap()))]
+#[case(SbatchOption::NTasksPerCore("test".to_string()))]
+#[case(SbatchOption::NTasksPerGPU("test".to_string()))]
+#[case(SbatchOption::NTasksPerNode("4".parse().unwrap()))]
+#[case(SbatchOption::NTasksPerSocket("test".to_string()))]
+#[case(SbatchOption::OOMKillStep(Some("test".to_string())))]
+#[case(SbatchOption::OpenMode(OpenMode::Append))]
+#[case(SbatchOption::Output("test".to_string()))]
+#[case(SbatchOption::Overcommit)]
+#[case(SbatchOption::Oversubscribe)]
+#[case(SbatchOption::Parsable)]
+#[case(SbatchOption::Partition("test".to_string()))]
+#[case(SbatchOption::Prefer("test".to_string()))]
+#[case(SbatchOption::Priority("test".to_string()))]
+#[case(SbatchOption::Profile("test".to_string()))]
+#[case(SbatchOption::Propagate(Some("test".to_string())))]
+#[case(SbatchOption::Qos("test".to_string()))]
+#[case(SbatchOption::Quiet)]
+#[case(SbatchOption::Reboot)]
+#[case(SbatchOption::Requeue)]
+#[case(SbatchOption::Reservation("test".to_string()))]
+#[case(SbatchOption::ResvPorts(Some("test".to_string())))]
+#[case(SbatchOption::Segment("test".to_string()))]
+#[case(SbatchOption::Signal("b:sigusr1@60".parse().unwrap()))]
+#[case(SbatchOption::SocketsPerNode(NonZeroU32::new(2).unwrap()))]
+#[case(SbatchOption::SpreadJob)]
+#[case(SbatchOption::Stepmgr)]
+#[case(SbatchOption::Switches("test".to_string()))]
+#[case(SbatchOption::TestOnly)]
+#[case(SbatchOption::ThreadSpec("test".to_string()))]
+#[case(SbatchOption::ThreadsPerCore("test".to_string()))]
+#[case(SbatchOption::Time("1:30:00".parse().unwrap()))]
+#[case(SbatchOption::TimeMin("UNLIMITED".parse().unwrap()))]
+#[case(SbatchOption::Tmp("test".to_string()))]
+#[case(SbatchOption::TresBind("test".to_string()))]
+#[case(SbatchOption::TresPerTask("test".to_string()))]
+#[case(SbatchOption::UID("test".to_string()))]
+#[case(SbatchOption::Usage)]
+#[case(SbatchOption::UseMinNodes)]
+#[case(SbatchOption::Verbose)]
+#[case(SbatchOption::Version)]
+#[case(SbatchOption::Wait)]
+#[case(SbatchOption::WaitAllNodes("1".to_string()))]
+#[case(SbatchOption::WCKey("test".to_string()))]
+#[case(SbatchOption::Wrap(r#"echo "a""#.to_string()))]
+fn test_canonical_name_matches_display(#[case] option: SbatchOption) {
+    let flag = format!("--{}", option.canonical_name());
+    let display = option.to_string();
+    assert!(display == flag || display.starts_with(&format!("{flag}=")));
+}
+
+// Helper function to parse a known flag with a placeholder value if it needs one
+fn parse_known_flag(key: &str) -> Result<SbatchOption, SbatchOptionError> {
+    match SbatchOption::requires_value(key) {
+        Some(true) => SbatchOption::from_key_value(key, Some("1")),
+        Some(false) => SbatchOption::from_key_value(key, None),
+        None => panic!("known flag {key} has no requires_value entry"),
+    }
+}
+
+#[test]
+fn test_known_long_flags_are_parsed() {
+    for flag in SbatchOption::known_long_flags() {
+        if let Err(SbatchOptionError::UnknownArgument(_)) = parse_known_flag(flag) {
+            panic!("known long flag {flag} is not parsed by from_key_value");
+        }
+    }
+}
+
+#[test]
+fn test_known_short_flags_are_parsed() {
+    for flag in SbatchOption::known_short_flags() {
+        if let Err(SbatchOptionError::UnknownArgument(_)) = parse_known_flag(&flag.to_string()) {
+            panic!("known short flag -{flag} is not parsed by from_key_value");
+        }
+    }
+}
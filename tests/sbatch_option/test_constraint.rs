@@ -0,0 +1,66 @@
+use rstest::rstest;
+use sbatch_rs::{Constraint, ConstraintExpr, SbatchOption, SbatchOptionError};
+
+#[rstest]
+#[case("intel", vec!["intel"])]
+#[case("intel&gpu", vec!["intel", "gpu"])]
+#[case("intel|amd", vec!["intel", "amd"])]
+#[case("[rack1|rack2|rack3]", vec!["rack1", "rack2", "rack3"])]
+#[case("[rack1*2&rack2*4]", vec!["rack1", "rack2"])]
+#[case("(intel|amd)&!gpu", vec!["intel", "amd", "gpu"])]
+#[case("knl&quad&cache|knl&snc4&flat", vec!["knl", "quad", "cache", "snc4", "flat"])]
+#[case("cpu_gen-2.5&mem=high", vec!["cpu_gen-2.5", "mem=high"])]
+fn test_constraint_from_str(#[case] input: &str, #[case] features: Vec<&str>) {
+    let constraint: Constraint = input.parse().unwrap();
+    assert_eq!(constraint.features(), features);
+    assert_eq!(constraint.to_string(), input);
+}
+
+#[rstest]
+#[case("")]
+#[case("a&&b")]
+#[case("a||b")]
+#[case("&a")]
+#[case("a|")]
+#[case("[a|b")]
+#[case("a|b]")]
+#[case("(a|b]")]
+#[case("[]")]
+#[case("a*")]
+#[case("a*0")]
+#[case("a b")]
+#[case("a,b")]
+#[case("!")]
+fn test_constraint_from_str_error(#[case] input: &str) {
+    assert!(matches!(
+        input.parse::<Constraint>(),
+        Err(SbatchOptionError::InvalidConstraint(_))
+    ));
+}
+
+#[test]
+fn test_constraint_expr() {
+    let constraint: Constraint = "a&b|!c*2".parse().unwrap();
+    let feature = |name: &str, count: Option<u32>| ConstraintExpr::Feature {
+        name: name.to_string(),
+        count: count.map(|count| count.try_into().unwrap()),
+    };
+    assert_eq!(
+        *constraint.expr(),
+        ConstraintExpr::Or(vec![
+            ConstraintExpr::And(vec![feature("a", None), feature("b", None)]),
+            ConstraintExpr::Not(Box::new(feature("c", Some(2)))),
+        ])
+    );
+}
+
+#[test]
+fn test_constraint_option() {
+    let option: SbatchOption = "--constraint=[rack1|rack2]&gpu".parse().unwrap();
+    assert_eq!(option.to_string(), "--constraint='[rack1|rack2]&gpu'");
+    assert_eq!(option.to_string().parse::<SbatchOption>().unwrap(), option);
+    assert!(matches!(
+        "-C a&&b".parse::<SbatchOption>(),
+        Err(SbatchOptionError::InvalidConstraint(_))
+    ));
+}
@@ -0,0 +1,91 @@
+use rstest::rstest;
+use sbatch_rs::{CpuFreq, CpuFrequency, CpuGovernor, SbatchOption, SbatchOptionError};
+use std::num::NonZeroU32;
+
+fn khz(value: u32) -> CpuFrequency {
+    CpuFrequency::KHz(NonZeroU32::new(value).unwrap())
+}
+
+#[rstest]
+#[case(
+    "Performance",
+    None,
+    None,
+    Some(CpuGovernor::Performance),
+    "Performance"
+)]
+#[case("ondemand", None, None, Some(CpuGovernor::OnDemand), "OnDemand")]
+#[case("1000000", Some(khz(1000000)), None, None, "1000000")]
+#[case("highm1", Some(CpuFrequency::HighM1), None, None, "highm1")]
+#[case(
+    "low-high:Conservative",
+    Some(CpuFrequency::Low),
+    Some(CpuFrequency::High),
+    Some(CpuGovernor::Conservative),
+    "low-high:Conservative"
+)]
+#[case(
+    "1000000-2000000",
+    Some(khz(1000000)),
+    Some(khz(2000000)),
+    None,
+    "1000000-2000000"
+)]
+#[case(
+    "Medium:SCHEDUTIL",
+    Some(CpuFrequency::Medium),
+    None,
+    Some(CpuGovernor::SchedUtil),
+    "medium:SchedUtil"
+)]
+fn test_cpu_freq_from_str(
+    #[case] input: &str,
+    #[case] frequency: Option<CpuFrequency>,
+    #[case] max_frequency: Option<CpuFrequency>,
+    #[case] governor: Option<CpuGovernor>,
+    #[case] display: &str,
+) {
+    let cpu_freq: CpuFreq = input.parse().unwrap();
+    assert_eq!(cpu_freq.frequency(), frequency);
+    assert_eq!(cpu_freq.max_frequency(), max_frequency);
+    assert_eq!(cpu_freq.governor(), governor);
+    assert_eq!(cpu_freq.to_string(), display);
+}
+
+#[rstest]
+#[case("")]
+#[case("turbo")]
+#[case("0")]
+#[case("-1")]
+#[case("high-low")]
+#[case("2000000-1000000")]
+#[case("low-")]
+#[case("low:")]
+#[case("low:turbo")]
+#[case("low-medium-high")]
+#[case("low:Performance:OnDemand")]
+#[case("Performance:OnDemand")]
+#[case("1.5GHz")]
+fn test_cpu_freq_from_str_error(#[case] input: &str) {
+    assert!(matches!(
+        input.parse::<CpuFreq>(),
+        Err(SbatchOptionError::InvalidCpuFreq(_))
+    ));
+}
+
+#[rstest]
+#[case("--cpu-freq=low-high:Conservative", "--cpu-freq=low-high:Conservative")]
+#[case("--cpu-freq performance", "--cpu-freq=Performance")]
+fn test_cpu_freq_option_from_str(#[case] input: &str, #[case] display: &str) {
+    let option: SbatchOption = input.parse().unwrap();
+    assert_eq!(option.to_string(), display);
+    assert_eq!(display.parse::<SbatchOption>().unwrap(), option);
+}
+
+#[test]
+fn test_cpu_freq_option_from_str_error() {
+    assert!(matches!(
+        "--cpu-freq=turbo".parse::<SbatchOption>(),
+        Err(SbatchOptionError::InvalidCpuFreq(_))
+    ));
+}
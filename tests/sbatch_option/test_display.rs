@@ -1,36 +1,37 @@
 use rstest::rstest;
-use sbatch_rs::SbatchOption;
+use sbatch_rs::{DisplayStyle, OpenMode, ParseOptions, SbatchOption, UnknownPolicy};
+use std::num::NonZeroU32;
 
 #[rstest]
 #[case(SbatchOption::Account("test".to_string()), "--account=test")]
 #[case(SbatchOption::AcctgFreq("test".to_string()), "--acctg-freq=test")]
-#[case(SbatchOption::Array("test".to_string()), "--array=test")]
+#[case(SbatchOption::Array("0-15%4".parse().unwrap()), "--array=0-15%4")]
 #[case(SbatchOption::Batch("test".to_string()), "--batch=test")]
 #[case(SbatchOption::Bb("test".to_string()), "--bb=test")]
 #[case(SbatchOption::Bbf("test".to_string()), "--bbf=test")]
-#[case(SbatchOption::Begin("test".to_string()), "--begin=test")]
+#[case(SbatchOption::Begin("now+1hour".parse().unwrap()), "--begin=now+1hours")]
 #[case(SbatchOption::Chdir("test".to_string()), "--chdir=test")]
 #[case(SbatchOption::ClusterConstraint("test".to_string()), "--cluster-constraint=test")]
 #[case(SbatchOption::Clusters("test".to_string()), "--clusters=test")]
 #[case(SbatchOption::Comment("test".to_string()), "--comment=test")]
-#[case(SbatchOption::Constraint("test".to_string()), "--constraint=test")]
+#[case(SbatchOption::Constraint("test".parse().unwrap()), "--constraint=test")]
 #[case(SbatchOption::Container("test".to_string()), "--container=test")]
 #[case(SbatchOption::ContainerID("test".to_string()), "--container-id=test")]
 #[case(SbatchOption::Contiguous, "--contiguous")]
-#[case(SbatchOption::CoreSpec("test".to_string()), "--core-spec=test")]
-#[case(SbatchOption::CoresPerSocket("test".to_string()), "--cores-per-socket=test")]
-#[case(SbatchOption::CPUFreq("test".to_string()), "--cpu-freq=test")]
+#[case(SbatchOption::CoreSpec("4".parse().unwrap()), "--core-spec=4")]
+#[case(SbatchOption::CoresPerSocket(NonZeroU32::new(8).unwrap()), "--cores-per-socket=8")]
+#[case(SbatchOption::CPUFreq("Performance".parse().unwrap()), "--cpu-freq=Performance")]
 #[case(SbatchOption::CPUsPerGPU("test".to_string()), "--cpus-per-gpu=test")]
 #[case(SbatchOption::CPUsPerTask("test".to_string()), "--cpus-per-task=test")]
 #[case(SbatchOption::Deadline("test".to_string()), "--deadline=test")]
 #[case(SbatchOption::DelayBoot("test".to_string()), "--delay-boot=test")]
 #[case(SbatchOption::Dependency("test".to_string()), "--dependency=test")]
-#[case(SbatchOption::Distribution("test".to_string()), "--distribution=test")]
+#[case(SbatchOption::Distribution("block".parse().unwrap()), "--distribution=block")]
 #[case(SbatchOption::Error("test".to_string()), "--error=test")]
-#[case(SbatchOption::Exclude("test".to_string()), "--exclude=test")]
-#[case(SbatchOption::Exclusive(Some("test".to_string())), "--exclusive=test")]
+#[case(SbatchOption::Exclude("test".parse().unwrap()), "--exclude=test")]
+#[case(SbatchOption::Exclusive(Some("user".to_string())), "--exclusive=user")]
 #[case(SbatchOption::Exclusive(None), "--exclusive")]
-#[case(SbatchOption::Export("test".to_string()), "--export=test")]
+#[case(SbatchOption::Export("test".parse().unwrap()), "--export=test")]
 #[case(SbatchOption::ExportFile("test".to_string()), "--export-file=test")]
 #[case(SbatchOption::Extra("test".to_string()), "--extra=test")]
 #[case(SbatchOption::ExtraNodeInfo("test".to_string()), "--extra-node-info=test")]
@@ -39,11 +40,11 @@ use sbatch_rs::SbatchOption;
 #[case(SbatchOption::GID("test".to_string()), "--gid=test")]
 #[case(SbatchOption::GPUBind("test".to_string()), "--gpu-bind=test")]
 #[case(SbatchOption::GPUFreq("test".to_string()), "--gpu-freq=test")]
-#[case(SbatchOption::GPUs("test".to_string()), "--gpus=test")]
-#[case(SbatchOption::GPUsPerNode("test".to_string()), "--gpus-per-node=test")]
-#[case(SbatchOption::GPUsPerSocket("test".to_string()), "--gpus-per-socket=test")]
-#[case(SbatchOption::GPUsPerTask("test".to_string()), "--gpus-per-task=test")]
-#[case(SbatchOption::Gres("test".to_string()), "--gres=test")]
+#[case(SbatchOption::GPUs("2".parse().unwrap()), "--gpus=2")]
+#[case(SbatchOption::GPUsPerNode("2".parse().unwrap()), "--gpus-per-node=2")]
+#[case(SbatchOption::GPUsPerSocket("2".parse().unwrap()), "--gpus-per-socket=2")]
+#[case(SbatchOption::GPUsPerTask("2".parse().unwrap()), "--gpus-per-task=2")]
+#[case(SbatchOption::Gres("gpu".parse().unwrap()), "--gres=gpu")]
 #[case(SbatchOption::GresFlags("test".to_string()), "--gres-flags=test")]
 #[case(SbatchOption::Help, "--help")]
 #[case(SbatchOption::Hint("test".to_string()), "--hint=test")]
@@ -53,31 +54,31 @@ use sbatch_rs::SbatchOption;
 #[case(SbatchOption::JobName("test".to_string()), "--job-name=test")]
 #[case(SbatchOption::KillOnInvalidDep("test".to_string()), "--kill-on-invalid-dep=test")]
 #[case(SbatchOption::Licenses("test".to_string()), "--licenses=test")]
-#[case(SbatchOption::MailType("test".to_string()), "--mail-type=test")]
+#[case(SbatchOption::MailType("END".to_string()), "--mail-type=END")]
 #[case(SbatchOption::MailUser("test".to_string()), "--mail-user=test")]
 #[case(SbatchOption::McsLabel("test".to_string()), "--mcs-label=test")]
-#[case(SbatchOption::Mem("test".to_string()), "--mem=test")]
+#[case(SbatchOption::Mem("4G".parse().unwrap()), "--mem=4G")]
 #[case(SbatchOption::MemBind("test".to_string()), "--mem-bind=test")]
-#[case(SbatchOption::MemPerCPU("test".to_string()), "--mem-per-cpu=test")]
-#[case(SbatchOption::MemPerGPU("test".to_string()), "--mem-per-gpu=test")]
+#[case(SbatchOption::MemPerCPU("512m".parse().unwrap()), "--mem-per-cpu=512M")]
+#[case(SbatchOption::MemPerGPU("1024".parse().unwrap()), "--mem-per-gpu=1024")]
 #[case(SbatchOption::MinCPUs("test".to_string()), "--min-cpus=test")]
 #[case(SbatchOption::Network("test".to_string()), "--network=test")]
-#[case(SbatchOption::Nice(Some("test".to_string())), "--nice=test")]
+#[case(SbatchOption::Nice(Some(-100)), "--nice=-100")]
 #[case(SbatchOption::Nice(None), "--nice")]
 #[case(SbatchOption::NoKill(Some("test".to_string())), "--no-kill=test")]
 #[case(SbatchOption::NoKill(None), "--no-kill")]
 #[case(SbatchOption::NoRequeue, "--no-requeue")]
 #[case(SbatchOption::NodeFile("test".to_string()), "--nodefile=test")]
-#[case(SbatchOption::NodeList("test".to_string()), "--nodelist=test")]
+#[case(SbatchOption::NodeList("test".parse().unwrap()), "--nodelist=test")]
 #[case(SbatchOption::Nodes("test".to_string()), "--nodes=test")]
-#[case(SbatchOption::NTasks("test".to_string()), "--ntasks=test")]
+#[case(SbatchOption::NTasks("4".parse().unwrap()), "--ntasks=4")]
 #[case(SbatchOption::NTasksPerCore("test".to_string()), "--ntasks-per-core=test")]
 #[case(SbatchOption::NTasksPerGPU("test".to_string()), "--ntasks-per-gpu=test")]
-#[case(SbatchOption::NTasksPerNode("test".to_string()), "--ntasks-per-node=test")]
+#[case(SbatchOption::NTasksPerNode("4".parse().unwrap()), "--ntasks-per-node=4")]
 #[case(SbatchOption::NTasksPerSocket("test".to_string()), "--ntasks-per-socket=test")]
 #[case(SbatchOption::OOMKillStep(Some("test".to_string())), "--oom-kill-step=test")]
 #[case(SbatchOption::OOMKillStep(None), "--oom-kill-step")]
-#[case(SbatchOption::OpenMode("test".to_string()), "--open-mode=test")]
+#[case(SbatchOption::OpenMode(OpenMode::Append), "--open-mode=append")]
 #[case(SbatchOption::Output("test".to_string()), "--output=test")]
 #[case(SbatchOption::Overcommit, "--overcommit")]
 #[case(SbatchOption::Oversubscribe, "--oversubscribe")]
@@ -90,22 +91,23 @@ use sbatch_rs::SbatchOption;
 #[case(SbatchOption::Propagate(None), "--propagate")]
 #[case(SbatchOption::Qos("test".to_string()), "--qos=test")]
 #[case(SbatchOption::Quiet, "--quiet")]
+#[case(SbatchOption::Raw("--newfangled-flag=x".to_string()), "--newfangled-flag=x")]
 #[case(SbatchOption::Reboot, "--reboot")]
 #[case(SbatchOption::Requeue, "--requeue")]
 #[case(SbatchOption::Reservation("test".to_string()), "--reservation=test")]
 #[case(SbatchOption::ResvPorts(Some("test".to_string())), "--resv-ports=test")]
 #[case(SbatchOption::ResvPorts(None), "--resv-ports")]
 #[case(SbatchOption::Segment("test".to_string()), "--segment=test")]
-#[case(SbatchOption::Signal("test".to_string()), "--signal=test")]
-#[case(SbatchOption::SocketsPerNode("test".to_string()), "--sockets-per-node=test")]
+#[case(SbatchOption::Signal("b:sigusr1@60".parse().unwrap()), "--signal=B:USR1@60")]
+#[case(SbatchOption::SocketsPerNode(NonZeroU32::new(2).unwrap()), "--sockets-per-node=2")]
 #[case(SbatchOption::SpreadJob, "--spread-job")]
 #[case(SbatchOption::Stepmgr, "--stepmgr")]
 #[case(SbatchOption::Switches("test".to_string()), "--switches=test")]
 #[case(SbatchOption::TestOnly, "--test-only")]
 #[case(SbatchOption::ThreadSpec("test".to_string()), "--thread-spec=test")]
 #[case(SbatchOption::ThreadsPerCore("test".to_string()), "--threads-per-core=test")]
-#[case(SbatchOption::Time("test".to_string()), "--time=test")]
-#[case(SbatchOption::TimeMin("test".to_string()), "--time-min=test")]
+#[case(SbatchOption::Time("1:30:00".parse().unwrap()), "--time=0-01:30:00")]
+#[case(SbatchOption::TimeMin("UNLIMITED".parse().unwrap()), "--time-min=UNLIMITED")]
 #[case(SbatchOption::Tmp("test".to_string()), "--tmp=test")]
 #[case(SbatchOption::TresBind("test".to_string()), "--tres-bind=test")]
 #[case(SbatchOption::TresPerTask("test".to_string()), "--tres-per-task=test")]
@@ -115,9 +117,61 @@ use sbatch_rs::SbatchOption;
 #[case(SbatchOption::Verbose, "--verbose")]
 #[case(SbatchOption::Version, "--version")]
 #[case(SbatchOption::Wait, "--wait")]
-#[case(SbatchOption::WaitAllNodes("test".to_string()), "--wait-all-nodes=test")]
+#[case(SbatchOption::WaitAllNodes("1".to_string()), "--wait-all-nodes=1")]
 #[case(SbatchOption::WCKey("test".to_string()), "--wckey=test")]
-#[case(SbatchOption::Wrap("test".to_string()), r#"--wrap="test""#)]
+#[case(SbatchOption::Wrap(r#"echo "a""#.to_string()), r#"--wrap='echo "a"'"#)]
+#[case(SbatchOption::Wrap("test".to_string()), "--wrap=test")]
 fn test_sbatch_option_to_string(#[case] option: SbatchOption, #[case] expected: &str) {
     assert_eq!(option.to_string(), expected);
+
+    // Every variant parses back from its display form; `Raw` needs unknown options to be allowed
+    let parse_options = ParseOptions {
+        on_unknown: UnknownPolicy::Passthrough,
+        ..Default::default()
+    };
+    assert_eq!(
+        SbatchOption::from_str_with(expected, &parse_options).unwrap(),
+        option
+    );
+}
+
+#[rstest]
+#[case(SbatchOption::Comment("hello world".to_string()), "--comment='hello world'")]
+#[case(SbatchOption::Comment("it's".to_string()), r"--comment='it'\''s'")]
+#[case(SbatchOption::Comment("say \"hi\"".to_string()), r#"--comment='say "hi"'"#)]
+#[case(SbatchOption::Partition("${PARTITION}".to_string()), "--partition='${PARTITION}'")]
+#[case(SbatchOption::Output("*.out".to_string()), "--output='*.out'")]
+#[case(SbatchOption::NodeList("node[1-4]".parse().unwrap()), "--nodelist='node[1-4]'")]
+#[case(SbatchOption::Output("logs/%x-%j.out".to_string()), "--output=logs/%x-%j.out")]
+#[case(SbatchOption::Exclusive(Some("a b".to_string())), "--exclusive='a b'")]
+#[case(SbatchOption::Raw("--foo=a b".to_string()), "--foo='a b'")]
+#[case(SbatchOption::Wrap("echo $SLURM_JOB_ID `hostname`".to_string()), "--wrap='echo $SLURM_JOB_ID `hostname`'")]
+#[case(SbatchOption::Wrap("echo it's".to_string()), r"--wrap='echo it'\''s'")]
+fn test_display_shell_quoting(#[case] option: SbatchOption, #[case] expected: &str) {
+    assert_eq!(option.to_string(), expected);
+    assert_eq!(option.display_with(DisplayStyle::Shell), expected);
+}
+
+#[rstest]
+#[case(SbatchOption::Comment("hello world".to_string()), "--comment=hello world")]
+#[case(SbatchOption::Partition("${PARTITION}".to_string()), "--partition=${PARTITION}")]
+#[case(SbatchOption::Wrap("echo \"hi\"".to_string()), "--wrap=echo \"hi\"")]
+#[case(SbatchOption::JobName("test".to_string()), "--job-name=test")]
+fn test_display_unquoted(#[case] option: SbatchOption, #[case] expected: &str) {
+    assert_eq!(option.display_with(DisplayStyle::Unquoted), expected);
+}
+
+#[rstest]
+#[case(SbatchOption::Comment("hello world".to_string()))]
+#[case(SbatchOption::Comment("it's a 'test'".to_string()))]
+#[case(SbatchOption::Comment("say \"hi\"".to_string()))]
+#[case(SbatchOption::Partition("${PARTITION}".to_string()))]
+#[case(SbatchOption::Output("*.out".to_string()))]
+#[case(SbatchOption::Wrap("echo \"a b\" $HOME".to_string()))]
+#[case(SbatchOption::Wrap("echo `hostname` it's".to_string()))]
+#[case(SbatchOption::Dependency("afterok:1?afterok:2".to_string()))]
+#[case(SbatchOption::Dependency("afterok:${STEP:-1}".to_string()))]
+#[case(SbatchOption::JobName("it's".to_string()))]
+fn test_display_round_trip(#[case] option: SbatchOption) {
+    assert_eq!(option.to_string().parse::<SbatchOption>().unwrap(), option);
 }
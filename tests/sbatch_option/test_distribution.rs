@@ -0,0 +1,108 @@
+use rstest::rstest;
+use sbatch_rs::{CpuDistribution, Distribution, NodeDistribution, SbatchOption, SbatchOptionError};
+use std::num::NonZeroU32;
+
+#[rstest]
+#[case("block", NodeDistribution::Block, None, None, None, "block")]
+#[case(
+    "block:cyclic",
+    NodeDistribution::Block,
+    Some(CpuDistribution::Cyclic),
+    None,
+    None,
+    "block:cyclic"
+)]
+#[case(
+    "cyclic:block:fcyclic",
+    NodeDistribution::Cyclic,
+    Some(CpuDistribution::Block),
+    Some(CpuDistribution::FCyclic),
+    None,
+    "cyclic:block:fcyclic"
+)]
+#[case("plane=4", NodeDistribution::Plane(NonZeroU32::new(4).unwrap()), None, None, None, "plane=4")]
+#[case(
+    "arbitrary",
+    NodeDistribution::Arbitrary,
+    None,
+    None,
+    None,
+    "arbitrary"
+)]
+#[case(
+    "*:*:fcyclic",
+    NodeDistribution::Default,
+    Some(CpuDistribution::Default),
+    Some(CpuDistribution::FCyclic),
+    None,
+    "*:*:fcyclic"
+)]
+#[case(
+    "BLOCK:Cyclic,pack",
+    NodeDistribution::Block,
+    Some(CpuDistribution::Cyclic),
+    None,
+    Some(true),
+    "block:cyclic,Pack"
+)]
+#[case(
+    "cyclic,NoPack",
+    NodeDistribution::Cyclic,
+    None,
+    None,
+    Some(false),
+    "cyclic,NoPack"
+)]
+fn test_distribution_from_str(
+    #[case] input: &str,
+    #[case] nodes: NodeDistribution,
+    #[case] sockets: Option<CpuDistribution>,
+    #[case] cores: Option<CpuDistribution>,
+    #[case] pack: Option<bool>,
+    #[case] display: &str,
+) {
+    let distribution: Distribution = input.parse().unwrap();
+    assert_eq!(distribution.nodes(), nodes);
+    assert_eq!(distribution.sockets(), sockets);
+    assert_eq!(distribution.cores(), cores);
+    assert_eq!(distribution.pack(), pack);
+    assert_eq!(distribution.to_string(), display);
+}
+
+#[rstest]
+#[case("")]
+#[case("spiral")]
+#[case("fcyclic")]
+#[case("block:arbitrary")]
+#[case("block:cyclic:cyclic:cyclic")]
+#[case("block:")]
+#[case("plane=")]
+#[case("plane=0")]
+#[case("plane=-1")]
+#[case("block,Packed")]
+#[case("block,")]
+#[case(" block")]
+fn test_distribution_from_str_error(#[case] input: &str) {
+    assert!(matches!(
+        input.parse::<Distribution>(),
+        Err(SbatchOptionError::InvalidDistribution(_))
+    ));
+}
+
+#[rstest]
+#[case("--distribution=block:cyclic", "--distribution=block:cyclic")]
+#[case("-m plane=4", "--distribution=plane=4")]
+#[case("--distribution=*:fcyclic", "--distribution='*:fcyclic'")]
+fn test_distribution_option_from_str(#[case] input: &str, #[case] display: &str) {
+    let option: SbatchOption = input.parse().unwrap();
+    assert_eq!(option.to_string(), display);
+    assert_eq!(display.parse::<SbatchOption>().unwrap(), option);
+}
+
+#[test]
+fn test_distribution_option_from_str_error() {
+    assert!(matches!(
+        "--distribution=spiral".parse::<SbatchOption>(),
+        Err(SbatchOptionError::InvalidDistribution(_))
+    ));
+}
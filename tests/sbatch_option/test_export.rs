@@ -0,0 +1,64 @@
+use rstest::rstest;
+use sbatch_rs::{Export, ExportMode, SbatchOption, SbatchOptionError};
+
+fn variables(pairs: &[(&str, Option<&str>)]) -> Vec<(String, Option<String>)> {
+    pairs
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.map(str::to_string)))
+        .collect()
+}
+
+#[rstest]
+#[case("ALL", ExportMode::All(vec![]), "ALL")]
+#[case("all", ExportMode::All(vec![]), "ALL")]
+#[case("NONE", ExportMode::None, "NONE")]
+#[case("None", ExportMode::None, "NONE")]
+#[case("ALL,FOO=bar", ExportMode::All(variables(&[("FOO", Some("bar"))])), "ALL,FOO=bar")]
+#[case("FOO,_BAR2=", ExportMode::Named(variables(&[("FOO", None), ("_BAR2", Some(""))])), "FOO,_BAR2=")]
+#[case("PATH=/opt/bin:$PATH", ExportMode::Named(variables(&[("PATH", Some("/opt/bin:$PATH"))])), "PATH=/opt/bin:$PATH")]
+#[case("A=x=y", ExportMode::Named(variables(&[("A", Some("x=y"))])), "A=x=y")]
+fn test_export_from_str(#[case] input: &str, #[case] mode: ExportMode, #[case] display: &str) {
+    let export: Export = input.parse().unwrap();
+    assert_eq!(export.mode(), &mode);
+    assert_eq!(export.to_string(), display);
+}
+
+#[test]
+fn test_export_variables() {
+    let export: Export = "ALL,FOO=bar,HOME".parse().unwrap();
+    assert_eq!(
+        export.variables(),
+        variables(&[("FOO", Some("bar")), ("HOME", None)])
+    );
+    assert!("ALL".parse::<Export>().unwrap().variables().is_empty());
+    assert!("NONE".parse::<Export>().unwrap().variables().is_empty());
+}
+
+#[rstest]
+#[case("")]
+#[case("ALL,")]
+#[case(",FOO")]
+#[case("=bar")]
+#[case("1FOO=bar")]
+#[case("FOO-BAR")]
+#[case("FOO BAR")]
+#[case("NONE,FOO")]
+#[case("FOO,ALL")]
+#[case("ALL,ALL")]
+#[case("ALL,NONE")]
+fn test_export_from_str_error(#[case] input: &str) {
+    assert!(matches!(
+        input.parse::<Export>(),
+        Err(SbatchOptionError::InvalidExport(_))
+    ));
+}
+
+#[rstest]
+#[case("--export=ALL,FOO=bar", "--export=ALL,FOO=bar")]
+#[case("--export=none", "--export=NONE")]
+#[case("--export=MSG=hello world", "--export='MSG=hello world'")]
+fn test_export_option_from_str(#[case] input: &str, #[case] display: &str) {
+    let option: SbatchOption = input.parse().unwrap();
+    assert_eq!(option.to_string(), display);
+    assert_eq!(display.parse::<SbatchOption>().unwrap(), option);
+}
@@ -0,0 +1,76 @@
+use rstest::rstest;
+use sbatch_rs::{FilenameContext, SbatchOption, SbatchOptionError};
+
+fn context() -> FilenameContext {
+    FilenameContext {
+        job_id: Some(1234),
+        job_name: Some("test".to_string()),
+        array_job_id: Some(1200),
+        array_task_id: Some(17),
+        step_id: Some(0),
+        node_name: Some("node01".to_string()),
+        node_id: Some(2),
+        task_id: Some(3),
+        user: Some("alice".to_string()),
+    }
+}
+
+#[rstest]
+#[case("slurm-%j.out")]
+#[case("slurm-%A_%a.out")]
+#[case("logs/%x-%u-%N.out")]
+#[case("%J.%s.%n.%t.%b")]
+#[case("task-%4a.out")]
+#[case("100%%.out")]
+#[case("plain.out")]
+#[case("literal\\%z.out")]
+fn test_filename_pattern_validate(#[case] pattern: &str) {
+    assert!(SbatchOption::Output(pattern.to_string()).validate().is_ok());
+    assert!(SbatchOption::Error(pattern.to_string()).validate().is_ok());
+}
+
+#[rstest]
+#[case("slurm-%z.out")]
+#[case("slurm-%q")]
+#[case("slurm-%")]
+#[case("slurm-%3")]
+#[case("slurm-%2%")]
+fn test_filename_pattern_validate_error(#[case] pattern: &str) {
+    assert!(matches!(
+        SbatchOption::Output(pattern.to_string()).validate(),
+        Err(SbatchOptionError::InvalidFilenamePattern(_))
+    ));
+    assert!(matches!(
+        format!("--error={}", pattern).parse::<SbatchOption>(),
+        Err(SbatchOptionError::InvalidFilenamePattern(_))
+    ));
+}
+
+#[rstest]
+#[case("slurm-%j.out", "slurm-1234.out")]
+#[case("slurm-%A_%a.out", "slurm-1200_17.out")]
+#[case("logs/%x-%u-%N.out", "logs/test-alice-node01.out")]
+#[case("%J.%s.%n.%t.%b", "1234.0.0.2.3.7")]
+#[case("task-%4a.out", "task-0017.out")]
+#[case("100%%.out", "100%.out")]
+#[case("literal\\%j.out", "literal\\%j.out")]
+fn test_render_filename(#[case] pattern: &str, #[case] expected: &str) {
+    let option = SbatchOption::Output(pattern.to_string());
+    assert_eq!(option.render_filename(&context()).unwrap(), expected);
+}
+
+#[test]
+fn test_render_filename_missing_values() {
+    let option = SbatchOption::Error("slurm-%x-%5a-%j.err".to_string());
+    let ctx = FilenameContext {
+        job_id: Some(7),
+        ..Default::default()
+    };
+    assert_eq!(option.render_filename(&ctx).unwrap(), "slurm-%x-%5a-7.err");
+}
+
+#[test]
+fn test_render_filename_other_option() {
+    let option = SbatchOption::JobName("%j".to_string());
+    assert_eq!(option.render_filename(&context()), None);
+}
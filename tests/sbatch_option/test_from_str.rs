@@ -0,0 +1,178 @@
+use rstest::rstest;
+use sbatch_rs::{SbatchOption, SbatchOptionError};
+use std::num::NonZeroU32;
+
+#[rstest]
+#[case("--account=test", SbatchOption::Account("test".to_string()))]
+#[case("--account test", SbatchOption::Account("test".to_string()))]
+#[case("-A test", SbatchOption::Account("test".to_string()))]
+#[case("  --job-name=test  ", SbatchOption::JobName("test".to_string()))]
+#[case("-J test", SbatchOption::JobName("test".to_string()))]
+#[case("--mem=4G", SbatchOption::Mem("4G".parse().unwrap()))]
+#[case("--contiguous", SbatchOption::Contiguous)]
+#[case("-H", SbatchOption::Hold)]
+#[case("--exclusive", SbatchOption::Exclusive(None))]
+#[case("--exclusive=user", SbatchOption::Exclusive(Some("user".to_string())))]
+#[case("--exclusive=mcs", SbatchOption::Exclusive(Some("mcs".to_string())))]
+#[case("--nice", SbatchOption::Nice(None))]
+#[case("--dependency=afterok:1:2", SbatchOption::Dependency("afterok:1:2".to_string()))]
+#[case("--wrap=\"echo hello\"", SbatchOption::Wrap("echo hello".to_string()))]
+#[case(r#"--wrap="echo \"a\" \\ b""#, SbatchOption::Wrap(r#"echo "a" \ b"#.to_string()))]
+#[case("--comment='hello world'", SbatchOption::Comment("hello world".to_string()))]
+#[case("-Jmyjob", SbatchOption::JobName("myjob".to_string()))]
+#[case("-J\"my job\"", SbatchOption::JobName("my job".to_string()))]
+#[case("-c4", SbatchOption::CPUsPerTask("4".to_string()))]
+#[case("-JJ test", SbatchOption::JobName("J test".to_string()))]
+#[case("--wait-all-nodes=0", SbatchOption::WaitAllNodes("0".to_string()))]
+#[case("--wait-all-nodes 1", SbatchOption::WaitAllNodes("1".to_string()))]
+fn test_from_str(#[case] input: &str, #[case] expected: SbatchOption) {
+    assert_eq!(input.parse::<SbatchOption>().unwrap(), expected);
+}
+
+#[rstest]
+#[case("--not-an-option=test")]
+#[case("--not-an-option")]
+#[case("--hold=test")]
+#[case("-Z test")]
+#[case("-HH")]
+#[case("-Hx")]
+#[case("job-name=test")]
+#[case("")]
+fn test_from_str_unknown_argument(#[case] input: &str) {
+    assert!(matches!(
+        input.parse::<SbatchOption>(),
+        Err(SbatchOptionError::UnknownArgument(_))
+    ));
+}
+
+#[rstest]
+#[case("--job-name", "job-name")]
+#[case("--partition", "partition")]
+#[case("-J", "J")]
+#[case("-o", "o")]
+fn test_from_str_missing_value(#[case] input: &str, #[case] expected: &str) {
+    match input.parse::<SbatchOption>() {
+        Err(SbatchOptionError::MissingValue(key)) => assert_eq!(key, expected),
+        other => panic!("unexpected result: {other:?}"),
+    }
+}
+
+#[rstest]
+#[case("job-name", Some(true))]
+#[case("J", Some(true))]
+#[case("wrap", Some(true))]
+#[case("exclusive", Some(false))]
+#[case("contiguous", Some(false))]
+#[case("not-an-option", None)]
+fn test_requires_value(#[case] name: &str, #[case] expected: Option<bool>) {
+    assert_eq!(SbatchOption::requires_value(name), expected);
+}
+
+#[rstest]
+#[case("--job-name=")]
+#[case("--job-name= test")]
+#[case("--exclusive=bogus")]
+#[case("--wait-all-nodes=yes")]
+#[case("--wait-all-nodes=true")]
+fn test_from_str_invalid_value(#[case] input: &str) {
+    assert!(input.parse::<SbatchOption>().is_err());
+}
+
+#[rstest]
+#[case("--sockets-per-node=2", SbatchOption::SocketsPerNode(NonZeroU32::new(2).unwrap()))]
+#[case("--cores-per-socket 8", SbatchOption::CoresPerSocket(NonZeroU32::new(8).unwrap()))]
+#[case("-n 4", SbatchOption::NTasks(NonZeroU32::new(4).unwrap()))]
+#[case("--ntasks-per-node=2", SbatchOption::NTasksPerNode(NonZeroU32::new(2).unwrap()))]
+#[case("-S 1", SbatchOption::CoreSpec(1))]
+#[case("--core-spec=0", SbatchOption::CoreSpec(0))]
+fn test_from_str_count(#[case] input: &str, #[case] expected: SbatchOption) {
+    assert_eq!(input.parse::<SbatchOption>().unwrap(), expected);
+}
+
+#[rstest]
+#[case("--sockets-per-node=0")]
+#[case("--sockets-per-node=two")]
+#[case("--cores-per-socket=-1")]
+#[case("--cores-per-socket=${CORES}")]
+#[case("--ntasks=0")]
+#[case("--ntasks=${NODE_CORES}")]
+#[case("--ntasks-per-node=four")]
+#[case("--core-spec=")]
+#[case("--core-spec=-1")]
+fn test_from_str_count_error(#[case] input: &str) {
+    assert!(matches!(
+        input.parse::<SbatchOption>(),
+        Err(SbatchOptionError::InvalidCount(_))
+    ));
+}
+
+#[rstest]
+#[case("--nice=+50", SbatchOption::Nice(Some(50)), "--nice=50")]
+#[case("--nice=-100", SbatchOption::Nice(Some(-100)), "--nice=-100")]
+#[case("--nice=0", SbatchOption::Nice(Some(0)), "--nice=0")]
+#[case("--nice 50", SbatchOption::Nice(Some(50)), "--nice=50")]
+fn test_from_str_nice(#[case] input: &str, #[case] expected: SbatchOption, #[case] display: &str) {
+    let option = input.parse::<SbatchOption>().unwrap();
+    assert_eq!(option, expected);
+    assert_eq!(option.to_string(), display);
+}
+
+#[rstest]
+#[case("--nice=")]
+#[case("--nice=+")]
+#[case("--nice=-")]
+#[case("--nice=+-5")]
+#[case("--nice=--5")]
+#[case("--nice=5.5")]
+#[case("--nice=high")]
+#[case("--nice=99999999999")]
+#[case("--nice=2147483647")]
+fn test_from_str_nice_error(#[case] input: &str) {
+    assert!(input.parse::<SbatchOption>().is_err());
+}
+
+// Helper function to parse a flag, retrying with a value if the bare flag is missing one
+fn parse_flag(flag: &str) -> Result<SbatchOption, SbatchOptionError> {
+    match flag.parse::<SbatchOption>() {
+        Err(SbatchOptionError::MissingValue(_)) => format!("{} x", flag).parse(),
+        result => result,
+    }
+}
+
+#[test]
+fn test_known_long_flags_parse() {
+    for flag in SbatchOption::known_long_flags() {
+        assert!(
+            !matches!(
+                parse_flag(&format!("--{}", flag)),
+                Err(SbatchOptionError::UnknownArgument(_))
+            ),
+            "--{} is not recognized",
+            flag
+        );
+        assert!(SbatchOption::requires_value(flag).is_some());
+    }
+}
+
+#[test]
+fn test_known_short_flags_parse() {
+    for flag in SbatchOption::known_short_flags() {
+        assert!(
+            !matches!(
+                parse_flag(&format!("-{}", flag)),
+                Err(SbatchOptionError::UnknownArgument(_))
+            ),
+            "-{} is not recognized",
+            flag
+        );
+        assert!(SbatchOption::requires_value(&flag.to_string()).is_some());
+    }
+}
+
+#[rstest]
+#[case("0")]
+#[case("1")]
+fn test_wait_all_nodes_round_trip(#[case] value: &str) {
+    let option = SbatchOption::WaitAllNodes(value.to_string());
+    assert_eq!(option.to_string().parse::<SbatchOption>().unwrap(), option);
+}
@@ -0,0 +1,137 @@
+use rstest::rstest;
+use sbatch_rs::{Normalization, ParseOptions, SbatchOption, SbatchOptionError, UnknownPolicy};
+
+#[rstest]
+#[case("--newfangled-flag=x", SbatchOption::Raw("--newfangled-flag=x".to_string()))]
+#[case("--newfangled-flag", SbatchOption::Raw("--newfangled-flag".to_string()))]
+#[case("-Z value", SbatchOption::Raw("-Z value".to_string()))]
+#[case("--newfangled-flag='a b'", SbatchOption::Raw("--newfangled-flag=a b".to_string()))]
+#[case("-Z 'a b'", SbatchOption::Raw("-Z a b".to_string()))]
+#[case("--job_name=test", SbatchOption::JobName("test".to_string()))]
+#[case("--JOB-NAME=test", SbatchOption::JobName("test".to_string()))]
+#[case("--Cpus_Per_Task=4", SbatchOption::CPUsPerTask("4".to_string()))]
+#[case("-J test", SbatchOption::JobName("test".to_string()))]
+fn test_from_str_with_lenient(#[case] input: &str, #[case] expected: SbatchOption) {
+    assert_eq!(
+        SbatchOption::from_str_with(input, &ParseOptions::lenient()).unwrap(),
+        expected
+    );
+}
+
+#[rstest]
+#[case(SbatchOption::Raw("--newfangled-flag=a b".to_string()))]
+#[case(SbatchOption::Raw("--newfangled-flag=it's".to_string()))]
+#[case(SbatchOption::Raw("--newfangled-flag".to_string()))]
+fn test_from_str_with_lenient_raw_round_trip(#[case] option: SbatchOption) {
+    let displayed = option.to_string();
+    let parsed = SbatchOption::from_str_with(&displayed, &ParseOptions::lenient()).unwrap();
+    assert_eq!(parsed, option);
+    assert_eq!(parsed.to_string(), displayed);
+}
+
+#[rstest]
+#[case("--newfangled-flag=x")]
+#[case("--job_name=test")]
+#[case("--JOB-NAME=test")]
+fn test_from_str_with_strict(#[case] input: &str) {
+    assert!(matches!(
+        SbatchOption::from_str_with(input, &ParseOptions::strict()),
+        Err(SbatchOptionError::UnknownArgument(_))
+    ));
+    assert!(input.parse::<SbatchOption>().is_err());
+}
+
+#[test]
+fn test_from_str_with_individual_flags() {
+    let options = ParseOptions {
+        normalize_underscores: true,
+        ..ParseOptions::strict()
+    };
+    assert!(SbatchOption::from_str_with("--job_name=test", &options).is_ok());
+    assert!(SbatchOption::from_str_with("--Job_Name=test", &options).is_err());
+    assert!(SbatchOption::from_str_with("--bogus=test", &options).is_err());
+}
+
+#[rstest]
+#[case(UnknownPolicy::Error, None)]
+#[case(UnknownPolicy::Passthrough, Some(SbatchOption::Raw("--newfangled-flag=x".to_string())))]
+#[case(UnknownPolicy::Skip, None)]
+#[case(UnknownPolicy::Collect, None)]
+fn test_from_str_with_unknown_policy(
+    #[case] on_unknown: UnknownPolicy,
+    #[case] expected: Option<SbatchOption>,
+) {
+    let options = ParseOptions {
+        on_unknown,
+        ..ParseOptions::strict()
+    };
+    match expected {
+        Some(expected) => assert_eq!(
+            SbatchOption::from_str_with("--newfangled-flag=x", &options).unwrap(),
+            expected
+        ),
+        None => assert!(matches!(
+            SbatchOption::from_str_with("--newfangled-flag=x", &options),
+            Err(SbatchOptionError::UnknownArgument(_))
+        )),
+    }
+}
+
+#[rstest]
+#[case("not-an-option")]
+#[case("--job-name")]
+#[case("--mem=4X")]
+fn test_from_str_with_lenient_errors(#[case] input: &str) {
+    assert!(SbatchOption::from_str_with(input, &ParseOptions::lenient()).is_err());
+}
+
+#[test]
+fn test_short_options_stay_case_sensitive() {
+    // `-v` is verbose and `-V` is version, so short options are never case-folded
+    assert_eq!(
+        SbatchOption::from_str_with("-V", &ParseOptions::lenient()).unwrap(),
+        SbatchOption::Version
+    );
+}
+
+#[rstest]
+#[case("--job_name=test", vec![("--job_name", "--job-name")])]
+#[case("--Job-Name test", vec![("--Job-Name", "--job-name")])]
+#[case("--job-name=test", vec![])]
+#[case("-J test", vec![])]
+#[case("--unknown_flag=x", vec![])]
+fn test_from_str_with_normalizations(#[case] input: &str, #[case] expected: Vec<(&str, &str)>) {
+    let (_, normalizations) =
+        SbatchOption::from_str_with_normalizations(input, &ParseOptions::lenient()).unwrap();
+    let expected: Vec<Normalization> = expected
+        .into_iter()
+        .map(|(original, canonical)| Normalization {
+            original: original.to_string(),
+            canonical: canonical.to_string(),
+        })
+        .collect();
+    assert_eq!(normalizations, expected);
+}
+
+#[rstest]
+#[case("--comment=first line\nsecond line", "first line second line")]
+#[case("--comment=a\r\nb\n\n  c", "a b c")]
+#[case("--comment=single line", "single line")]
+fn test_from_str_with_join_comment_lines(#[case] input: &str, #[case] expected: &str) {
+    let options = ParseOptions {
+        join_comment_lines: true,
+        ..ParseOptions::strict()
+    };
+    assert_eq!(
+        SbatchOption::from_str_with(input, &options).unwrap(),
+        SbatchOption::Comment(expected.to_string())
+    );
+}
+
+#[test]
+fn test_from_str_with_strict_rejects_comment_newline() {
+    assert!(matches!(
+        SbatchOption::from_str_with("--comment=a\nb", &ParseOptions::strict()),
+        Err(SbatchOptionError::InvalidComment(_))
+    ));
+}
@@ -0,0 +1,62 @@
+use rstest::rstest;
+use sbatch_rs::{GpuSpec, SbatchOption, SbatchOptionError};
+
+#[rstest]
+#[case("2", None, 2, "2")]
+#[case("tesla:4", Some("tesla"), 4, "tesla:4")]
+#[case("a100-80g:1", Some("a100-80g"), 1, "a100-80g:1")]
+#[case("08", None, 8, "8")]
+fn test_gpu_spec_from_str(
+    #[case] input: &str,
+    #[case] gpu_type: Option<&str>,
+    #[case] count: u32,
+    #[case] display: &str,
+) {
+    let gpus: GpuSpec = input.parse().unwrap();
+    assert_eq!(gpus.gpu_type(), gpu_type);
+    assert_eq!(gpus.count().get(), count);
+    assert_eq!(gpus.to_string(), display);
+}
+
+#[rstest]
+#[case("")]
+#[case("tesla:")]
+#[case(":2")]
+#[case("-2")]
+#[case("0")]
+#[case("tesla:0")]
+#[case("tesla")]
+#[case("tesla:2:1")]
+#[case("tesla:+2")]
+#[case("te sla:2")]
+#[case("4294967296")]
+fn test_gpu_spec_from_str_error(#[case] input: &str) {
+    assert!(matches!(
+        input.parse::<GpuSpec>(),
+        Err(SbatchOptionError::InvalidGpuSpec(_))
+    ));
+}
+
+#[rstest]
+#[case("--gpus=tesla:4", "--gpus=tesla:4")]
+#[case("-G 2", "--gpus=2")]
+#[case("--gpus-per-node=2", "--gpus-per-node=2")]
+#[case("--gpus-per-socket=v100:1", "--gpus-per-socket=v100:1")]
+#[case("--gpus-per-task 1", "--gpus-per-task=1")]
+fn test_gpu_spec_option_from_str(#[case] input: &str, #[case] display: &str) {
+    let option: SbatchOption = input.parse().unwrap();
+    assert_eq!(option.to_string(), display);
+    assert_eq!(display.parse::<SbatchOption>().unwrap(), option);
+}
+
+#[rstest]
+#[case("--gpus=tesla:")]
+#[case("--gpus-per-node=-2")]
+#[case("--gpus-per-socket=0")]
+#[case("--gpus-per-task=all")]
+fn test_gpu_spec_option_from_str_error(#[case] input: &str) {
+    assert!(matches!(
+        input.parse::<SbatchOption>(),
+        Err(SbatchOptionError::InvalidGpuSpec(_))
+    ));
+}
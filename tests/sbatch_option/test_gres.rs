@@ -0,0 +1,82 @@
+use rstest::rstest;
+use sbatch_rs::{Gres, SbatchOption, SbatchOptionError};
+
+#[rstest]
+#[case("gpu", vec![("gpu", None, None)], "gpu")]
+#[case("gpu:2", vec![("gpu", None, Some(2))], "gpu:2")]
+#[case("gpu:tesla", vec![("gpu", Some("tesla"), None)], "gpu:tesla")]
+#[case("gpu:tesla:2", vec![("gpu", Some("tesla"), Some(2))], "gpu:tesla:2")]
+#[case("gpu:a100-80g:4", vec![("gpu", Some("a100-80g"), Some(4))], "gpu:a100-80g:4")]
+#[case("gpu:tesla:2,mps:50", vec![("gpu", Some("tesla"), Some(2)), ("mps", None, Some(50))], "gpu:tesla:2,mps:50")]
+fn test_gres_from_str(
+    #[case] input: &str,
+    #[case] expected: Vec<(&str, Option<&str>, Option<u32>)>,
+    #[case] display: &str,
+) {
+    let gres: Gres = input.parse().unwrap();
+    let entries = gres
+        .entries()
+        .iter()
+        .map(|entry| {
+            (
+                entry.name(),
+                entry.gres_type(),
+                entry.count().map(|count| count.get()),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(entries, expected);
+    assert_eq!(gres.to_string(), display);
+}
+
+#[rstest]
+#[case("")]
+#[case("gpu:")]
+#[case(":2")]
+#[case("gpu:0")]
+#[case("gpu:tesla:0")]
+#[case("gpu:tesla:")]
+#[case("gpu:tesla:two")]
+#[case("gpu:tesla:-1")]
+#[case("gpu:tesla:2:1")]
+#[case("gpu,")]
+#[case("gpu 2")]
+#[case("gpu;rm")]
+fn test_gres_from_str_error(#[case] input: &str) {
+    assert!(matches!(
+        input.parse::<Gres>(),
+        Err(SbatchOptionError::InvalidGres(_))
+    ));
+}
+
+#[rstest]
+#[case("--gres=gpu:tesla:2", "--gres=gpu:tesla:2")]
+#[case("--gres gpu:2,mps:50", "--gres=gpu:2,mps:50")]
+fn test_gres_option_from_str(#[case] input: &str, #[case] display: &str) {
+    let option: SbatchOption = input.parse().unwrap();
+    assert_eq!(option.to_string(), display);
+    assert_eq!(display.parse::<SbatchOption>().unwrap(), option);
+}
+
+#[test]
+fn test_gres_option_from_str_error() {
+    assert!(matches!(
+        "--gres=gpu:".parse::<SbatchOption>(),
+        Err(SbatchOptionError::InvalidGres(_))
+    ));
+}
+
+#[test]
+fn test_gres_merge_value() {
+    let option = SbatchOption::Gres("gpu:tesla:2".parse().unwrap());
+    assert!(option.is_mergeable());
+    assert_eq!(
+        option.merge_value(SbatchOption::Gres("mps:50".parse().unwrap())),
+        Some(SbatchOption::Gres("gpu:tesla:2,mps:50".parse().unwrap()))
+    );
+    assert_eq!(
+        SbatchOption::Gres("gpu".parse().unwrap())
+            .merge_value(SbatchOption::Licenses("matlab".to_string())),
+        None
+    );
+}
@@ -0,0 +1,45 @@
+use rstest::rstest;
+use sbatch_rs::{SbatchOption, SbatchOptionError};
+
+#[rstest]
+#[case("NONE")]
+#[case("BEGIN")]
+#[case("END")]
+#[case("FAIL")]
+#[case("REQUEUE")]
+#[case("ALL")]
+#[case("INVALID_DEPEND")]
+#[case("STAGE_OUT")]
+#[case("TIME_LIMIT")]
+#[case("TIME_LIMIT_90")]
+#[case("TIME_LIMIT_80")]
+#[case("TIME_LIMIT_50")]
+#[case("ARRAY_TASKS")]
+#[case("begin,end")]
+#[case("BEGIN,End,FAIL")]
+#[case("ALL,ARRAY_TASKS")]
+fn test_mail_type_valid(#[case] value: &str) {
+    assert!(SbatchOption::MailType(value.to_string()).validate().is_ok());
+}
+
+#[rstest]
+#[case("BOGUS", "BOGUS")]
+#[case("BEGIN,BOGUS", "BOGUS")]
+#[case("BEGIN,,END", "")]
+#[case("BEGIN, END", " END")]
+#[case("TIME_LIMIT_70", "TIME_LIMIT_70")]
+fn test_mail_type_invalid(#[case] value: &str, #[case] token: &str) {
+    match SbatchOption::MailType(value.to_string()).validate() {
+        Err(SbatchOptionError::InvalidMailType(invalid)) => assert_eq!(invalid, token),
+        other => panic!("unexpected result: {other:?}"),
+    }
+}
+
+#[test]
+fn test_mail_type_from_str() {
+    assert!("--mail-type=END,FAIL".parse::<SbatchOption>().is_ok());
+    assert!(matches!(
+        "--mail-type=BOGUS".parse::<SbatchOption>(),
+        Err(SbatchOptionError::InvalidMailType(_))
+    ));
+}
@@ -0,0 +1,67 @@
+use rstest::rstest;
+use sbatch_rs::{Memory, MemoryUnit, SbatchOption, SbatchOptionError};
+
+#[rstest]
+#[case("0", 0, None, "0")]
+#[case("512", 512 << 20, None, "512")]
+#[case("100k", 100 << 10, Some(MemoryUnit::Kilobytes), "100K")]
+#[case("100K", 100 << 10, Some(MemoryUnit::Kilobytes), "100K")]
+#[case("512M", 512 << 20, Some(MemoryUnit::Megabytes), "512M")]
+#[case("4g", 4 << 30, Some(MemoryUnit::Gigabytes), "4G")]
+#[case("4G", 4 << 30, Some(MemoryUnit::Gigabytes), "4G")]
+#[case("2T", 2 << 40, Some(MemoryUnit::Terabytes), "2T")]
+fn test_memory_from_str(
+    #[case] input: &str,
+    #[case] bytes: u64,
+    #[case] unit: Option<MemoryUnit>,
+    #[case] display: &str,
+) {
+    let memory: Memory = input.parse().unwrap();
+    assert_eq!(memory.as_bytes(), bytes);
+    assert_eq!(memory.unit(), unit);
+    assert_eq!(memory.to_string(), display);
+}
+
+#[rstest]
+#[case("")]
+#[case("G")]
+#[case("-4G")]
+#[case("4X")]
+#[case("4Gilobytes")]
+#[case("4.5G")]
+#[case(" 4G")]
+#[case("4 G")]
+#[case("99999999999999999999T")]
+fn test_memory_from_str_error(#[case] input: &str) {
+    assert!(matches!(
+        input.parse::<Memory>(),
+        Err(SbatchOptionError::InvalidMemory(_))
+    ));
+}
+
+#[rstest]
+#[case("--mem=4G", SbatchOption::Mem("4G".parse().unwrap()))]
+#[case("--mem-per-cpu=2g", SbatchOption::MemPerCPU("2G".parse().unwrap()))]
+#[case("--mem-per-gpu=0", SbatchOption::MemPerGPU("0".parse().unwrap()))]
+fn test_memory_option_from_str(#[case] input: &str, #[case] expected: SbatchOption) {
+    assert_eq!(input.parse::<SbatchOption>().unwrap(), expected);
+}
+
+#[test]
+fn test_memory_zero_is_all_memory() {
+    let option: SbatchOption = "--mem=0".parse().unwrap();
+    assert!(option.validate().is_ok());
+    match option {
+        SbatchOption::Mem(memory) => assert!(memory.is_zero()),
+        other => panic!("Expected Mem, got {other:?}"),
+    }
+    assert!(!"4G".parse::<Memory>().unwrap().is_zero());
+}
+
+#[test]
+fn test_memory_option_from_str_error() {
+    assert!(matches!(
+        "--mem=4Gilobytes".parse::<SbatchOption>(),
+        Err(SbatchOptionError::InvalidMemory(_))
+    ));
+}
@@ -0,0 +1,89 @@
+use rstest::rstest;
+use sbatch_rs::{NodeList, SbatchOption, SbatchOptionError};
+
+#[rstest]
+#[case("node1", vec!["node1"], "node1")]
+#[case("node1,node3", vec!["node1", "node3"], "node1,node3")]
+#[case("node[1-4,7]", vec!["node1", "node2", "node3", "node4", "node7"], "node[1-4,7]")]
+#[case("node[08-11]", vec!["node08", "node09", "node10", "node11"], "node[08-11]")]
+#[case("node[9-10]", vec!["node9", "node10"], "node[9-10]")]
+#[case("node[3]", vec!["node3"], "node[3]")]
+#[case("node[3-3]", vec!["node3"], "node[3]")]
+#[case(
+    "rack[1-2]node[01-02]",
+    vec!["rack1node01", "rack1node02", "rack2node01", "rack2node02"],
+    "rack[1-2]node[01-02]"
+)]
+#[case("[1-2]-ib", vec!["1-ib", "2-ib"], "[1-2]-ib")]
+#[case("a[1-2],b", vec!["a1", "a2", "b"], "a[1-2],b")]
+#[case("gpu-node.cluster", vec!["gpu-node.cluster"], "gpu-node.cluster")]
+#[case("/home/user/hosts.txt", vec!["/home/user/hosts.txt"], "/home/user/hosts.txt")]
+fn test_node_list_from_str(
+    #[case] input: &str,
+    #[case] expected: Vec<&str>,
+    #[case] display: &str,
+) {
+    let nodes: NodeList = input.parse().unwrap();
+    assert_eq!(nodes.expand().unwrap(), expected);
+    assert_eq!(nodes.to_string(), display);
+}
+
+#[rstest]
+#[case("")]
+#[case("node1,")]
+#[case(",node1")]
+#[case("node1,,node2")]
+#[case("node[")]
+#[case("node[1-")]
+#[case("node]1")]
+#[case("node[]")]
+#[case("node[4-1]")]
+#[case("node[a-b]")]
+#[case("node[1,]")]
+#[case("node[[1]]")]
+#[case("node 1")]
+#[case(" node1")]
+fn test_node_list_from_str_error(#[case] input: &str) {
+    assert!(matches!(
+        input.parse::<NodeList>(),
+        Err(SbatchOptionError::InvalidNodeList(_))
+    ));
+}
+
+#[test]
+fn test_node_list_expand_limit() {
+    let nodes: NodeList = "a[1-10],b[1-10]".parse().unwrap();
+    assert_eq!(nodes.expand_with_limit(20).unwrap().len(), 20);
+    assert!(matches!(
+        nodes.expand_with_limit(19),
+        Err(SbatchOptionError::NodeListTooLarge(19))
+    ));
+
+    let nodes: NodeList = "a[0-4294967295]b[0-4294967295]c[0-4294967295]"
+        .parse()
+        .unwrap();
+    assert!(matches!(
+        nodes.expand(),
+        Err(SbatchOptionError::NodeListTooLarge(
+            NodeList::DEFAULT_NODE_LIMIT
+        ))
+    ));
+}
+
+#[rstest]
+#[case("--nodelist=node[1-2]", "--nodelist='node[1-2]'")]
+#[case("-w node[1-2]", "--nodelist='node[1-2]'")]
+#[case("--exclude=node5", "--exclude=node5")]
+#[case("-x node[01-02],gpu1", "--exclude='node[01-02],gpu1'")]
+fn test_node_list_option_from_str(#[case] input: &str, #[case] display: &str) {
+    let option: SbatchOption = input.parse().unwrap();
+    assert_eq!(option.to_string(), display);
+}
+
+#[test]
+fn test_node_list_option_from_str_error() {
+    assert!(matches!(
+        "--nodelist=node[1-".parse::<SbatchOption>(),
+        Err(SbatchOptionError::InvalidNodeList(_))
+    ));
+}
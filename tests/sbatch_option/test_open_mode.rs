@@ -0,0 +1,37 @@
+use rstest::rstest;
+use sbatch_rs::{OpenMode, SbatchOption, SbatchOptionError};
+
+#[rstest]
+#[case("append", OpenMode::Append)]
+#[case("APPEND", OpenMode::Append)]
+#[case("truncate", OpenMode::Truncate)]
+#[case("Truncate", OpenMode::Truncate)]
+fn test_open_mode_from_str(#[case] input: &str, #[case] expected: OpenMode) {
+    let mode: OpenMode = input.parse().unwrap();
+    assert_eq!(mode, expected);
+    assert_eq!(mode.to_string(), input.to_ascii_lowercase());
+}
+
+#[rstest]
+#[case("")]
+#[case("overwrite")]
+#[case("apend")]
+#[case(" append")]
+fn test_open_mode_from_str_error(#[case] input: &str) {
+    assert!(matches!(
+        input.parse::<OpenMode>(),
+        Err(SbatchOptionError::InvalidOpenMode(_))
+    ));
+}
+
+#[test]
+fn test_open_mode_option_from_str() {
+    assert_eq!(
+        "--open-mode=Append".parse::<SbatchOption>().unwrap(),
+        SbatchOption::OpenMode(OpenMode::Append)
+    );
+    assert!(matches!(
+        "--open-mode=test".parse::<SbatchOption>(),
+        Err(SbatchOptionError::InvalidOpenMode(_))
+    ));
+}
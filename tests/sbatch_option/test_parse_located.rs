@@ -0,0 +1,34 @@
+use rstest::rstest;
+use sbatch_rs::SbatchOption;
+
+#[rstest]
+#[case("--sockets-per-node=ab", 19..21)]
+#[case("--time=abc", 7..10)]
+#[case("--time abc", 7..10)]
+#[case("  --time=abc  ", 9..12)]
+#[case("-t abc", 3..6)]
+#[case("--array='1-x'", 8..13)]
+#[case("--job-name= test", 11..16)]
+#[case("--not-an-option=1", 2..15)]
+#[case("--job-name", 2..10)]
+#[case("--hold=1", 2..6)]
+#[case("-Z", 1..2)]
+#[case("job-name=test", 0..13)]
+#[case(" -ab ", 3..4)]
+#[case(" -Zq ", 1..4)]
+fn test_parse_located_span(#[case] input: &str, #[case] span: std::ops::Range<usize>) {
+    let error = SbatchOption::parse_located(input).unwrap_err();
+    assert_eq!(error.span, span);
+    assert_eq!(
+        error.message,
+        input.parse::<SbatchOption>().unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn test_parse_located_success() {
+    assert_eq!(
+        SbatchOption::parse_located("--job-name=test").unwrap(),
+        SbatchOption::JobName("test".to_string())
+    );
+}
@@ -0,0 +1,61 @@
+use rstest::rstest;
+use sbatch_rs::{SbatchOption, SbatchOptionError, SignalScope, SignalSpec};
+
+#[rstest]
+#[case("USR1", None, "USR1", None, "USR1")]
+#[case("10", None, "10", None, "10")]
+#[case("sigterm", None, "TERM", None, "TERM")]
+#[case("B:USR1@60", Some(SignalScope::Batch), "USR1", Some(60), "B:USR1@60")]
+#[case("r:10@0", Some(SignalScope::Reservation), "10", Some(0), "R:10@0")]
+#[case("KILL@65535", None, "KILL", Some(65535), "KILL@65535")]
+fn test_signal_spec_from_str(
+    #[case] input: &str,
+    #[case] scope: Option<SignalScope>,
+    #[case] signal: &str,
+    #[case] time: Option<u16>,
+    #[case] display: &str,
+) {
+    let spec: SignalSpec = input.parse().unwrap();
+    assert_eq!(spec.scope(), scope);
+    assert_eq!(spec.signal(), signal);
+    assert_eq!(spec.time(), time);
+    assert_eq!(spec.to_string(), display);
+}
+
+#[rstest]
+#[case("")]
+#[case("FOO")]
+#[case("SIG")]
+#[case("0")]
+#[case("65")]
+#[case("USR1@-5")]
+#[case("USR1@")]
+#[case("USR1@1.5")]
+#[case("USR1@65536")]
+#[case("X:USR1")]
+#[case(":USR1")]
+#[case("B:")]
+#[case("B:R:USR1")]
+fn test_signal_spec_from_str_error(#[case] input: &str) {
+    assert!(matches!(
+        input.parse::<SignalSpec>(),
+        Err(SbatchOptionError::InvalidSignal(_))
+    ));
+}
+
+#[rstest]
+#[case("--signal=B:USR1@60", "--signal=B:USR1@60")]
+#[case("--signal 10", "--signal=10")]
+fn test_signal_option_from_str(#[case] input: &str, #[case] display: &str) {
+    let option: SbatchOption = input.parse().unwrap();
+    assert!(matches!(option, SbatchOption::Signal(_)));
+    assert_eq!(option.to_string(), display);
+}
+
+#[test]
+fn test_signal_option_from_str_error() {
+    assert!(matches!(
+        "--signal=USR3".parse::<SbatchOption>(),
+        Err(SbatchOptionError::InvalidSignal(_))
+    ));
+}
@@ -0,0 +1,74 @@
+use rstest::rstest;
+use sbatch_rs::{SbatchOption, SbatchOptionError, TimeLimit};
+use std::time::Duration;
+
+#[rstest]
+#[case("30", 30 * 60, "0-00:30:00")]
+#[case("90", 90 * 60, "0-01:30:00")]
+#[case("30:15", 30 * 60 + 15, "0-00:30:15")]
+#[case("1:30:00", 90 * 60, "0-01:30:00")]
+#[case("48:00:00", 48 * 3600, "2-00:00:00")]
+#[case("2-12", 2 * 86400 + 12 * 3600, "2-12:00:00")]
+#[case("2-12:30", 2 * 86400 + 12 * 3600 + 30 * 60, "2-12:30:00")]
+#[case("2-12:30:45", 2 * 86400 + 12 * 3600 + 30 * 60 + 45, "2-12:30:45")]
+#[case("0-00:00:00", 0, "0-00:00:00")]
+fn test_time_limit_from_str(#[case] input: &str, #[case] seconds: u64, #[case] display: &str) {
+    let time: TimeLimit = input.parse().unwrap();
+    assert_eq!(time.as_duration(), Duration::from_secs(seconds));
+    assert_eq!(time.to_string(), display);
+
+    // The canonical form parses back to the same value
+    assert_eq!(display.parse::<TimeLimit>().unwrap(), time);
+}
+
+#[rstest]
+#[case("UNLIMITED")]
+#[case("unlimited")]
+#[case("INFINITE")]
+fn test_time_limit_unlimited(#[case] input: &str) {
+    let time: TimeLimit = input.parse().unwrap();
+    assert!(time.is_unlimited());
+    assert_eq!(time.as_duration(), Duration::MAX);
+    assert_eq!(time.to_string(), "UNLIMITED");
+}
+
+#[rstest]
+#[case("")]
+#[case("99:99")]
+#[case("1:60:00")]
+#[case("1:00:60")]
+#[case("1-24")]
+#[case("1-")]
+#[case("-12")]
+#[case("1-12:00:00:00")]
+#[case("1:2:3:4")]
+#[case("abc")]
+#[case("-5")]
+#[case(" 30")]
+fn test_time_limit_from_str_error(#[case] input: &str) {
+    assert!(matches!(
+        input.parse::<TimeLimit>(),
+        Err(SbatchOptionError::InvalidTimeLimit(_))
+    ));
+}
+
+#[rstest]
+#[case("--time=1:00:00", SbatchOption::Time(TimeLimit::from_secs(3600)))]
+#[case("-t 60", SbatchOption::Time(TimeLimit::from_secs(3600)))]
+#[case("--time-min=UNLIMITED", SbatchOption::TimeMin(TimeLimit::unlimited()))]
+fn test_time_limit_option_from_str(#[case] input: &str, #[case] expected: SbatchOption) {
+    assert_eq!(input.parse::<SbatchOption>().unwrap(), expected);
+}
+
+#[rstest]
+#[case("2-00:00:01", "2-00:00:00", true)]
+#[case("48:00:00", "2-00:00:00", false)]
+#[case("30", "1:00:00", false)]
+#[case("UNLIMITED", "2-00:00:00", true)]
+#[case("365-00:00:00", "UNLIMITED", false)]
+#[case("UNLIMITED", "UNLIMITED", false)]
+fn test_time_limit_exceeds(#[case] time: &str, #[case] limit: &str, #[case] expected: bool) {
+    let time: TimeLimit = time.parse().unwrap();
+    let limit: TimeLimit = limit.parse().unwrap();
+    assert_eq!(time.exceeds(&limit), expected);
+}
@@ -1,36 +1,39 @@
 use rstest::rstest;
-use sbatch_rs::SbatchOption;
+use sbatch_rs::{OpenMode, SbatchOption, SbatchOptionError};
+use std::num::NonZeroU32;
 
 #[rstest]
 #[case(SbatchOption::Account("test".to_string()))]
 #[case(SbatchOption::AcctgFreq("test".to_string()))]
-#[case(SbatchOption::Array("test".to_string()))]
+#[case(SbatchOption::Array("0-15%4".parse().unwrap()))]
 #[case(SbatchOption::Batch("test".to_string()))]
 #[case(SbatchOption::Bb("test".to_string()))]
 #[case(SbatchOption::Bbf("test".to_string()))]
-#[case(SbatchOption::Begin("test".to_string()))]
+#[case(SbatchOption::Begin("now+1hour".parse().unwrap()))]
 #[case(SbatchOption::Chdir("test".to_string()))]
 #[case(SbatchOption::ClusterConstraint("test".to_string()))]
 #[case(SbatchOption::Clusters("test".to_string()))]
 #[case(SbatchOption::Comment("test".to_string()))]
-#[case(SbatchOption::Constraint("test".to_string()))]
+#[case(SbatchOption::Constraint("test".parse().unwrap()))]
 #[case(SbatchOption::Container("test".to_string()))]
 #[case(SbatchOption::ContainerID("test".to_string()))]
 #[case(SbatchOption::Contiguous)]
-#[case(SbatchOption::CoreSpec("test".to_string()))]
-#[case(SbatchOption::CoresPerSocket("test".to_string()))]
-#[case(SbatchOption::CPUFreq("test".to_string()))]
+#[case(SbatchOption::CoreSpec("4".parse().unwrap()))]
+#[case(SbatchOption::CoresPerSocket("8".parse().unwrap()))]
+#[case(SbatchOption::CPUFreq("Performance".parse().unwrap()))]
 #[case(SbatchOption::CPUsPerGPU("test".to_string()))]
 #[case(SbatchOption::CPUsPerTask("test".to_string()))]
 #[case(SbatchOption::Deadline("test".to_string()))]
 #[case(SbatchOption::DelayBoot("test".to_string()))]
 #[case(SbatchOption::Dependency("test".to_string()))]
-#[case(SbatchOption::Distribution("test".to_string()))]
+#[case(SbatchOption::Distribution("block".parse().unwrap()))]
 #[case(SbatchOption::Error("test".to_string()))]
-#[case(SbatchOption::Exclude("test".to_string()))]
-#[case(SbatchOption::Exclusive(Some("test".to_string())))]
+#[case(SbatchOption::Exclude("test".parse().unwrap()))]
+#[case(SbatchOption::Exclusive(Some("user".to_string())))]
+#[case(SbatchOption::Exclusive(Some("mcs".to_string())))]
+#[case(SbatchOption::Exclusive(Some("topo".to_string())))]
 #[case(SbatchOption::Exclusive(None))]
-#[case(SbatchOption::Export("test".to_string()))]
+#[case(SbatchOption::Export("test".parse().unwrap()))]
 #[case(SbatchOption::ExportFile("test".to_string()))]
 #[case(SbatchOption::Extra("test".to_string()))]
 #[case(SbatchOption::ExtraNodeInfo("test".to_string()))]
@@ -39,11 +42,11 @@ use sbatch_rs::SbatchOption;
 #[case(SbatchOption::GID("test".to_string()))]
 #[case(SbatchOption::GPUBind("test".to_string()))]
 #[case(SbatchOption::GPUFreq("test".to_string()))]
-#[case(SbatchOption::GPUs("test".to_string()))]
-#[case(SbatchOption::GPUsPerNode("test".to_string()))]
-#[case(SbatchOption::GPUsPerSocket("test".to_string()))]
-#[case(SbatchOption::GPUsPerTask("test".to_string()))]
-#[case(SbatchOption::Gres("test".to_string()))]
+#[case(SbatchOption::GPUs("2".parse().unwrap()))]
+#[case(SbatchOption::GPUsPerNode("2".parse().unwrap()))]
+#[case(SbatchOption::GPUsPerSocket("2".parse().unwrap()))]
+#[case(SbatchOption::GPUsPerTask("2".parse().unwrap()))]
+#[case(SbatchOption::Gres("gpu".parse().unwrap()))]
 #[case(SbatchOption::GresFlags("test".to_string()))]
 #[case(SbatchOption::Help)]
 #[case(SbatchOption::Hint("test".to_string()))]
@@ -53,31 +56,33 @@ use sbatch_rs::SbatchOption;
 #[case(SbatchOption::JobName("test".to_string()))]
 #[case(SbatchOption::KillOnInvalidDep("test".to_string()))]
 #[case(SbatchOption::Licenses("test".to_string()))]
-#[case(SbatchOption::MailType("test".to_string()))]
+#[case(SbatchOption::MailType("END".to_string()))]
 #[case(SbatchOption::MailUser("test".to_string()))]
 #[case(SbatchOption::McsLabel("test".to_string()))]
-#[case(SbatchOption::Mem("test".to_string()))]
+#[case(SbatchOption::Mem("4G".parse().unwrap()))]
 #[case(SbatchOption::MemBind("test".to_string()))]
-#[case(SbatchOption::MemPerCPU("test".to_string()))]
-#[case(SbatchOption::MemPerGPU("test".to_string()))]
+#[case(SbatchOption::MemPerCPU("512M".parse().unwrap()))]
+#[case(SbatchOption::MemPerGPU("0".parse().unwrap()))]
 #[case(SbatchOption::MinCPUs("test".to_string()))]
 #[case(SbatchOption::Network("test".to_string()))]
-#[case(SbatchOption::Nice(Some("test".to_string())))]
+#[case(SbatchOption::Nice(Some(-100)))]
 #[case(SbatchOption::Nice(None))]
+#[case(SbatchOption::Nice(Some(2147483645)))]
+#[case(SbatchOption::Nice(Some(-2147483645)))]
 #[case(SbatchOption::NoKill(Some("test".to_string())))]
 #[case(SbatchOption::NoKill(None))]
 #[case(SbatchOption::NoRequeue)]
 #[case(SbatchOption::NodeFile("test".to_string()))]
-#[case(SbatchOption::NodeList("test".to_string()))]
+#[case(SbatchOption::NodeList("test".parse().unwrap()))]
 #[case(SbatchOption::Nodes("test".to_string()))]
-#[case(SbatchOption::NTasks("test".to_string()))]
+#[case(SbatchOption::NTasks("4".parse().unwrap()))]
 #[case(SbatchOption::NTasksPerCore("test".to_string()))]
 #[case(SbatchOption::NTasksPerGPU("test".to_string()))]
-#[case(SbatchOption::NTasksPerNode("test".to_string()))]
+#[case(SbatchOption::NTasksPerNode("4".parse().unwrap()))]
 #[case(SbatchOption::NTasksPerSocket("test".to_string()))]
 #[case(SbatchOption::OOMKillStep(Some("test".to_string())))]
 #[case(SbatchOption::OOMKillStep(None))]
-#[case(SbatchOption::OpenMode("test".to_string()))]
+#[case(SbatchOption::OpenMode(OpenMode::Append))]
 #[case(SbatchOption::Output("test".to_string()))]
 #[case(SbatchOption::Overcommit)]
 #[case(SbatchOption::Oversubscribe)]
@@ -90,22 +95,23 @@ use sbatch_rs::SbatchOption;
 #[case(SbatchOption::Propagate(None))]
 #[case(SbatchOption::Qos("test".to_string()))]
 #[case(SbatchOption::Quiet)]
+#[case(SbatchOption::Raw("--newfangled-flag=x".to_string()))]
 #[case(SbatchOption::Reboot)]
 #[case(SbatchOption::Requeue)]
 #[case(SbatchOption::Reservation("test".to_string()))]
 #[case(SbatchOption::ResvPorts(Some("test".to_string())))]
 #[case(SbatchOption::ResvPorts(None))]
 #[case(SbatchOption::Segment("test".to_string()))]
-#[case(SbatchOption::Signal("test".to_string()))]
-#[case(SbatchOption::SocketsPerNode("test".to_string()))]
+#[case(SbatchOption::Signal("B:USR1@60".parse().unwrap()))]
+#[case(SbatchOption::SocketsPerNode("2".parse().unwrap()))]
 #[case(SbatchOption::SpreadJob)]
 #[case(SbatchOption::Stepmgr)]
 #[case(SbatchOption::Switches("test".to_string()))]
 #[case(SbatchOption::TestOnly)]
 #[case(SbatchOption::ThreadSpec("test".to_string()))]
 #[case(SbatchOption::ThreadsPerCore("test".to_string()))]
-#[case(SbatchOption::Time("test".to_string()))]
-#[case(SbatchOption::TimeMin("test".to_string()))]
+#[case(SbatchOption::Time("1:30:00".parse().unwrap()))]
+#[case(SbatchOption::TimeMin("30".parse().unwrap()))]
 #[case(SbatchOption::Tmp("test".to_string()))]
 #[case(SbatchOption::TresBind("test".to_string()))]
 #[case(SbatchOption::TresPerTask("test".to_string()))]
@@ -115,7 +121,8 @@ use sbatch_rs::SbatchOption;
 #[case(SbatchOption::Verbose)]
 #[case(SbatchOption::Version)]
 #[case(SbatchOption::Wait)]
-#[case(SbatchOption::WaitAllNodes("test".to_string()))]
+#[case(SbatchOption::WaitAllNodes("0".to_string()))]
+#[case(SbatchOption::WaitAllNodes("1".to_string()))]
 #[case(SbatchOption::WCKey("test".to_string()))]
 #[case(SbatchOption::Wrap("test".to_string()))]
 fn test_sbatch_option_validate(#[case] option: SbatchOption) {
@@ -129,12 +136,93 @@ fn test_sbatch_option_validate(#[case] option: SbatchOption) {
 #[case(SbatchOption::Account("test  ".to_string()))]
 #[case(SbatchOption::Account("  test".to_string()))]
 #[case(SbatchOption::Account("  test  ".to_string()))]
-#[case(SbatchOption::Nice(Some("".to_string())))]
-#[case(SbatchOption::Nice(Some(" ".to_string())))]
-#[case(SbatchOption::Nice(Some("  ".to_string())))]
-#[case(SbatchOption::Nice(Some("  test".to_string())))]
-#[case(SbatchOption::Nice(Some("test  ".to_string())))]
-#[case(SbatchOption::Nice(Some("  test  ".to_string())))]
+#[case(SbatchOption::Raw("".to_string()))]
+#[case(SbatchOption::Raw(" --newfangled-flag ".to_string()))]
+#[case(SbatchOption::Comment("first\nsecond".to_string()))]
+#[case(SbatchOption::Comment("first\rsecond".to_string()))]
 fn test_sbatch_option_validate_error(#[case] option: SbatchOption) {
     assert!(option.validate().is_err());
 }
+
+#[rstest]
+#[case(2147483646)]
+#[case(-2147483646)]
+#[case(i32::MAX)]
+#[case(i32::MIN)]
+fn test_sbatch_option_validate_nice_out_of_range(#[case] value: i32) {
+    assert!(matches!(
+        SbatchOption::Nice(Some(value)).validate(),
+        Err(SbatchOptionError::NiceOutOfRange(v)) if v == value
+    ));
+}
+
+#[rstest]
+#[case("bogus")]
+#[case("")]
+#[case("USER")]
+#[case(" user")]
+fn test_sbatch_option_validate_exclusive_invalid(#[case] value: &str) {
+    assert!(matches!(
+        SbatchOption::Exclusive(Some(value.to_string())).validate(),
+        Err(SbatchOptionError::InvalidExclusiveValue(v)) if v == value
+    ));
+}
+
+#[rstest]
+#[case("yes")]
+#[case("true")]
+#[case("")]
+#[case("01")]
+#[case(" 1")]
+fn test_sbatch_option_validate_wait_all_nodes_invalid(#[case] value: &str) {
+    assert!(matches!(
+        SbatchOption::WaitAllNodes(value.to_string()).validate(),
+        Err(SbatchOptionError::InvalidWaitAllNodes(v)) if v == value
+    ));
+}
+
+#[rstest]
+#[case("; rm -rf ~")]
+#[case("newfangled-flag")]
+#[case("--")]
+#[case("--x;rm=1")]
+#[case("--$(id)")]
+#[case("-Z|cat")]
+fn test_sbatch_option_validate_raw_invalid(#[case] value: &str) {
+    assert!(matches!(
+        SbatchOption::Raw(value.to_string()).validate(),
+        Err(SbatchOptionError::InvalidRawOption(v)) if v == value
+    ));
+}
+
+#[rstest]
+#[case(SbatchOption::CoresPerSocket(NonZeroU32::MIN), "--cores-per-socket=1")]
+#[case(
+    SbatchOption::CoresPerSocket(NonZeroU32::MAX),
+    "--cores-per-socket=4294967295"
+)]
+#[case(SbatchOption::SocketsPerNode(NonZeroU32::MIN), "--sockets-per-node=1")]
+#[case(SbatchOption::NTasks(NonZeroU32::MIN), "--ntasks=1")]
+#[case(SbatchOption::NTasks(NonZeroU32::MAX), "--ntasks=4294967295")]
+#[case(SbatchOption::NTasksPerNode(NonZeroU32::MIN), "--ntasks-per-node=1")]
+#[case(
+    SbatchOption::SocketsPerNode(NonZeroU32::MAX),
+    "--sockets-per-node=4294967295"
+)]
+fn test_sbatch_option_validate_non_zero_counts(
+    #[case] option: SbatchOption,
+    #[case] display: &str,
+) {
+    assert!(option.validate().is_ok());
+    assert_eq!(option.to_string(), display);
+    assert_eq!(display.parse::<SbatchOption>().unwrap(), option);
+}
+
+#[test]
+fn test_sbatch_option_validate_core_spec_zero() {
+    // With `AllowSpecResourcesUsage`, `--core-spec=0` lets the job use the specialized cores
+    let option: SbatchOption = "--core-spec=0".parse().unwrap();
+    assert_eq!(option, SbatchOption::CoreSpec(0));
+    assert!(option.validate().is_ok());
+    assert_eq!(option.to_string(), "--core-spec=0");
+}
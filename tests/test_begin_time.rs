@@ -0,0 +1,50 @@
+#![cfg(feature = "time")]
+
+use rstest::rstest;
+use sbatch_rs::BeginTime;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const HOUR: u64 = 3600;
+const DAY: u64 = 24 * HOUR;
+
+// Helper function to get the time a number of seconds after the epoch
+fn at(seconds: u64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_secs(seconds)
+}
+
+#[rstest]
+#[case("now", at(10 * DAY + 18 * HOUR))]
+#[case("now+90", at(10 * DAY + 18 * HOUR + 90))]
+#[case("now+2hours", at(10 * DAY + 20 * HOUR))]
+#[case("now+1week", at(17 * DAY + 18 * HOUR))]
+#[case("midnight", at(11 * DAY))]
+#[case("noon", at(11 * DAY + 12 * HOUR))]
+#[case("teatime", at(11 * DAY + 16 * HOUR))]
+#[case("today", at(10 * DAY))]
+#[case("tomorrow", at(11 * DAY))]
+#[case("elevenses", at(11 * DAY + 11 * HOUR))]
+#[case("fika", at(11 * DAY + 15 * HOUR))]
+#[case("07:30 PM", at(10 * DAY + 19 * HOUR + 30 * 60))]
+#[case("7:30 PM", at(10 * DAY + 19 * HOUR + 30 * 60))]
+#[case("12:00AM", at(11 * DAY))]
+#[case("12:00 PM", at(11 * DAY + 12 * HOUR))]
+#[case("19:30", at(10 * DAY + 19 * HOUR + 30 * 60))]
+#[case("18:00", at(11 * DAY + 18 * HOUR))]
+#[case("1970-01-02T01:02:03", at(DAY + HOUR + 2 * 60 + 3))]
+#[case("2000-03-01", at(11017 * DAY))]
+#[case("030100", at(11017 * DAY))]
+#[case("03/01/00", at(11017 * DAY))]
+#[case("01/20", at(19 * DAY))]
+#[case("0111", at(10 * DAY))]
+#[case("01/05", at(369 * DAY))]
+fn test_begin_time_resolve(#[case] input: &str, #[case] expected: SystemTime) {
+    // 1970-01-11T18:00:00
+    let now = at(10 * DAY + 18 * HOUR);
+    assert_eq!(input.parse::<BeginTime>().unwrap().resolve(now), expected);
+}
+
+#[test]
+fn test_begin_time_resolve_before_epoch() {
+    let begin: BeginTime = "1969-12-31T23:00".parse().unwrap();
+    assert_eq!(begin.resolve(at(0)), UNIX_EPOCH - Duration::from_secs(HOUR));
+}
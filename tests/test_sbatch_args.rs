@@ -0,0 +1,68 @@
+#![cfg(feature = "clap")]
+
+use clap::Parser;
+use rstest::rstest;
+use sbatch_rs::{Sbatch, SbatchArgs, SbatchError};
+
+#[test]
+fn test_try_from_all_options() {
+    let args = SbatchArgs::parse_from([
+        "submit",
+        "--partition=gpu",
+        "--ntasks=4",
+        "--cpus-per-task=2",
+        "--mem=8G",
+        "--time=2-00:00:00",
+        "--job-name=test",
+        "--output=test.out",
+        "--error=test.err",
+        "--dependency=afterok:1234",
+        "test.sh",
+        "--input",
+        "data.txt",
+    ]);
+    assert_eq!(
+        Sbatch::try_from(args).unwrap().build().unwrap(),
+        "sbatch --cpus-per-task=2 --dependency=afterok:1234 --error=test.err --job-name=test --mem=8G \
+         --ntasks=4 --output=test.out --partition=gpu --time=2-00:00:00 test.sh --input data.txt"
+    );
+}
+
+#[test]
+fn test_try_from_short_flags() {
+    let args = SbatchArgs::parse_from([
+        "submit", "-p", "gpu", "-n", "4", "-c", "2", "-t", "10", "-J", "test", "-o", "test.out",
+        "-e", "test.err", "-d", "after:1",
+    ]);
+    assert_eq!(
+        Sbatch::try_from(args).unwrap().build().unwrap(),
+        "sbatch --cpus-per-task=2 --dependency=after:1 --error=test.err --job-name=test --ntasks=4 \
+         --output=test.out --partition=gpu --time=0-00:10:00"
+    );
+}
+
+#[rstest]
+#[case(&["submit", "--mem=lots", "test.sh"])]
+#[case(&["submit", "--time=soon", "test.sh"])]
+#[case(&["submit", "--partition=", "test.sh"])]
+fn test_try_from_invalid_option(#[case] argv: &[&str]) {
+    let args = SbatchArgs::parse_from(argv);
+    assert!(matches!(
+        Sbatch::try_from(args),
+        Err(SbatchError::SbatchOptionError(_))
+    ));
+}
+
+#[test]
+fn test_try_from_invalid_dependency() {
+    let args = SbatchArgs::parse_from(["submit", "--dependency=afterfoo:1", "test.sh"]);
+    assert!(matches!(
+        Sbatch::try_from(args),
+        Err(SbatchError::DependencyError(_))
+    ));
+}
+
+#[test]
+fn test_parse_rejects_zero_ntasks() {
+    assert!(SbatchArgs::try_parse_from(["submit", "--ntasks=0"]).is_err());
+}
@@ -1,6 +1,6 @@
 use rstest::rstest;
-use sbatch_rs::Sbatch;
 use sbatch_rs::SbatchOption;
+use sbatch_rs::{OrderMode, Sbatch, SbatchError};
 
 #[test]
 fn test_new_and_default() {
@@ -51,3 +51,243 @@ fn test_build_error_empty() {
     let sbatch = Sbatch::new().build();
     assert!(sbatch.is_err());
 }
+
+#[test]
+fn test_try_build() {
+    let command = Sbatch::try_build(
+        vec![
+            SbatchOption::JobName("test".to_string()),
+            SbatchOption::Output("test.out".to_string()),
+            SbatchOption::Error("test.err".to_string()),
+            SbatchOption::Exclusive(None),
+        ],
+        Some("test.sh arg".to_string()),
+    );
+    assert_eq!(
+        command.unwrap(),
+        "sbatch --error=test.err --exclusive --job-name=test --output=test.out test.sh arg"
+    );
+
+    let command = Sbatch::try_build([SbatchOption::Wrap("echo hello".to_string())], None);
+    assert_eq!(command.unwrap(), "sbatch --wrap='echo hello'");
+}
+
+#[test]
+fn test_try_build_errors() {
+    assert!(matches!(
+        Sbatch::try_build(
+            [SbatchOption::Exclusive(None), SbatchOption::Oversubscribe,],
+            Some("test.sh".to_string()),
+        ),
+        Err(SbatchError::ConflictingOptions(..))
+    ));
+    assert!(matches!(
+        Sbatch::try_build([SbatchOption::JobName("".to_string())], None),
+        Err(SbatchError::SbatchOptionError(_))
+    ));
+    assert!(matches!(
+        Sbatch::try_build(Vec::new(), None),
+        Err(SbatchError::NoOptionsOrScript)
+    ));
+}
+
+#[test]
+fn test_collect_and_iterate() {
+    let options = vec![
+        SbatchOption::Partition("batch".to_string()),
+        SbatchOption::JobName("test".to_string()),
+        SbatchOption::Partition("gpu".to_string()),
+    ];
+    let sbatch: Sbatch = options.into_iter().collect();
+    assert_eq!(sbatch.len(), 2);
+    assert!(!sbatch.is_empty());
+
+    let borrowed: Vec<_> = (&sbatch).into_iter().cloned().collect();
+    let owned: Vec<_> = sbatch.into_iter().collect();
+    assert_eq!(owned, borrowed);
+    assert_eq!(
+        owned,
+        vec![
+            SbatchOption::JobName("test".to_string()),
+            SbatchOption::Partition("gpu".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_collect_empty() {
+    let sbatch: Sbatch = Vec::new().into_iter().collect();
+    assert_eq!(sbatch.len(), 0);
+    assert!(sbatch.is_empty());
+    assert_eq!(sbatch.into_iter().count(), 0);
+}
+
+#[test]
+fn test_collect_invalid_option() {
+    let sbatch: Sbatch = [SbatchOption::JobName("".to_string())]
+        .into_iter()
+        .collect();
+    assert!(matches!(
+        sbatch.build(),
+        Err(SbatchError::SbatchOptionError(_))
+    ));
+}
+
+#[test]
+fn test_consuming_option_and_script() {
+    let sbatch = Sbatch::new()
+        .option(SbatchOption::JobName("test".to_string()))
+        .unwrap()
+        .option(SbatchOption::Output("test.out".to_string()))
+        .unwrap()
+        .script("test.sh".to_string())
+        .unwrap();
+    assert_eq!(
+        sbatch.build().unwrap(),
+        "sbatch --job-name=test --output=test.out test.sh"
+    );
+
+    // The builder can be moved into a closure
+    let build = move || sbatch.build();
+    assert!(build().is_ok());
+}
+
+#[test]
+fn test_consuming_option_and_script_errors() {
+    assert!(matches!(
+        Sbatch::new().option(SbatchOption::JobName("".to_string())),
+        Err(SbatchError::SbatchOptionError(_))
+    ));
+    assert!(matches!(
+        Sbatch::new().script("".to_string()),
+        Err(SbatchError::ScriptEmpty)
+    ));
+    assert!(matches!(
+        Sbatch::new()
+            .option(SbatchOption::Wrap("echo".to_string()))
+            .unwrap()
+            .script("test.sh".to_string()),
+        Err(SbatchError::WrapAndScript)
+    ));
+}
+
+#[test]
+fn test_eq_ignores_insertion_order_when_sorted() {
+    let mut a = Sbatch::new();
+    a.add_option(SbatchOption::JobName("test".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::Partition("gpu".to_string()))
+        .unwrap()
+        .set_script("test.sh".to_string())
+        .unwrap();
+
+    let b: Sbatch = "sbatch --partition=gpu --job-name=test test.sh"
+        .parse()
+        .unwrap();
+    assert_eq!(a, b);
+    assert_eq!(a.build().unwrap(), b.build().unwrap());
+}
+
+#[test]
+fn test_eq_differences() {
+    let base = Sbatch::new()
+        .option(SbatchOption::JobName("test".to_string()))
+        .unwrap();
+
+    // A removed option leaves the builder equal to one that never had it
+    let mut removed = base
+        .clone()
+        .option(SbatchOption::Partition("gpu".to_string()))
+        .unwrap();
+    removed.remove_option(&SbatchOption::Partition(String::new()));
+    assert_eq!(removed, base);
+
+    assert_ne!(
+        base,
+        base.clone()
+            .option(SbatchOption::Partition("gpu".to_string()))
+            .unwrap()
+    );
+    assert_ne!(base, base.clone().script("test.sh".to_string()).unwrap());
+    assert_ne!(Sbatch::new(), Sbatch::new().set_script_stdin().clone());
+}
+
+#[test]
+fn test_eq_respects_insertion_order() {
+    let build = |first: SbatchOption, second: SbatchOption| {
+        let mut sbatch = Sbatch::new();
+        sbatch
+            .with_order(OrderMode::InsertionOrder)
+            .add_option(first)
+            .unwrap()
+            .add_option(second)
+            .unwrap();
+        sbatch
+    };
+    let job_name = SbatchOption::JobName("test".to_string());
+    let partition = SbatchOption::Partition("gpu".to_string());
+    assert_eq!(
+        build(job_name.clone(), partition.clone()),
+        build(job_name.clone(), partition.clone())
+    );
+    assert_ne!(
+        build(job_name.clone(), partition.clone()),
+        build(partition, job_name)
+    );
+}
+
+#[test]
+fn test_canonical_string_ignores_insertion_order() {
+    let build = |options: Vec<SbatchOption>| {
+        let mut sbatch = Sbatch::new();
+        sbatch.with_order(OrderMode::InsertionOrder);
+        for option in options {
+            sbatch.add_option(option).unwrap();
+        }
+        sbatch.set_script("test.sh arg".to_string()).unwrap();
+        sbatch
+    };
+    let a = build(vec![
+        SbatchOption::JobName("test job".to_string()),
+        SbatchOption::Partition("gpu".to_string()),
+        SbatchOption::Dependency("afterok:2:1".to_string()),
+    ]);
+    let b = build(vec![
+        SbatchOption::Dependency("afterok:1,afterok:2".to_string()),
+        SbatchOption::Partition("gpu".to_string()),
+        SbatchOption::JobName("test job".to_string()),
+    ]);
+    assert_ne!(a, b);
+    assert_eq!(a.canonical_string(), b.canonical_string());
+    assert_eq!(
+        a.canonical_string(),
+        "--dependency=afterok:1,afterok:2\n--job-name='test job'\n--partition=gpu\n-- test.sh arg"
+    );
+}
+
+#[test]
+fn test_canonical_string_differences() {
+    let mut het = Sbatch::new();
+    het.add_option(SbatchOption::Partition("cpu".to_string()))
+        .unwrap()
+        .add_het_group()
+        .add_option(SbatchOption::Partition("gpu".to_string()))
+        .unwrap();
+    assert_eq!(
+        het.canonical_string(),
+        "--partition=cpu\n:\n--partition=gpu"
+    );
+
+    let mut stdin = Sbatch::new();
+    stdin.set_script_stdin();
+    assert_eq!(stdin.canonical_string(), "-- -");
+    assert_eq!(Sbatch::new().canonical_string(), "");
+
+    let a = Sbatch::new()
+        .option(SbatchOption::JobName("a".to_string()))
+        .unwrap();
+    let b = Sbatch::new()
+        .option(SbatchOption::JobName("b".to_string()))
+        .unwrap();
+    assert_ne!(a.canonical_string(), b.canonical_string());
+}
@@ -0,0 +1,142 @@
+use sbatch_rs::{Dependency, DependencyError, DependencyType, Sbatch, SbatchError, SbatchOption};
+
+#[test]
+fn test_set_dependency() {
+    let mut dependency = Dependency::new_and();
+    dependency
+        .push_after_ok("1")
+        .unwrap()
+        .push_after_any("2")
+        .unwrap();
+
+    let sbatch = Sbatch::new()
+        .set_dependency(dependency)
+        .unwrap()
+        .set_script("test.sh".to_string())
+        .unwrap()
+        .build();
+    assert_eq!(
+        sbatch.unwrap(),
+        "sbatch --dependency=afterany:2,afterok:1 test.sh"
+    );
+}
+
+#[test]
+fn test_set_dependency_replaces_existing() {
+    let mut dependency = Dependency::new_and();
+    dependency.push_after_ok("2").unwrap();
+
+    let sbatch = Sbatch::new()
+        .add_option(SbatchOption::Dependency("afterok:1".to_string()))
+        .unwrap()
+        .set_dependency(dependency)
+        .unwrap()
+        .build();
+    assert_eq!(sbatch.unwrap(), "sbatch --dependency=afterok:2");
+}
+
+#[test]
+fn test_set_dependency_empty() {
+    assert!(matches!(
+        Sbatch::new().set_dependency(Dependency::new_and()),
+        Err(SbatchError::DependencyError(
+            DependencyError::NoDependencies
+        ))
+    ));
+}
+
+#[test]
+fn test_add_dependency_after() {
+    let sbatch = Sbatch::new()
+        .add_dependency_after("1")
+        .unwrap()
+        .add_dependency_after("2")
+        .unwrap()
+        .add_dependency_after("2")
+        .unwrap()
+        .build();
+    assert_eq!(sbatch.unwrap(), "sbatch --dependency=after:1,after:2");
+}
+
+#[test]
+fn test_add_dependency_after_keeps_separator() {
+    let sbatch = Sbatch::new()
+        .add_option(SbatchOption::Dependency("afterok:1?afterok:2".to_string()))
+        .unwrap()
+        .add_dependency_after("3")
+        .unwrap()
+        .build();
+    assert_eq!(
+        sbatch.unwrap(),
+        "sbatch --dependency='after:3?afterok:1?afterok:2'"
+    );
+}
+
+#[test]
+fn test_add_dependency_after_invalid_job_id() {
+    let mut sbatch = Sbatch::new();
+    assert!(matches!(
+        sbatch.add_dependency_after(" 1"),
+        Err(SbatchError::DependencyError(_))
+    ));
+    assert!(matches!(
+        sbatch.add_dependency_after(""),
+        Err(SbatchError::DependencyError(_))
+    ));
+    assert_eq!(sbatch.options().count(), 0);
+}
+
+#[test]
+fn test_add_dependency_after_invalid_existing() {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(SbatchOption::Dependency("afterfoo:1".to_string()))
+        .unwrap();
+    assert!(matches!(
+        sbatch.add_dependency_after("2"),
+        Err(SbatchError::DependencyError(
+            DependencyError::InvalidDependency(_)
+        ))
+    ));
+}
+
+#[test]
+fn test_add_dependency_incrementally() {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_dependency(DependencyType::AfterOk("1".to_string()))
+        .unwrap();
+    assert_eq!(sbatch.build().unwrap(), "sbatch --dependency=afterok:1");
+
+    sbatch
+        .add_dependency(DependencyType::AfterTimeDelay(
+            "2".to_string(),
+            "10".to_string(),
+        ))
+        .unwrap();
+    assert_eq!(
+        sbatch.build().unwrap(),
+        "sbatch --dependency=after:2+10,afterok:1"
+    );
+}
+
+#[test]
+fn test_add_dependency_keeps_separator() {
+    let mut dependency = Dependency::new_or();
+    dependency
+        .push_after_ok("1")
+        .unwrap()
+        .push_after_ok("2")
+        .unwrap();
+
+    let sbatch = Sbatch::new()
+        .set_dependency(dependency)
+        .unwrap()
+        .add_dependency(DependencyType::AfterNotOk("3".to_string()))
+        .unwrap()
+        .build();
+    assert_eq!(
+        sbatch.unwrap(),
+        "sbatch --dependency='afternotok:3?afterok:1?afterok:2'"
+    );
+}
@@ -0,0 +1,99 @@
+use rstest::rstest;
+use sbatch_rs::{Sbatch, SbatchError, SbatchOption};
+
+#[rstest]
+#[case(SbatchOption::Partition("general".to_string()), "SBATCH_PARTITION", "general")]
+#[case(SbatchOption::JobName("test job".to_string()), "SBATCH_JOB_NAME", "test job")]
+#[case(SbatchOption::Mem("4G".parse().unwrap()), "SBATCH_MEM_PER_NODE", "4G")]
+#[case(SbatchOption::Time("1:00:00".parse().unwrap()), "SBATCH_TIMELIMIT", "0-01:00:00")]
+#[case(SbatchOption::Requeue, "SBATCH_REQUEUE", "1")]
+fn test_option_to_env(#[case] option: SbatchOption, #[case] name: &str, #[case] value: &str) {
+    assert_eq!(option.to_env(), Some((name.to_string(), value.to_string())));
+}
+
+#[rstest]
+#[case(SbatchOption::Hold)]
+#[case(SbatchOption::Exclusive(None))]
+#[case(SbatchOption::Raw("--partition=general".to_string()))]
+fn test_option_to_env_none(#[case] option: SbatchOption) {
+    assert_eq!(option.to_env(), None);
+}
+
+#[test]
+fn test_sbatch_to_env_active_component() {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(SbatchOption::Partition("cpu".to_string()))
+        .unwrap()
+        .add_het_group()
+        .add_option(SbatchOption::Partition("gpu".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::Hold)
+        .unwrap()
+        .set_script("test.sh".to_string())
+        .unwrap();
+    assert_eq!(
+        sbatch.to_env(),
+        vec![("SBATCH_PARTITION".to_string(), "gpu".to_string())]
+    );
+    assert!(Sbatch::new().to_env().is_empty());
+}
+
+#[test]
+fn test_from_env_vars() {
+    let vars = [
+        ("SBATCH_ACCOUNT", "lab"),
+        ("SBATCH_PARTITION", "general"),
+        ("SBATCH_TIMELIMIT", "1:00:00"),
+        ("SBATCH_REQUEUE", "yes"),
+        ("SBATCH_NOT_A_VARIABLE", "x"),
+        ("PATH", "/usr/bin"),
+    ];
+    let sbatch = Sbatch::from_env_vars(vars).unwrap();
+    assert_eq!(
+        sbatch.build().unwrap(),
+        "sbatch --account=lab --partition=general --requeue --time=0-01:00:00"
+    );
+}
+
+#[test]
+fn test_from_env_vars_round_trip() {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(SbatchOption::JobName("test job".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::Mem("4G".parse().unwrap()))
+        .unwrap()
+        .add_option(SbatchOption::Overcommit)
+        .unwrap();
+    assert_eq!(Sbatch::from_env_vars(sbatch.to_env()).unwrap(), sbatch);
+}
+
+#[test]
+fn test_from_env_vars_invalid_value() {
+    let result = Sbatch::from_env_vars([("SBATCH_TIMELIMIT", "soon")]);
+    assert!(matches!(
+        result,
+        Err(SbatchError::InvalidEnvVar { name, .. }) if name == "SBATCH_TIMELIMIT"
+    ));
+}
+
+#[test]
+fn test_from_env_layers_explicit_options() {
+    // SAFETY: No other test in this binary reads or writes the environment
+    unsafe { std::env::set_var("SBATCH_QOS", "sbatch-rs-test-qos") };
+    let mut sbatch = Sbatch::from_env().unwrap();
+    unsafe { std::env::remove_var("SBATCH_QOS") };
+
+    assert_eq!(
+        sbatch.get_option(&SbatchOption::Qos(String::new())),
+        Some(&SbatchOption::Qos("sbatch-rs-test-qos".to_string()))
+    );
+    sbatch
+        .overwrite_option(SbatchOption::Qos("high".to_string()))
+        .unwrap();
+    assert_eq!(
+        sbatch.get_option(&SbatchOption::Qos(String::new())),
+        Some(&SbatchOption::Qos("high".to_string()))
+    );
+}
@@ -0,0 +1,235 @@
+use sbatch_rs::{ParseOptions, Sbatch, SbatchError, SbatchOption, UnknownPolicy};
+
+#[test]
+fn test_from_script() {
+    let contents = "\
+#!/bin/bash
+#SBATCH --job-name=test
+#SBATCH   --mem=4G
+#SBATCH -o test.out
+# A regular comment
+
+srun hostname
+#SBATCH --error=ignored.err
+";
+    let sbatch = Sbatch::from_script(contents).unwrap();
+
+    let expected = Sbatch::new()
+        .add_option(SbatchOption::JobName("test".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::Mem("4G".parse().unwrap()))
+        .unwrap()
+        .add_option(SbatchOption::Output("test.out".to_string()))
+        .unwrap()
+        .set_script("srun hostname\n#SBATCH --error=ignored.err".to_string())
+        .unwrap()
+        .clone();
+
+    assert_eq!(sbatch, expected);
+    assert!(matches!(sbatch.build(), Err(SbatchError::MultiLineScript)));
+}
+
+#[test]
+fn test_from_script_short_and_long_forms() {
+    let long = Sbatch::from_script("#SBATCH --job-name=name\necho hi").unwrap();
+    let short = Sbatch::from_script("#SBATCH -J name\necho hi").unwrap();
+    assert_eq!(long, short);
+    assert_eq!(long.to_directives(), vec!["#SBATCH --job-name=name"]);
+}
+
+#[test]
+fn test_from_script_round_trip() {
+    let sbatch = Sbatch::new()
+        .add_option(SbatchOption::JobName("test".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::Wrap("echo hello".to_string()))
+        .unwrap()
+        .clone();
+    let script = sbatch.to_script("#!/bin/bash").unwrap();
+
+    let parsed = Sbatch::from_script(&script).unwrap();
+    assert_eq!(parsed.build().unwrap(), sbatch.build().unwrap());
+}
+
+#[test]
+fn test_from_script_het_job_round_trip() {
+    let sbatch = Sbatch::new()
+        .add_option(SbatchOption::JobName("test".to_string()))
+        .unwrap()
+        .add_het_group()
+        .add_option(SbatchOption::NTasks("4".parse().unwrap()))
+        .unwrap()
+        .set_script("srun hostname".to_string())
+        .unwrap()
+        .clone();
+    let script = sbatch.to_script("#!/bin/bash").unwrap();
+    assert_eq!(
+        script,
+        "#!/bin/bash\n#SBATCH --job-name=test\n#SBATCH hetjob\n#SBATCH --ntasks=4\n\nsrun hostname\n"
+    );
+
+    let parsed = Sbatch::from_script(&script).unwrap();
+    assert_eq!(parsed, sbatch);
+    assert_eq!(parsed.to_script("#!/bin/bash").unwrap(), script);
+}
+
+#[test]
+fn test_from_script_invalid_directive() {
+    let contents = "#!/bin/bash\n#SBATCH --job-name=test\n#SBATCH --bogus=1\nsrun hostname\n";
+    match Sbatch::from_script(contents) {
+        Err(SbatchError::InvalidDirective {
+            line, directive, ..
+        }) => {
+            assert_eq!(line, 3);
+            assert_eq!(directive, "--bogus=1");
+        }
+        other => panic!("unexpected result: {other:?}"),
+    }
+}
+
+#[test]
+fn test_from_script_empty() {
+    let sbatch = Sbatch::from_script("#!/bin/bash\n\n").unwrap();
+    assert!(matches!(
+        sbatch.build(),
+        Err(SbatchError::NoOptionsOrScript)
+    ));
+}
+
+#[test]
+fn test_from_heredoc_round_trip() {
+    let sbatch = Sbatch::new()
+        .add_option(SbatchOption::JobName("test".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::Partition("general".to_string()))
+        .unwrap()
+        .set_script("module load foo\nsrun ./a.out".to_string())
+        .unwrap()
+        .clone();
+    let script = sbatch.to_script("#!/bin/bash").unwrap();
+
+    let parsed = Sbatch::from_heredoc(&script).unwrap();
+    assert_eq!(parsed.to_script("#!/bin/bash").unwrap(), script);
+}
+
+#[test]
+fn test_from_heredoc_round_trip_wrap_and_het_job() {
+    let sbatch = Sbatch::new()
+        .add_option(SbatchOption::Wrap("echo \"hi\"; hostname".to_string()))
+        .unwrap()
+        .add_het_group()
+        .add_option(SbatchOption::NTasks("2".parse().unwrap()))
+        .unwrap()
+        .clone();
+    let script = sbatch.to_script("#!/bin/bash").unwrap();
+
+    assert_eq!(Sbatch::from_heredoc(&script).unwrap(), sbatch);
+}
+
+#[test]
+fn test_from_heredoc_drops_leading_body_comments() {
+    let sbatch = Sbatch::new()
+        .add_option(SbatchOption::JobName("test".to_string()))
+        .unwrap()
+        .set_script("# setup\nsrun hostname".to_string())
+        .unwrap()
+        .clone();
+    let script = sbatch.to_script("#!/bin/bash").unwrap();
+
+    let parsed = Sbatch::from_heredoc(&script).unwrap();
+    assert_eq!(
+        parsed.to_script("#!/bin/bash").unwrap(),
+        "#!/bin/bash\n#SBATCH --job-name=test\n\nsrun hostname\n"
+    );
+}
+
+#[test]
+fn test_from_heredoc_indented() {
+    let heredoc = "
+        #SBATCH --job-name=test
+        #SBATCH -n 4
+
+        srun hostname
+    ";
+    let sbatch = Sbatch::from_heredoc(heredoc).unwrap();
+    assert_eq!(
+        sbatch.to_script("#!/bin/bash").unwrap(),
+        "#!/bin/bash\n#SBATCH --job-name=test\n#SBATCH --ntasks=4\n\nsrun hostname\n"
+    );
+}
+
+const NEWER_SLURM_SCRIPT: &str = "\
+#!/bin/bash
+#SBATCH --job-name=test
+#SBATCH --newfangled-flag=x
+#SBATCH -Z
+#SBATCH --ntasks=4
+
+srun hostname
+";
+
+#[test]
+fn test_from_script_with_unknown_policy() {
+    let options = |on_unknown| ParseOptions {
+        on_unknown,
+        ..ParseOptions::strict()
+    };
+
+    assert!(matches!(
+        Sbatch::from_script_with(NEWER_SLURM_SCRIPT, &options(UnknownPolicy::Error)),
+        Err(SbatchError::InvalidDirective { line: 3, .. })
+    ));
+
+    let (sbatch, unknown) =
+        Sbatch::from_script_with(NEWER_SLURM_SCRIPT, &options(UnknownPolicy::Skip)).unwrap();
+    assert_eq!(
+        sbatch.to_directives(),
+        vec!["#SBATCH --job-name=test", "#SBATCH --ntasks=4"]
+    );
+    assert!(unknown.is_empty());
+
+    let (sbatch, unknown) =
+        Sbatch::from_script_with(NEWER_SLURM_SCRIPT, &options(UnknownPolicy::Collect)).unwrap();
+    assert_eq!(
+        sbatch.to_directives(),
+        vec!["#SBATCH --job-name=test", "#SBATCH --ntasks=4"]
+    );
+    assert_eq!(unknown, vec!["#SBATCH --newfangled-flag=x", "#SBATCH -Z"]);
+
+    let (sbatch, unknown) =
+        Sbatch::from_script_with(NEWER_SLURM_SCRIPT, &options(UnknownPolicy::Passthrough)).unwrap();
+    assert_eq!(
+        sbatch.to_directives(),
+        vec![
+            "#SBATCH -Z",
+            "#SBATCH --job-name=test",
+            "#SBATCH --newfangled-flag=x",
+            "#SBATCH --ntasks=4",
+        ]
+    );
+    assert!(unknown.is_empty());
+}
+
+#[test]
+fn test_from_script_with_invalid_value_is_not_skipped() {
+    let options = ParseOptions {
+        on_unknown: UnknownPolicy::Collect,
+        ..ParseOptions::strict()
+    };
+    assert!(matches!(
+        Sbatch::from_script_with("#SBATCH --mem=4X\n", &options),
+        Err(SbatchError::InvalidDirective { line: 1, .. })
+    ));
+}
+
+#[test]
+fn test_from_script_with_lenient_keeps_unknown_as_raw() {
+    let (sbatch, unknown) =
+        Sbatch::from_script_with(NEWER_SLURM_SCRIPT, &ParseOptions::lenient()).unwrap();
+    assert!(unknown.is_empty());
+    assert!(
+        sbatch
+            .options()
+            .any(|option| *option == SbatchOption::Raw("--newfangled-flag=x".to_string()))
+    );
+}
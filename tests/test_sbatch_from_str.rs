@@ -0,0 +1,165 @@
+use rstest::rstest;
+use sbatch_rs::{
+    CommandParseError, Sbatch, SbatchError, SbatchOption, SbatchOptionError, tokenize_command_line,
+};
+
+// Helper function to convert a slice of string literals into owned arguments
+fn args(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| arg.to_string()).collect()
+}
+
+#[rstest]
+#[case("sbatch test.sh", vec!["sbatch", "test.sh"])]
+#[case("  sbatch   -J  test  ", vec!["sbatch", "-J", "test"])]
+#[case(r#"sbatch --wrap="echo hello""#, vec!["sbatch", "--wrap=echo hello"])]
+#[case(r#"sbatch --wrap="say \"hi\" \\o/""#, vec!["sbatch", r#"--wrap=say "hi" \o/"#])]
+#[case("sbatch --comment='a \"b\" c'", vec!["sbatch", "--comment=a \"b\" c"])]
+#[case(r"sbatch --comment=a\ b", vec!["sbatch", "--comment=a b"])]
+#[case("sbatch ''", vec!["sbatch", ""])]
+#[case("sbatch \\\n    -J test \\\n    test.sh", vec!["sbatch", "-J", "test", "test.sh"])]
+#[case("sbatch --comment=a\\\nb", vec!["sbatch", "--comment=ab"])]
+#[case("sbatch --wrap=\"a\\\nb\"", vec!["sbatch", "--wrap=ab"])]
+fn test_tokenize_command_line(#[case] input: &str, #[case] expected: Vec<&str>) {
+    assert_eq!(tokenize_command_line(input).unwrap(), expected);
+}
+
+#[rstest]
+#[case(r#"sbatch --wrap="echo hello"#, CommandParseError::UnterminatedQuote)]
+#[case("sbatch --comment='a", CommandParseError::UnterminatedQuote)]
+#[case("", CommandParseError::Empty)]
+#[case("   ", CommandParseError::Empty)]
+fn test_tokenize_command_line_error(#[case] input: &str, #[case] expected: CommandParseError) {
+    assert_eq!(tokenize_command_line(input).unwrap_err(), expected);
+}
+
+#[rstest]
+#[case("sbatch test.sh", "sbatch test.sh")]
+#[case(
+    "sbatch -J test -o test.out test.sh",
+    "sbatch --job-name=test --output=test.out test.sh"
+)]
+#[case(
+    "sbatch --job-name test --exclusive test.sh a b",
+    "sbatch --exclusive --job-name=test test.sh a b"
+)]
+#[case(
+    r#"sbatch --job-name=test --wrap="echo hello""#,
+    "sbatch --job-name=test --wrap='echo hello'"
+)]
+#[case("sbatch --mem=4G --hold", "sbatch --hold --mem=4G")]
+#[case(
+    "sbatch -J test -- test.sh -o x",
+    "sbatch --job-name=test test.sh -o x"
+)]
+fn test_sbatch_from_str(#[case] input: &str, #[case] expected: &str) {
+    let sbatch: Sbatch = input.parse().unwrap();
+    assert_eq!(sbatch.build().unwrap(), expected);
+}
+
+#[test]
+fn test_sbatch_from_str_round_trip() {
+    let built = Sbatch::new()
+        .add_option(sbatch_rs::SbatchOption::Wrap(r#"echo "a b""#.to_string()))
+        .unwrap()
+        .add_option(sbatch_rs::SbatchOption::JobName("test".to_string()))
+        .unwrap()
+        .build()
+        .unwrap();
+    let parsed: Sbatch = built.parse().unwrap();
+    assert_eq!(parsed.build().unwrap(), built);
+}
+
+#[rstest]
+#[case("", CommandParseError::Empty)]
+#[case("srun test.sh", CommandParseError::InvalidToken("srun".to_string()))]
+#[case("sbatch - test.sh", CommandParseError::InvalidToken("-".to_string()))]
+#[case("sbatch --wrap='echo", CommandParseError::UnterminatedQuote)]
+fn test_sbatch_from_str_command_error(#[case] input: &str, #[case] expected: CommandParseError) {
+    match input.parse::<Sbatch>() {
+        Err(SbatchError::CommandParseError(error)) => assert_eq!(error, expected),
+        other => panic!("Expected CommandParseError, got {other:?}"),
+    }
+}
+
+#[rstest]
+#[case("sbatch --bogus test.sh")]
+#[case("sbatch --job-name")]
+#[case("sbatch --mem=4X")]
+fn test_sbatch_from_str_option_error(#[case] input: &str) {
+    assert!(matches!(
+        input.parse::<Sbatch>(),
+        Err(SbatchError::SbatchOptionError(
+            SbatchOptionError::UnknownArgument(_)
+                | SbatchOptionError::MissingValue(_)
+                | SbatchOptionError::InvalidMemory(_)
+        ))
+    ));
+}
+
+#[rstest]
+#[case(&["test.sh"], "sbatch test.sh")]
+#[case(&["-Jmyjob", "-c4", "-o", "test.out"], "sbatch --cpus-per-task=4 --job-name=myjob --output=test.out")]
+#[case(&["--job-name=test", "test.sh"], "sbatch --job-name=test test.sh")]
+#[case(&["--job-name", "my job", "test.sh"], "sbatch --job-name='my job' test.sh")]
+#[case(&["-J", "test", "-o", "test.out"], "sbatch --job-name=test --output=test.out")]
+#[case(&["-H", "--exclusive", "test.sh", "a", "b"], "sbatch --exclusive --hold test.sh a b")]
+#[case(&["--wrap", "echo hello"], "sbatch --wrap='echo hello'")]
+#[case(&["-J", "test", "--", "test.sh", "--hold"], "sbatch --job-name=test test.sh --hold")]
+#[case(&["--hold", "--"], "sbatch --hold")]
+fn test_sbatch_from_args(#[case] input: &[&str], #[case] expected: &str) {
+    let sbatch = Sbatch::from_args(&args(input)).unwrap();
+    assert_eq!(sbatch.build().unwrap(), expected);
+}
+
+#[test]
+fn test_sbatch_from_args_matches_from_str() {
+    let input = "sbatch -J test --output test.out --mem=4G -- test.sh arg";
+    let from_str: Sbatch = input.parse().unwrap();
+    let from_args = Sbatch::from_args(&tokenize_command_line(input).unwrap()[1..]).unwrap();
+    assert_eq!(from_args.build().unwrap(), from_str.build().unwrap());
+}
+
+#[rstest]
+#[case(&["-", "test.sh"])]
+#[case(&["--bogus", "test.sh"])]
+#[case(&["--job-name"])]
+#[case(&["-J"])]
+fn test_sbatch_from_args_error(#[case] input: &[&str]) {
+    assert!(Sbatch::from_args(&args(input)).is_err());
+}
+
+#[test]
+fn test_from_str_het_job_round_trip() {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(SbatchOption::NTasks("1".parse().unwrap()))
+        .unwrap()
+        .add_het_group()
+        .add_option(SbatchOption::NTasks("4".parse().unwrap()))
+        .unwrap()
+        .add_option(SbatchOption::Partition("gpu".to_string()))
+        .unwrap()
+        .set_script("test.sh".to_string())
+        .unwrap();
+
+    let command = sbatch.build().unwrap();
+    assert_eq!(
+        command,
+        "sbatch --ntasks=1 : --ntasks=4 --partition=gpu test.sh"
+    );
+    let parsed: Sbatch = command.parse().unwrap();
+    assert_eq!(parsed, sbatch);
+    assert_eq!(parsed.build().unwrap(), command);
+}
+
+#[test]
+fn test_from_args_quotes_script_arguments() {
+    let sbatch =
+        Sbatch::from_args(&args(&["-J", "test", "test.sh", "a b", "it's", "plain"])).unwrap();
+    let command = sbatch.build().unwrap();
+    assert_eq!(
+        command,
+        r#"sbatch --job-name=test test.sh 'a b' 'it'\''s' plain"#
+    );
+    assert_eq!(command.parse::<Sbatch>().unwrap(), sbatch);
+}
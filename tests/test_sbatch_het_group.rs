@@ -0,0 +1,90 @@
+use sbatch_rs::{Sbatch, SbatchError, SbatchOption};
+
+fn het_job() -> Sbatch {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(SbatchOption::NTasks("1".parse().unwrap()))
+        .unwrap()
+        .add_option(SbatchOption::Mem("4G".parse().unwrap()))
+        .unwrap()
+        .add_het_group()
+        .add_option(SbatchOption::NTasks("4".parse().unwrap()))
+        .unwrap()
+        .add_option(SbatchOption::Partition("gpu".to_string()))
+        .unwrap()
+        .set_script("test.sh".to_string())
+        .unwrap();
+    sbatch
+}
+
+#[test]
+fn test_build_het_job() {
+    assert_eq!(
+        het_job().build().unwrap(),
+        "sbatch --mem=4G --ntasks=1 : --ntasks=4 --partition=gpu test.sh"
+    );
+}
+
+#[test]
+fn test_to_script_het_job() {
+    assert_eq!(
+        het_job().to_script("#!/bin/bash").unwrap(),
+        "#!/bin/bash\n#SBATCH --mem=4G\n#SBATCH --ntasks=1\n#SBATCH hetjob\n#SBATCH --ntasks=4\n#SBATCH --partition=gpu\n\ntest.sh\n"
+    );
+}
+
+#[test]
+fn test_het_group_options_apply_to_active_group() {
+    let sbatch = het_job();
+    assert_eq!(
+        sbatch.get_option(&SbatchOption::NTasks("1".parse().unwrap())),
+        Some(&SbatchOption::NTasks("4".parse().unwrap()))
+    );
+    assert_eq!(
+        sbatch.get_option(&SbatchOption::Mem("1".parse().unwrap())),
+        None
+    );
+}
+
+#[test]
+fn test_het_group_without_options_is_ignored() {
+    let sbatch = Sbatch::new()
+        .add_het_group()
+        .add_option(SbatchOption::NTasks("1".parse().unwrap()))
+        .unwrap()
+        .add_het_group()
+        .add_het_group()
+        .add_option(SbatchOption::NTasks("2".parse().unwrap()))
+        .unwrap()
+        .add_het_group()
+        .build();
+    assert_eq!(sbatch.unwrap(), "sbatch --ntasks=1 : --ntasks=2");
+}
+
+#[test]
+fn test_het_group_conflicts_are_per_group() {
+    // The same option in different components is not a conflict
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(SbatchOption::Mem("4G".parse().unwrap()))
+        .unwrap()
+        .add_het_group()
+        .add_option(SbatchOption::MemPerCPU("1G".parse().unwrap()))
+        .unwrap();
+    assert!(sbatch.validate().is_ok());
+
+    // A conflict inside an earlier component is still reported
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(SbatchOption::Exclusive(None))
+        .unwrap()
+        .add_option(SbatchOption::Oversubscribe)
+        .unwrap()
+        .add_het_group()
+        .add_option(SbatchOption::NTasks("4".parse().unwrap()))
+        .unwrap();
+    assert!(matches!(
+        sbatch.build(),
+        Err(SbatchError::ConflictingOptions(_, _))
+    ));
+}
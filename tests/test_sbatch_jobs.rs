@@ -0,0 +1,38 @@
+use sbatch_rs::{Sbatch, SbatchOption, check_unique_job_names};
+
+fn job(name: &str) -> Sbatch {
+    Sbatch::new()
+        .option(SbatchOption::JobName(name.to_string()))
+        .unwrap()
+        .script("test.sh".to_string())
+        .unwrap()
+}
+
+#[test]
+fn test_check_unique_job_names_unique() {
+    assert!(check_unique_job_names(&[job("prepare"), job("train"), job("evaluate")]).is_ok());
+    assert!(check_unique_job_names(&[]).is_ok());
+}
+
+#[test]
+fn test_check_unique_job_names_duplicate() {
+    assert_eq!(
+        check_unique_job_names(&[job("train"), job("prepare"), job("train")]),
+        Err(vec!["train".to_string()])
+    );
+}
+
+#[test]
+fn test_check_unique_job_names_reports_each_name_once() {
+    let jobs = [job("b"), job("a"), job("b"), job("a"), job("b"), job("c")];
+    assert_eq!(
+        check_unique_job_names(&jobs),
+        Err(vec!["b".to_string(), "a".to_string()])
+    );
+}
+
+#[test]
+fn test_check_unique_job_names_ignores_unnamed_jobs() {
+    let unnamed = Sbatch::new().script("test.sh".to_string()).unwrap();
+    assert!(check_unique_job_names(&[unnamed.clone(), unnamed, job("a")]).is_ok());
+}
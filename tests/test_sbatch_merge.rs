@@ -0,0 +1,122 @@
+use rstest::rstest;
+use sbatch_rs::{MergeStrategy, Sbatch, SbatchError, SbatchOption};
+
+fn builder(partition: &str, script: Option<&str>) -> Sbatch {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(SbatchOption::Partition(partition.to_string()))
+        .unwrap();
+    if let Some(script) = script {
+        sbatch.set_script(script.to_string()).unwrap();
+    }
+    sbatch
+}
+
+#[rstest]
+#[case(MergeStrategy::PreferSelf, "sbatch --partition=batch test.sh")]
+#[case(MergeStrategy::PreferOther, "sbatch --partition=gpu test.sh")]
+fn test_merge_overlapping_partition(#[case] strategy: MergeStrategy, #[case] expected: &str) {
+    let mut sbatch = builder("batch", Some("test.sh"));
+    sbatch.merge(builder("gpu", None), strategy).unwrap();
+    assert_eq!(sbatch.build().unwrap(), expected);
+}
+
+#[test]
+fn test_merge_error_on_conflict() {
+    let mut sbatch = builder("batch", Some("test.sh"));
+    let result = sbatch.merge(builder("gpu", None), MergeStrategy::ErrorOnConflict);
+    assert!(matches!(
+        result,
+        Err(SbatchError::ConflictingOptions(
+            SbatchOption::Partition(_),
+            SbatchOption::Partition(_)
+        ))
+    ));
+    assert_eq!(sbatch.build().unwrap(), "sbatch --partition=batch test.sh");
+}
+
+#[test]
+fn test_merge_error_on_conflict_with_equal_values() {
+    let mut sbatch = builder("batch", Some("test.sh"));
+    sbatch
+        .merge(builder("batch", None), MergeStrategy::ErrorOnConflict)
+        .unwrap();
+    assert_eq!(sbatch.build().unwrap(), "sbatch --partition=batch test.sh");
+}
+
+#[rstest]
+#[case(MergeStrategy::PreferSelf)]
+#[case(MergeStrategy::PreferOther)]
+#[case(MergeStrategy::ErrorOnConflict)]
+fn test_merge_disjoint_options(#[case] strategy: MergeStrategy) {
+    let mut sbatch = builder("batch", None);
+    let mut other = Sbatch::new();
+    other
+        .add_option(SbatchOption::Account("site".to_string()))
+        .unwrap()
+        .set_script("test.sh".to_string())
+        .unwrap();
+    sbatch.merge(other, strategy).unwrap();
+    assert_eq!(
+        sbatch.build().unwrap(),
+        "sbatch --account=site --partition=batch test.sh"
+    );
+}
+
+#[rstest]
+#[case(MergeStrategy::PreferSelf, "sbatch --partition=batch a.sh")]
+#[case(MergeStrategy::PreferOther, "sbatch --partition=batch b.sh")]
+fn test_merge_scripts(#[case] strategy: MergeStrategy, #[case] expected: &str) {
+    let mut sbatch = builder("batch", Some("a.sh"));
+    sbatch
+        .merge(builder("batch", Some("b.sh")), strategy)
+        .unwrap();
+    assert_eq!(sbatch.build().unwrap(), expected);
+}
+
+#[test]
+fn test_merge_conflicting_scripts() {
+    let mut sbatch = builder("batch", Some("a.sh"));
+    let result = sbatch.merge(
+        builder("batch", Some("b.sh")),
+        MergeStrategy::ErrorOnConflict,
+    );
+    assert!(matches!(result, Err(SbatchError::ConflictingScripts(_, _))));
+}
+
+#[rstest]
+#[case(MergeStrategy::PreferSelf)]
+#[case(MergeStrategy::PreferOther)]
+#[case(MergeStrategy::ErrorOnConflict)]
+fn test_merge_het_job_error(#[case] strategy: MergeStrategy) {
+    let mut sbatch = builder("batch", Some("test.sh"));
+    let mut other = builder("gpu", None);
+    other
+        .add_het_group()
+        .add_option(SbatchOption::Account("site".to_string()))
+        .unwrap();
+    assert!(matches!(
+        sbatch.merge(other, strategy),
+        Err(SbatchError::HetJobMerge)
+    ));
+    assert_eq!(sbatch.build().unwrap(), "sbatch --partition=batch test.sh");
+}
+
+#[test]
+fn test_merge_into_het_job_active_component() {
+    let mut sbatch = builder("batch", None);
+    sbatch
+        .add_het_group()
+        .add_option(SbatchOption::Partition("gpu".to_string()))
+        .unwrap();
+    sbatch
+        .merge(
+            builder("debug", Some("test.sh")),
+            MergeStrategy::PreferOther,
+        )
+        .unwrap();
+    assert_eq!(
+        sbatch.build().unwrap(),
+        "sbatch --partition=batch : --partition=debug test.sh"
+    );
+}
@@ -0,0 +1,270 @@
+use rstest::rstest;
+use sbatch_rs::{OrderMode, Sbatch, SbatchError, SbatchOption};
+
+fn sbatch_with_three_options() -> Sbatch {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(SbatchOption::Output("test.out".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::JobName("test".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::Account("acct".to_string()))
+        .unwrap();
+    sbatch
+}
+
+#[test]
+fn test_options_iteration() {
+    let sbatch = sbatch_with_three_options();
+    assert_eq!(
+        sbatch.options().collect::<Vec<_>>(),
+        vec![
+            &SbatchOption::Account("acct".to_string()),
+            &SbatchOption::JobName("test".to_string()),
+            &SbatchOption::Output("test.out".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_options_iteration_insertion_order() {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .with_order(OrderMode::InsertionOrder)
+        .add_option(SbatchOption::Output("test.out".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::JobName("test".to_string()))
+        .unwrap();
+    assert_eq!(
+        sbatch.options().collect::<Vec<_>>(),
+        vec![
+            &SbatchOption::Output("test.out".to_string()),
+            &SbatchOption::JobName("test".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_options_empty() {
+    assert_eq!(Sbatch::new().options().count(), 0);
+}
+
+#[test]
+fn test_get_option() {
+    let sbatch = sbatch_with_three_options();
+    assert_eq!(
+        sbatch.get_option(&SbatchOption::JobName(String::new())),
+        Some(&SbatchOption::JobName("test".to_string()))
+    );
+    assert_eq!(sbatch.get_option(&SbatchOption::Error(String::new())), None);
+}
+
+#[test]
+fn test_remove_option() {
+    let mut sbatch = sbatch_with_three_options();
+    assert_eq!(
+        sbatch.remove_option(&SbatchOption::JobName(String::new())),
+        Some(SbatchOption::JobName("test".to_string()))
+    );
+    assert_eq!(
+        sbatch.remove_option(&SbatchOption::JobName(String::new())),
+        None
+    );
+    assert_eq!(sbatch.options().count(), 2);
+    assert_eq!(
+        sbatch.build().unwrap(),
+        "sbatch --account=acct --output=test.out"
+    );
+}
+
+#[test]
+fn test_has_option() {
+    let sbatch = sbatch_with_three_options();
+    assert!(sbatch.has_option(&SbatchOption::JobName(String::new())));
+    assert!(!sbatch.has_option(&SbatchOption::Error(String::new())));
+}
+
+#[test]
+fn test_option_count() {
+    let mut sbatch = sbatch_with_three_options();
+    assert_eq!(sbatch.option_count(), 3);
+
+    sbatch
+        .add_het_group()
+        .add_option(SbatchOption::NTasks("4".parse().unwrap()))
+        .unwrap();
+    assert_eq!(sbatch.option_count(), 4);
+    assert_eq!(sbatch.len(), 1);
+    assert_eq!(Sbatch::new().option_count(), 0);
+}
+
+#[test]
+fn test_clear_options() {
+    let mut sbatch = sbatch_with_three_options();
+    sbatch
+        .add_het_group()
+        .add_option(SbatchOption::NTasks("4".parse().unwrap()))
+        .unwrap();
+    sbatch.clear_options();
+    assert_eq!(sbatch.option_count(), 0);
+    assert!(matches!(
+        sbatch.build(),
+        Err(SbatchError::NoOptionsOrScript)
+    ));
+
+    // The builder can be reused after clearing
+    sbatch
+        .add_option(SbatchOption::JobName("again".to_string()))
+        .unwrap();
+    assert_eq!(sbatch.build().unwrap(), "sbatch --job-name=again");
+}
+
+#[test]
+fn test_clear_script() {
+    let mut sbatch = Sbatch::new();
+    sbatch.set_script("test.sh".to_string()).unwrap();
+    sbatch.clear_script();
+    assert!(matches!(
+        sbatch.build(),
+        Err(SbatchError::NoOptionsOrScript)
+    ));
+
+    sbatch.set_script_stdin().clear_script();
+    assert!(matches!(
+        sbatch.build(),
+        Err(SbatchError::NoOptionsOrScript)
+    ));
+}
+
+#[rstest]
+#[case(OrderMode::Sorted)]
+#[case(OrderMode::InsertionOrder)]
+fn test_add_option_rejects_duplicate_variant(#[case] order: OrderMode) {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .with_order(order)
+        .add_option(SbatchOption::JobName("first".to_string()))
+        .unwrap();
+    assert!(matches!(
+        sbatch.add_option(SbatchOption::JobName("second".to_string())),
+        Err(SbatchError::DuplicateOption(existing, added))
+            if existing == SbatchOption::JobName("first".to_string())
+                && added == SbatchOption::JobName("second".to_string())
+    ));
+
+    // Adding the same value again is not an error
+    sbatch
+        .add_option(SbatchOption::JobName("first".to_string()))
+        .unwrap();
+    assert_eq!(sbatch.build().unwrap(), "sbatch --job-name=first");
+}
+
+#[test]
+fn test_add_option_rejects_duplicate_raw_flag() {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(SbatchOption::Raw("--x=1".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::Raw("--y=1".to_string()))
+        .unwrap();
+    assert!(matches!(
+        sbatch.add_option(SbatchOption::Raw("--x=2".to_string())),
+        Err(SbatchError::DuplicateOption(existing, added))
+            if existing == SbatchOption::Raw("--x=1".to_string())
+                && added == SbatchOption::Raw("--x=2".to_string())
+    ));
+    assert_eq!(sbatch.build().unwrap(), "sbatch --x=1 --y=1");
+}
+
+#[test]
+fn test_build_rejects_raw_shell_injection() {
+    assert!(matches!(
+        Sbatch::new().add_option(SbatchOption::Raw("; rm -rf ~".to_string())),
+        Err(SbatchError::SbatchOptionError(_))
+    ));
+
+    // A value after the flag is quoted like any other option value
+    let command = Sbatch::new()
+        .add_option(SbatchOption::Raw("--x=$(id); ls".to_string()))
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(command, "sbatch --x='$(id); ls'");
+}
+
+#[test]
+fn test_overwrite_option() {
+    let mut sbatch = sbatch_with_three_options();
+    sbatch
+        .overwrite_option(SbatchOption::JobName("other".to_string()))
+        .unwrap()
+        .overwrite_option(SbatchOption::Error("test.err".to_string()))
+        .unwrap();
+    assert_eq!(
+        sbatch.build().unwrap(),
+        "sbatch --account=acct --error=test.err --job-name=other --output=test.out"
+    );
+    assert!(
+        sbatch
+            .overwrite_option(SbatchOption::JobName("".to_string()))
+            .is_err()
+    );
+}
+
+#[test]
+fn test_repeated_flag_never_emitted_twice() {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(SbatchOption::JobName("first".to_string()))
+        .unwrap();
+    let _ = sbatch.add_option(SbatchOption::JobName("second".to_string()));
+    let command = sbatch.build().unwrap();
+    assert_eq!(command.matches("--job-name").count(), 1);
+
+    // Parsed command lines and scripts keep the last value, as sbatch does
+    let sbatch: Sbatch = "sbatch --job-name=first --job-name=second test.sh"
+        .parse()
+        .unwrap();
+    assert_eq!(sbatch.build().unwrap(), "sbatch --job-name=second test.sh");
+}
+
+#[test]
+fn test_add_option_merges_licenses() {
+    let sbatch = Sbatch::new()
+        .add_option(SbatchOption::Licenses("matlab:1".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::JobName("test".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::Licenses("fluent:2".to_string()))
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(
+        sbatch,
+        "sbatch --job-name=test --licenses=matlab:1,fluent:2"
+    );
+    assert_eq!(sbatch.matches("--licenses").count(), 1);
+}
+
+#[test]
+fn test_add_option_merges_gres() {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .with_order(OrderMode::InsertionOrder)
+        .add_option(SbatchOption::Gres("gpu:2".parse().unwrap()))
+        .unwrap()
+        .add_option(SbatchOption::JobName("test".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::Gres("mps:50".parse().unwrap()))
+        .unwrap();
+    assert_eq!(
+        sbatch.build().unwrap(),
+        "sbatch --gres=gpu:2,mps:50 --job-name=test"
+    );
+
+    // Adding an identical value again does not repeat it
+    sbatch
+        .add_option(SbatchOption::Gres("gpu:2,mps:50".parse().unwrap()))
+        .unwrap();
+    assert_eq!(sbatch.len(), 2);
+}
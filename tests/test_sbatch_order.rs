@@ -0,0 +1,121 @@
+use rstest::rstest;
+use sbatch_rs::{OrderMode, Sbatch, SbatchOption};
+
+#[test]
+fn test_sorted_by_default() {
+    let sbatch = Sbatch::new()
+        .add_option(SbatchOption::Output("test.out".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::JobName("test".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::Account("acct".to_string()))
+        .unwrap()
+        .build();
+    assert_eq!(
+        sbatch.unwrap(),
+        "sbatch --account=acct --job-name=test --output=test.out"
+    );
+}
+
+#[test]
+fn test_insertion_order() {
+    let sbatch = Sbatch::new()
+        .with_order(OrderMode::InsertionOrder)
+        .add_option(SbatchOption::Output("test.out".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::JobName("test".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::Account("acct".to_string()))
+        .unwrap()
+        .set_script("test.sh".to_string())
+        .unwrap()
+        .build();
+    assert_eq!(
+        sbatch.unwrap(),
+        "sbatch --output=test.out --job-name=test --account=acct test.sh"
+    );
+}
+
+#[test]
+fn test_insertion_order_overwrite_replaces_same_variant() {
+    let sbatch = Sbatch::new()
+        .with_order(OrderMode::InsertionOrder)
+        .add_option(SbatchOption::JobName("first".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::Output("test.out".to_string()))
+        .unwrap()
+        .overwrite_option(SbatchOption::JobName("second".to_string()))
+        .unwrap()
+        .build();
+    assert_eq!(
+        sbatch.unwrap(),
+        "sbatch --job-name=second --output=test.out"
+    );
+}
+
+#[test]
+fn test_insertion_order_keeps_distinct_raw_options() {
+    let sbatch = Sbatch::new()
+        .with_order(OrderMode::InsertionOrder)
+        .add_option(SbatchOption::Raw("--foo=1".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::Raw("--bar=2".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::Raw("--foo=1".to_string()))
+        .unwrap()
+        .build();
+    assert_eq!(sbatch.unwrap(), "sbatch --foo=1 --bar=2");
+}
+
+#[test]
+fn test_insertion_order_to_script() {
+    let script = Sbatch::new()
+        .with_order(OrderMode::InsertionOrder)
+        .add_option(SbatchOption::Output("test.out".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::JobName("test".to_string()))
+        .unwrap()
+        .set_script("srun hostname".to_string())
+        .unwrap()
+        .to_script("#!/bin/bash");
+    assert_eq!(
+        script.unwrap(),
+        "#!/bin/bash\n#SBATCH --output=test.out\n#SBATCH --job-name=test\n\nsrun hostname\n"
+    );
+}
+
+#[rstest]
+#[case("a")]
+#[case("zzz")]
+#[case("0")]
+#[case("middle")]
+fn test_sorted_position_is_value_independent(#[case] job_name: &str) {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(SbatchOption::Output("test.out".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::JobName(job_name.to_string()))
+        .unwrap()
+        .add_option(SbatchOption::Account("acct".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::Hold)
+        .unwrap()
+        .add_option(SbatchOption::Raw("--newfangled-flag=x".to_string()))
+        .unwrap();
+    assert_eq!(
+        sbatch.build().unwrap(),
+        format!(
+            "sbatch --account=acct --hold --job-name={job_name} --newfangled-flag=x --output=test.out"
+        )
+    );
+}
+
+#[test]
+fn test_option_ordering() {
+    let job_name = |name: &str| SbatchOption::JobName(name.to_string());
+    assert!(job_name("b") > job_name("a"));
+    assert!(SbatchOption::Account("z".to_string()) < job_name("a"));
+    assert!(SbatchOption::Hold < job_name("a"));
+    assert!(job_name("a") < SbatchOption::Raw("--job-name=a".to_string()));
+    assert!(SbatchOption::Raw("--abc".to_string()) < SbatchOption::Account("a".to_string()));
+}
@@ -0,0 +1,213 @@
+#![cfg(feature = "process")]
+
+use sbatch_rs::{MockSubmitter, Sbatch, SbatchError, SbatchOption};
+use std::cell::RefCell;
+
+#[test]
+fn test_to_command() {
+    let command = Sbatch::new()
+        .add_option(SbatchOption::JobName("my job".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::Output("test.out".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::Contiguous)
+        .unwrap()
+        .set_script("test.sh arg1 arg2".to_string())
+        .unwrap()
+        .to_command()
+        .unwrap();
+
+    assert_eq!(command.get_program(), "sbatch");
+    assert_eq!(
+        command.get_args().collect::<Vec<_>>(),
+        vec![
+            "--contiguous",
+            "--job-name=my job",
+            "--output=test.out",
+            "test.sh",
+            "arg1",
+            "arg2"
+        ]
+    );
+}
+
+#[test]
+fn test_to_command_wrap_is_unquoted() {
+    let command = Sbatch::new()
+        .add_option(SbatchOption::Wrap("echo \"hello\"".to_string()))
+        .unwrap()
+        .to_command()
+        .unwrap();
+
+    assert_eq!(
+        command.get_args().collect::<Vec<_>>(),
+        vec!["--wrap=echo \"hello\""]
+    );
+}
+
+#[test]
+fn test_to_command_keeps_quoted_script_arguments() {
+    let args: Vec<String> = ["test.sh", "a b", "it's"]
+        .iter()
+        .map(|arg| arg.to_string())
+        .collect();
+    let command = Sbatch::from_args(&args).unwrap().to_command().unwrap();
+
+    assert_eq!(
+        command.get_args().collect::<Vec<_>>(),
+        vec!["test.sh", "a b", "it's"]
+    );
+}
+
+#[test]
+fn test_to_command_rejects_script_body() {
+    let sbatch =
+        Sbatch::from_script("#SBATCH --job-name=test\nsrun hostname\necho done\n").unwrap();
+    assert!(matches!(
+        sbatch.to_command(),
+        Err(SbatchError::MultiLineScript)
+    ));
+}
+
+#[test]
+fn test_to_command_error_empty() {
+    assert!(matches!(
+        Sbatch::new().to_command(),
+        Err(SbatchError::NoOptionsOrScript)
+    ));
+}
+
+#[test]
+fn test_to_command_error_all_options_removed() {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(SbatchOption::JobName("test".to_string()))
+        .unwrap();
+    sbatch.remove_option(&SbatchOption::JobName(String::new()));
+    assert!(matches!(
+        sbatch.to_command(),
+        Err(SbatchError::NoOptionsOrScript)
+    ));
+    assert!(matches!(
+        sbatch.build(),
+        Err(SbatchError::NoOptionsOrScript)
+    ));
+}
+
+// Submitter returning canned output and recording the command it was given
+struct FixedSubmitter {
+    stdout: &'static str,
+    command: RefCell<Vec<String>>,
+}
+
+impl FixedSubmitter {
+    fn new(stdout: &'static str) -> Self {
+        FixedSubmitter {
+            stdout,
+            command: RefCell::new(Vec::new()),
+        }
+    }
+}
+
+impl MockSubmitter for FixedSubmitter {
+    fn submit(&self, command: &[String]) -> Result<String, SbatchError> {
+        *self.command.borrow_mut() = command.to_vec();
+        Ok(self.stdout.to_string())
+    }
+}
+
+#[test]
+fn test_submit_with_submitter() {
+    let submitter = FixedSubmitter::new("Submitted batch job 42\n");
+    let job_id = Sbatch::new()
+        .add_option(SbatchOption::JobName("test".to_string()))
+        .unwrap()
+        .set_script("test.sh arg1".to_string())
+        .unwrap()
+        .submit_with_submitter(&submitter);
+
+    assert_eq!(job_id.unwrap(), 42);
+    assert_eq!(
+        *submitter.command.borrow(),
+        vec!["sbatch", "--job-name=test", "test.sh", "arg1"]
+    );
+}
+
+#[test]
+fn test_submit_with_submitter_parsable() {
+    let submitter = FixedSubmitter::new("42;cluster\n");
+    let job_id = Sbatch::new()
+        .add_option(SbatchOption::Parsable)
+        .unwrap()
+        .set_script("test.sh".to_string())
+        .unwrap()
+        .submit_with_submitter(&submitter);
+    assert_eq!(job_id.unwrap(), 42);
+}
+
+#[test]
+fn test_submit_with_submitter_bad_output() {
+    let submitter = FixedSubmitter::new("sbatch: error: invalid partition\n");
+    let result = Sbatch::new()
+        .set_script("test.sh".to_string())
+        .unwrap()
+        .submit_with_submitter(&submitter);
+    assert!(matches!(result, Err(SbatchError::SbatchExecutionError(_))));
+}
+
+#[test]
+fn test_submit_with_submitter_error_empty() {
+    let submitter = FixedSubmitter::new("Submitted batch job 42\n");
+    assert!(matches!(
+        Sbatch::new().submit_with_submitter(&submitter),
+        Err(SbatchError::NoOptionsOrScript)
+    ));
+    assert!(submitter.command.borrow().is_empty());
+}
+
+#[test]
+fn test_to_command_het_job() {
+    let command = Sbatch::new()
+        .add_option(SbatchOption::NTasks("1".parse().unwrap()))
+        .unwrap()
+        .add_het_group()
+        .add_option(SbatchOption::NTasks("4".parse().unwrap()))
+        .unwrap()
+        .set_script("test.sh".to_string())
+        .unwrap()
+        .to_command()
+        .unwrap();
+    assert_eq!(
+        command.get_args().collect::<Vec<_>>(),
+        vec!["--ntasks=1", ":", "--ntasks=4", "test.sh"]
+    );
+}
+
+#[test]
+fn test_submit_with_submitter_validates_first() {
+    let submitter = FixedSubmitter::new("Submitted batch job 42\n");
+    let result = Sbatch::new()
+        .add_option(SbatchOption::Mem("4G".parse().unwrap()))
+        .unwrap()
+        .add_option(SbatchOption::MemPerCPU("1G".parse().unwrap()))
+        .unwrap()
+        .set_script("test.sh".to_string())
+        .unwrap()
+        .submit_with_submitter(&submitter);
+    assert!(matches!(result, Err(SbatchError::ConflictingOptions(..))));
+    assert!(submitter.command.borrow().is_empty());
+}
+
+#[test]
+fn test_to_command_rejects_conflicts() {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(SbatchOption::Exclusive(None))
+        .unwrap()
+        .add_option(SbatchOption::Oversubscribe)
+        .unwrap();
+    assert!(matches!(
+        sbatch.to_command(),
+        Err(SbatchError::ConflictingOptions(..))
+    ));
+}
@@ -0,0 +1,64 @@
+use rstest::rstest;
+use sbatch_rs::{OptionKind, OptionSpec, Sbatch, SbatchOption};
+
+#[test]
+fn test_to_spec_job_name_and_contiguous() {
+    let specs = Sbatch::new()
+        .option(SbatchOption::JobName("test job".to_string()))
+        .unwrap()
+        .option(SbatchOption::Contiguous)
+        .unwrap()
+        .to_spec();
+    assert_eq!(
+        specs,
+        vec![
+            OptionSpec {
+                name: "contiguous",
+                value: None,
+                kind: OptionKind::Flag,
+            },
+            OptionSpec {
+                name: "job-name",
+                value: Some("test job".to_string()),
+                kind: OptionKind::String,
+            },
+        ]
+    );
+}
+
+#[rstest]
+#[case(SbatchOption::SocketsPerNode(2.try_into().unwrap()), "sockets-per-node", Some("2"), OptionKind::Numeric)]
+#[case(SbatchOption::Nice(Some(-5)), "nice", Some("-5"), OptionKind::Numeric)]
+#[case(SbatchOption::Nice(None), "nice", None, OptionKind::Flag)]
+#[case(SbatchOption::Exclusive(Some("user".to_string())), "exclusive", Some("user"), OptionKind::String)]
+#[case(SbatchOption::Time("90".parse().unwrap()), "time", Some("0-01:30:00"), OptionKind::String)]
+#[case(SbatchOption::Raw("--newfangled-flag=x".to_string()), "", Some("--newfangled-flag=x"), OptionKind::String)]
+fn test_option_to_spec(
+    #[case] option: SbatchOption,
+    #[case] name: &str,
+    #[case] value: Option<&str>,
+    #[case] kind: OptionKind,
+) {
+    let spec = option.to_spec();
+    assert_eq!(spec.name, name);
+    assert_eq!(spec.value.as_deref(), value);
+    assert_eq!(spec.kind, kind);
+}
+
+#[test]
+fn test_to_spec_het_groups() {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(SbatchOption::Partition("cpu".to_string()))
+        .unwrap()
+        .add_het_group()
+        .add_option(SbatchOption::Partition("gpu".to_string()))
+        .unwrap();
+    let values: Vec<_> = sbatch
+        .to_spec()
+        .into_iter()
+        .map(|spec| spec.value.unwrap())
+        .collect();
+    assert_eq!(values, vec!["cpu", "gpu"]);
+    assert!(Sbatch::new().to_spec().is_empty());
+}
@@ -1,5 +1,8 @@
+use rstest::rstest;
 use sbatch_rs::Sbatch;
+use sbatch_rs::SbatchError;
 use sbatch_rs::SbatchOption;
+use sbatch_rs::SbatchOptionError;
 
 #[test]
 fn test_set_script() {
@@ -44,3 +47,206 @@ fn test_add_option() {
         "sbatch --error=test.err --job-name=test --output=test.out test.sh"
     );
 }
+
+#[test]
+fn test_set_wrap_commands() {
+    let sbatch = Sbatch::new()
+        .add_option(SbatchOption::JobName("test".to_string()))
+        .unwrap()
+        .set_wrap_commands(&["echo start", "srun ./a.out"])
+        .unwrap()
+        .build();
+    assert_eq!(
+        sbatch.unwrap(),
+        "sbatch --job-name=test --wrap='echo start; srun ./a.out'"
+    );
+}
+
+#[test]
+fn test_set_wrap_commands_with_separator() {
+    let sbatch = Sbatch::new()
+        .set_wrap_commands_with(&["echo start", "", "srun ./a.out"], " && ")
+        .unwrap()
+        .build();
+    assert_eq!(
+        sbatch.unwrap(),
+        "sbatch --wrap='echo start && srun ./a.out'"
+    );
+}
+
+#[test]
+fn test_set_wrap_commands_replaces_wrap() {
+    let sbatch = Sbatch::new()
+        .set_wrap_commands(&["echo first"])
+        .unwrap()
+        .set_wrap_commands(&["echo second"])
+        .unwrap()
+        .build();
+    assert_eq!(sbatch.unwrap(), "sbatch --wrap='echo second'");
+}
+
+#[test]
+fn test_set_wrap_commands_quoting() {
+    let sbatch = Sbatch::new()
+        .set_wrap_commands(&["echo \"hello world\"", "echo done"])
+        .unwrap()
+        .build();
+    assert_eq!(
+        sbatch.unwrap(),
+        r#"sbatch --wrap='echo "hello world"; echo done'"#
+    );
+}
+
+#[rstest]
+#[case(&[])]
+#[case(&["", "  "])]
+fn test_set_wrap_commands_error_empty(#[case] cmds: &[&str]) {
+    assert!(matches!(
+        Sbatch::new().set_wrap_commands(cmds),
+        Err(SbatchError::SbatchOptionError(
+            SbatchOptionError::EmptyString
+        ))
+    ));
+}
+
+#[test]
+fn test_set_wrap_commands_error_with_script() {
+    let mut sbatch = Sbatch::new();
+    sbatch.set_script("test.sh".to_string()).unwrap();
+    assert!(matches!(
+        sbatch.set_wrap_commands(&["echo hello"]),
+        Err(SbatchError::WrapAndScript)
+    ));
+    assert_eq!(sbatch.build().unwrap(), "sbatch test.sh");
+}
+
+#[test]
+fn test_set_script_stdin() {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(SbatchOption::JobName("test".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::Output("test.out".to_string()))
+        .unwrap()
+        .set_script_stdin();
+    assert!(sbatch.reads_script_from_stdin());
+    assert_eq!(
+        sbatch.build().unwrap(),
+        "sbatch --job-name=test --output=test.out"
+    );
+}
+
+#[test]
+fn test_set_script_stdin_replaces_script() {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(SbatchOption::JobName("test".to_string()))
+        .unwrap()
+        .set_script("test.sh".to_string())
+        .unwrap()
+        .set_script_stdin();
+    assert_eq!(sbatch.build().unwrap(), "sbatch --job-name=test");
+
+    // Setting a script again leaves stdin mode
+    sbatch.set_script("test.sh".to_string()).unwrap();
+    assert!(!sbatch.reads_script_from_stdin());
+    assert_eq!(sbatch.build().unwrap(), "sbatch --job-name=test test.sh");
+}
+
+#[test]
+fn test_set_script_stdin_requires_options() {
+    assert!(matches!(
+        Sbatch::new().set_script_stdin().build(),
+        Err(SbatchError::NoOptionsOrScript)
+    ));
+}
+
+#[test]
+fn test_set_script_stdin_with_wrap() {
+    let mut sbatch = Sbatch::new();
+    sbatch.set_script_stdin();
+    assert!(matches!(
+        sbatch.set_wrap("echo hello".to_string()),
+        Err(SbatchError::WrapAndScript)
+    ));
+}
+
+// Helper function to undo backslash line continuations and collapse whitespace
+fn join_continuations(command: &str) -> String {
+    command
+        .replace(" \\\n", " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[test]
+fn test_build_multiline() {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(SbatchOption::Wrap("echo hello".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::JobName("test".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::Exclusive(None))
+        .unwrap();
+    let multiline = sbatch.build_multiline().unwrap();
+
+    assert_eq!(
+        multiline,
+        "sbatch \\\n    --exclusive \\\n    --job-name=test \\\n    --wrap='echo hello'"
+    );
+    assert_eq!(join_continuations(&multiline), sbatch.build().unwrap());
+}
+
+#[test]
+fn test_build_multiline_matches_build() {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(SbatchOption::Mem("4G".parse().unwrap()))
+        .unwrap()
+        .add_het_group()
+        .add_option(SbatchOption::NTasks("4".parse().unwrap()))
+        .unwrap()
+        .add_option(SbatchOption::Comment("two words".to_string()))
+        .unwrap()
+        .set_script("test.sh arg".to_string())
+        .unwrap();
+    let multiline = sbatch.build_multiline().unwrap();
+
+    assert!(
+        multiline
+            .lines()
+            .rev()
+            .skip(1)
+            .all(|line| line.ends_with(" \\"))
+    );
+    assert_eq!(multiline.lines().last(), Some("    test.sh arg"));
+    assert_eq!(join_continuations(&multiline), sbatch.build().unwrap());
+}
+
+#[test]
+fn test_build_multiline_parses_back() {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(SbatchOption::JobName("test".to_string()))
+        .unwrap()
+        .add_het_group()
+        .add_option(SbatchOption::Comment("two words".to_string()))
+        .unwrap()
+        .set_script("test.sh arg".to_string())
+        .unwrap();
+    let multiline = sbatch.build_multiline().unwrap();
+
+    let parsed: Sbatch = multiline.parse().unwrap();
+    assert_eq!(parsed, sbatch);
+    assert_eq!(parsed.build().unwrap(), sbatch.build().unwrap());
+}
+
+#[test]
+fn test_build_multiline_errors() {
+    assert!(matches!(
+        Sbatch::new().build_multiline(),
+        Err(SbatchError::NoOptionsOrScript)
+    ));
+}
@@ -0,0 +1,110 @@
+use sbatch_rs::{Sbatch, SbatchError, SbatchOption};
+
+#[test]
+fn test_to_script() {
+    let script = Sbatch::new()
+        .add_option(SbatchOption::JobName("test".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::Output("test.out".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::Error("test.err".to_string()))
+        .unwrap()
+        .set_script("srun hostname".to_string())
+        .unwrap()
+        .to_script("#!/bin/bash")
+        .unwrap();
+
+    assert_eq!(
+        script,
+        "#!/bin/bash\n\
+         #SBATCH --error=test.err\n\
+         #SBATCH --job-name=test\n\
+         #SBATCH --output=test.out\n\
+         \n\
+         srun hostname\n"
+    );
+}
+
+#[test]
+fn test_to_script_no_options() {
+    let script = Sbatch::new()
+        .set_script("srun hostname".to_string())
+        .unwrap()
+        .to_script("#!/bin/bash")
+        .unwrap();
+
+    assert_eq!(script, "#!/bin/bash\n\nsrun hostname\n");
+}
+
+#[test]
+fn test_to_script_no_script() {
+    let script = Sbatch::new()
+        .add_option(SbatchOption::Wrap("hostname".to_string()))
+        .unwrap()
+        .to_script("#!/bin/sh")
+        .unwrap();
+
+    assert_eq!(script, "#!/bin/sh\n#SBATCH --wrap=hostname\n");
+}
+
+#[test]
+fn test_to_script_error_empty() {
+    let script = Sbatch::new().to_script("#!/bin/bash");
+    assert!(matches!(script, Err(SbatchError::NoOptionsOrScript)));
+}
+
+#[test]
+fn test_to_directives() {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(SbatchOption::Output("test.out".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::JobName("test".to_string()))
+        .unwrap()
+        .set_script("test.sh".to_string())
+        .unwrap();
+    assert_eq!(
+        sbatch.to_directives(),
+        vec!["#SBATCH --job-name=test", "#SBATCH --output=test.out"]
+    );
+}
+
+#[test]
+fn test_to_directives_with_prefix() {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(SbatchOption::JobName("test".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::NTasks("4".parse().unwrap()))
+        .unwrap()
+        .add_het_group()
+        .add_option(SbatchOption::NTasks("1".parse().unwrap()))
+        .unwrap();
+    assert_eq!(
+        sbatch.to_directives_with_prefix("#CUSTOM"),
+        vec![
+            "#CUSTOM --job-name=test",
+            "#CUSTOM --ntasks=4",
+            "#CUSTOM hetjob",
+            "#CUSTOM --ntasks=1"
+        ]
+    );
+}
+
+#[test]
+fn test_to_directives_empty() {
+    assert!(Sbatch::new().to_directives().is_empty());
+}
+
+#[test]
+fn test_to_script_error_all_options_removed() {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(SbatchOption::JobName("test".to_string()))
+        .unwrap();
+    sbatch.remove_option(&SbatchOption::JobName(String::new()));
+    assert!(matches!(
+        sbatch.to_script("#!/bin/bash"),
+        Err(SbatchError::NoOptionsOrScript)
+    ));
+}
@@ -0,0 +1,332 @@
+use rstest::rstest;
+use sbatch_rs::{OpenMode, Sbatch, SbatchError, SbatchOption, SbatchOptionError};
+
+#[rstest]
+#[case(SbatchOption::Mem("4G".parse().unwrap()), SbatchOption::MemPerCPU("1G".parse().unwrap()))]
+#[case(SbatchOption::Mem("0".parse().unwrap()), SbatchOption::MemPerCPU("1G".parse().unwrap()))]
+#[case(SbatchOption::Mem("4G".parse().unwrap()), SbatchOption::MemPerGPU("1G".parse().unwrap()))]
+#[case(SbatchOption::MemPerCPU("1G".parse().unwrap()), SbatchOption::MemPerGPU("1G".parse().unwrap()))]
+#[case(SbatchOption::Exclusive(None), SbatchOption::Oversubscribe)]
+#[case(SbatchOption::Exclusive(Some("user".to_string())), SbatchOption::Oversubscribe)]
+fn test_conflicting_options(#[case] first: SbatchOption, #[case] second: SbatchOption) {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(second.clone())
+        .unwrap()
+        .add_option(first.clone())
+        .unwrap();
+
+    // Verify that the conflict is reported in table order
+    match sbatch.validate() {
+        Err(SbatchError::ConflictingOptions(a, b)) => {
+            assert_eq!(a, first);
+            assert_eq!(b, second);
+        }
+        other => panic!("Expected ConflictingOptions, got {other:?}"),
+    }
+    assert!(matches!(
+        sbatch.build(),
+        Err(SbatchError::ConflictingOptions(_, _))
+    ));
+}
+
+#[test]
+fn test_wrap_and_script() {
+    // `add_option` does not know about the script, so the conflict is caught by `validate`
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .set_script("test.sh".to_string())
+        .unwrap()
+        .add_option(SbatchOption::Wrap("echo hello".to_string()))
+        .unwrap();
+    assert!(matches!(sbatch.validate(), Err(SbatchError::WrapAndScript)));
+    assert!(matches!(sbatch.build(), Err(SbatchError::WrapAndScript)));
+}
+
+#[test]
+fn test_valid_combination() {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(SbatchOption::Mem("4G".parse().unwrap()))
+        .unwrap()
+        .add_option(SbatchOption::Exclusive(None))
+        .unwrap()
+        .set_script("test.sh".to_string())
+        .unwrap();
+    assert!(sbatch.validate().is_ok());
+    assert_eq!(
+        sbatch.build().unwrap(),
+        "sbatch --exclusive --mem=4G test.sh"
+    );
+}
+
+#[test]
+fn test_conflict_error_message() {
+    let error =
+        SbatchError::ConflictingOptions(SbatchOption::Exclusive(None), SbatchOption::Oversubscribe);
+    assert_eq!(
+        error.to_string(),
+        "Conflicting options: --exclusive and --oversubscribe"
+    );
+}
+
+#[test]
+fn test_set_wrap_after_script() {
+    let mut sbatch = Sbatch::new();
+    sbatch.set_script("test.sh".to_string()).unwrap();
+    assert!(matches!(
+        sbatch.set_wrap("echo hello".to_string()),
+        Err(SbatchError::WrapAndScript)
+    ));
+    assert_eq!(sbatch.build().unwrap(), "sbatch test.sh");
+}
+
+#[test]
+fn test_set_script_after_wrap() {
+    let mut sbatch = Sbatch::new();
+    sbatch.set_wrap("echo hello".to_string()).unwrap();
+    assert!(matches!(
+        sbatch.set_script("test.sh".to_string()),
+        Err(SbatchError::WrapAndScript)
+    ));
+    assert_eq!(sbatch.build().unwrap(), "sbatch --wrap='echo hello'");
+}
+
+#[test]
+fn test_set_wrap_replaces_existing() {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .set_wrap("echo first".to_string())
+        .unwrap()
+        .set_wrap("echo second".to_string())
+        .unwrap();
+    assert_eq!(sbatch.build().unwrap(), "sbatch --wrap='echo second'");
+}
+
+#[test]
+fn test_check_time_limit() {
+    let limit = "2-00:00:00".parse().unwrap();
+
+    let mut sbatch = Sbatch::new();
+    assert!(sbatch.check_time_limit(&limit).is_ok());
+
+    sbatch
+        .add_option(SbatchOption::Time("1-00:00:00".parse().unwrap()))
+        .unwrap();
+    assert!(sbatch.check_time_limit(&limit).is_ok());
+
+    sbatch.remove_option(&SbatchOption::Time("0".parse().unwrap()));
+    sbatch
+        .add_option(SbatchOption::Time("3-00:00:00".parse().unwrap()))
+        .unwrap();
+    assert_eq!(
+        sbatch.check_time_limit(&limit).unwrap_err().to_string(),
+        "Time limit 3-00:00:00 exceeds the maximum of 2-00:00:00"
+    );
+}
+
+#[rstest]
+#[case("0-1000", 1001, true)]
+#[case("0-1001", 1001, false)]
+#[case("1,5,2000", 1001, false)]
+#[case("0-2000:1000%1", 2001, true)]
+#[case("0-2000:1000%1", 2000, false)]
+fn test_check_array_size(#[case] array: &str, #[case] max_array_size: u32, #[case] ok: bool) {
+    let mut sbatch = Sbatch::new();
+    assert!(sbatch.check_array_size(max_array_size).is_ok());
+
+    sbatch
+        .add_option(SbatchOption::Array(array.parse().unwrap()))
+        .unwrap();
+    match sbatch.check_array_size(max_array_size) {
+        Ok(()) => assert!(ok),
+        Err(SbatchError::ArraySizeExceeded {
+            index,
+            max_array_size: max,
+        }) => {
+            assert!(!ok);
+            assert!(index >= max);
+            assert_eq!(max, max_array_size);
+        }
+        Err(error) => panic!("Unexpected error: {error}"),
+    }
+}
+
+#[rstest]
+#[case(&["slurm-%A.out"], Some(0))]
+#[case(&["logs/%x.out"], Some(0))]
+#[case(&["%%a.out"], Some(0))]
+#[case(&["slurm-%A_%a.out", "slurm-%A.err"], Some(1))]
+#[case(&["slurm-%A_%a.out"], None)]
+#[case(&["slurm-%A_%4a.out", "slurm-%A_%a.err"], None)]
+#[case(&[], None)]
+fn test_check_array_output(#[case] patterns: &[&str], #[case] flagged: Option<usize>) {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(SbatchOption::Array("1-10".parse().unwrap()))
+        .unwrap()
+        .add_option(SbatchOption::OpenMode(OpenMode::Append))
+        .unwrap();
+    let options: Vec<SbatchOption> = patterns
+        .iter()
+        .enumerate()
+        .map(|(index, pattern)| match index {
+            0 => SbatchOption::Output(pattern.to_string()),
+            _ => SbatchOption::Error(pattern.to_string()),
+        })
+        .collect();
+    for option in &options {
+        sbatch.add_option(option.clone()).unwrap();
+    }
+
+    match (sbatch.check_array_output(), flagged) {
+        (Ok(()), None) => {}
+        (Err(SbatchError::SharedArrayOutput(option)), Some(index)) => {
+            assert_eq!(option, options[index])
+        }
+        (result, _) => panic!("Unexpected result: {result:?}"),
+    }
+}
+
+#[rstest]
+#[case(Some("1-10"), Some(OpenMode::Truncate))]
+#[case(Some("1-10"), None)]
+#[case(None, Some(OpenMode::Append))]
+fn test_check_array_output_not_applicable(
+    #[case] array: Option<&str>,
+    #[case] open_mode: Option<OpenMode>,
+) {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(SbatchOption::Output("shared.out".to_string()))
+        .unwrap();
+    if let Some(array) = array {
+        sbatch
+            .add_option(SbatchOption::Array(array.parse().unwrap()))
+            .unwrap();
+    }
+    if let Some(open_mode) = open_mode {
+        sbatch
+            .add_option(SbatchOption::OpenMode(open_mode))
+            .unwrap();
+    }
+    assert!(sbatch.check_array_output().is_ok());
+}
+
+#[rstest]
+#[case("2:8:2", SbatchOption::SocketsPerNode("4".parse().unwrap()))]
+#[case("2:8", SbatchOption::CoresPerSocket("16".parse().unwrap()))]
+#[case("*:8:2", SbatchOption::ThreadsPerCore("1".to_string()))]
+fn test_topology_conflict(#[case] extra_node_info: &str, #[case] option: SbatchOption) {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(SbatchOption::ExtraNodeInfo(extra_node_info.to_string()))
+        .unwrap()
+        .add_option(option.clone())
+        .unwrap();
+    match sbatch.validate() {
+        Err(SbatchError::ConflictingOptions(a, b)) => {
+            assert_eq!(a, SbatchOption::ExtraNodeInfo(extra_node_info.to_string()));
+            assert_eq!(b, option);
+        }
+        other => panic!("Expected ConflictingOptions, got {other:?}"),
+    }
+}
+
+#[rstest]
+#[case("2:8:2", SbatchOption::SocketsPerNode("2".parse().unwrap()))]
+#[case("2:8:2", SbatchOption::CoresPerSocket("8".parse().unwrap()))]
+#[case("*:8", SbatchOption::SocketsPerNode("4".parse().unwrap()))]
+#[case("2", SbatchOption::CoresPerSocket("16".parse().unwrap()))]
+#[case("2:8:2", SbatchOption::ThreadsPerCore("${THREADS}".to_string()))]
+fn test_topology_consistent(#[case] extra_node_info: &str, #[case] option: SbatchOption) {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(SbatchOption::ExtraNodeInfo(extra_node_info.to_string()))
+        .unwrap()
+        .add_option(option)
+        .unwrap();
+    assert!(sbatch.validate().is_ok());
+}
+
+#[rstest]
+#[case("10", "2", "4", false)]
+#[case("8", "2", "4", true)]
+#[case("6", "2", "4", true)]
+#[case("10", "2-4", "4", true)]
+#[case("10", "$NODES", "4", true)]
+fn test_task_layout(
+    #[case] ntasks: &str,
+    #[case] nodes: &str,
+    #[case] per_node: &str,
+    #[case] ok: bool,
+) {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(SbatchOption::NTasks(ntasks.parse().unwrap()))
+        .unwrap()
+        .add_option(SbatchOption::Nodes(nodes.to_string()))
+        .unwrap()
+        .add_option(SbatchOption::NTasksPerNode(per_node.parse().unwrap()))
+        .unwrap();
+    match sbatch.validate() {
+        Ok(()) => assert!(ok),
+        Err(SbatchError::InconsistentTaskLayout(description)) => {
+            assert!(!ok);
+            assert_eq!(
+                description,
+                format!(
+                    "--ntasks={ntasks} does not fit on --nodes={nodes} with --ntasks-per-node={per_node}"
+                )
+            );
+        }
+        Err(error) => panic!("unexpected error: {error}"),
+    }
+}
+
+#[test]
+fn test_task_layout_partial() {
+    // Without --ntasks-per-node, Slurm spreads the tasks however it needs to
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(SbatchOption::NTasks("10".parse().unwrap()))
+        .unwrap()
+        .add_option(SbatchOption::Nodes("2".to_string()))
+        .unwrap();
+    assert!(sbatch.validate().is_ok());
+}
+
+#[test]
+fn test_validate_all_reports_every_error() {
+    let sbatch: Sbatch = [
+        SbatchOption::JobName(" test".to_string()),
+        SbatchOption::Partition("gpu ".to_string()),
+        SbatchOption::Account("site".to_string()),
+    ]
+    .into_iter()
+    .collect();
+
+    let errors = sbatch.validate_all().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(errors.iter().all(|error| matches!(
+        error,
+        SbatchError::SbatchOptionError(SbatchOptionError::LeadingOrTrailingSpaces)
+    )));
+
+    // `validate` reports the first of them
+    assert!(matches!(
+        sbatch.validate(),
+        Err(SbatchError::SbatchOptionError(
+            SbatchOptionError::LeadingOrTrailingSpaces
+        ))
+    ));
+}
+
+#[test]
+fn test_validate_all_ok() {
+    let mut sbatch = Sbatch::new();
+    sbatch
+        .add_option(SbatchOption::JobName("test".to_string()))
+        .unwrap();
+    assert!(sbatch.validate_all().is_ok());
+}
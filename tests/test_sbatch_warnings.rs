@@ -0,0 +1,72 @@
+use sbatch_rs::{Sbatch, SbatchOption, Warning};
+
+#[test]
+fn test_warnings_from_args_workdir() {
+    let args: Vec<String> = ["--workdir", "/scratch", "-J", "test", "test.sh"]
+        .iter()
+        .map(|arg| arg.to_string())
+        .collect();
+    let sbatch = Sbatch::from_args(&args).unwrap();
+    assert_eq!(
+        sbatch.build().unwrap(),
+        "sbatch --chdir=/scratch --job-name=test test.sh"
+    );
+    assert_eq!(
+        sbatch.warnings(),
+        vec![Warning {
+            flag: "--workdir".to_string(),
+            replacement: "--chdir".to_string(),
+            message: "--workdir is deprecated and was removed from Slurm in favor of --chdir"
+                .to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_warnings_from_script() {
+    let contents =
+        "#!/bin/bash\n#SBATCH --workdir=/scratch\n#SBATCH --tasks-per-node=4\n\nsrun hostname\n";
+    let sbatch = Sbatch::from_script(contents).unwrap();
+    assert_eq!(
+        sbatch.to_script("#!/bin/bash").unwrap(),
+        "#!/bin/bash\n#SBATCH --chdir=/scratch\n#SBATCH --ntasks-per-node=4\n\nsrun hostname\n"
+    );
+
+    let warnings = sbatch.warnings();
+    let flags: Vec<_> = warnings
+        .iter()
+        .map(|warning| (warning.flag.as_str(), warning.replacement.as_str()))
+        .collect();
+    assert_eq!(
+        flags,
+        vec![
+            ("--workdir", "--chdir"),
+            ("--tasks-per-node", "--ntasks-per-node")
+        ]
+    );
+}
+
+#[test]
+fn test_warnings_raw_option() {
+    let sbatch = Sbatch::new()
+        .option(SbatchOption::Raw("--workdir=/scratch".to_string()))
+        .unwrap();
+    let warnings = sbatch.warnings();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].replacement, "--chdir");
+}
+
+#[test]
+fn test_warnings_none_for_current_flags() {
+    let sbatch: Sbatch = "sbatch --chdir=/scratch -D /tmp test.sh".parse().unwrap();
+    assert!(sbatch.warnings().is_empty());
+    assert!(Sbatch::new().warnings().is_empty());
+}
+
+#[test]
+fn test_warnings_cleared_with_options() {
+    let mut sbatch: Sbatch = "sbatch --workdir=/scratch test.sh".parse().unwrap();
+    assert_eq!(sbatch.warnings().len(), 1);
+    sbatch.clear_options();
+    assert!(sbatch.warnings().is_empty());
+}
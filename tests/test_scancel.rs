@@ -0,0 +1,92 @@
+use rstest::rstest;
+use sbatch_rs::{Scancel, ScancelError};
+
+#[test]
+fn test_build_job_ids() {
+    let command = Scancel::new()
+        .add_job_id("1234")
+        .unwrap()
+        .add_job_id("1235_7")
+        .unwrap()
+        .add_job_id("$JOB_ID")
+        .unwrap()
+        .build();
+    assert_eq!(command.unwrap(), "scancel 1234 1235_7 $JOB_ID");
+}
+
+#[test]
+fn test_build_filters() {
+    let command = Scancel::new()
+        .set_user("alice")
+        .unwrap()
+        .set_partition("gpu")
+        .unwrap()
+        .set_job_name("test")
+        .unwrap()
+        .set_state("R")
+        .unwrap()
+        .build();
+    assert_eq!(
+        command.unwrap(),
+        "scancel --name=test --partition=gpu --state=R --user=alice"
+    );
+}
+
+#[test]
+fn test_build_signal_with_job_id() {
+    let command = Scancel::new()
+        .set_signal("USR1")
+        .unwrap()
+        .add_job_id("${JOB_ID}")
+        .unwrap()
+        .build();
+    assert_eq!(command.unwrap(), "scancel --signal=USR1 ${JOB_ID}");
+}
+
+#[test]
+fn test_build_no_jobs_or_filters() {
+    assert!(matches!(
+        Scancel::new().build(),
+        Err(ScancelError::NoJobsOrFilters)
+    ));
+    assert!(matches!(
+        Scancel::new().set_signal("KILL").unwrap().build(),
+        Err(ScancelError::NoJobsOrFilters)
+    ));
+}
+
+#[rstest]
+#[case("pending")]
+#[case("PD")]
+#[case("Running")]
+#[case("s")]
+fn test_set_state(#[case] state: &str) {
+    assert!(Scancel::new().set_state(state).is_ok());
+}
+
+#[rstest]
+#[case("")]
+#[case("completed")]
+#[case("P")]
+fn test_set_state_error(#[case] state: &str) {
+    assert!(matches!(
+        Scancel::new().set_state(state),
+        Err(ScancelError::InvalidState(_))
+    ));
+}
+
+#[test]
+fn test_invalid_values() {
+    assert!(matches!(
+        Scancel::new().add_job_id(""),
+        Err(ScancelError::EmptyValue)
+    ));
+    assert!(matches!(
+        Scancel::new().set_job_name("my job"),
+        Err(ScancelError::InvalidValue(_))
+    ));
+    assert!(matches!(
+        Scancel::new().set_user(" alice"),
+        Err(ScancelError::InvalidValue(_))
+    ));
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "serde")]
+
+use sbatch_rs::{Dependency, DependencyType, Sbatch, SbatchOption};
+
+#[test]
+fn test_sbatch_option_round_trip() {
+    let options = vec![
+        SbatchOption::JobName("test".to_string()),
+        SbatchOption::Exclusive(None),
+        SbatchOption::Exclusive(Some("user".to_string())),
+        SbatchOption::Contiguous,
+        SbatchOption::Wrap("echo hello".to_string()),
+    ];
+
+    let json = serde_json::to_string(&options).unwrap();
+    let parsed: Vec<SbatchOption> = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, options);
+}
+
+#[test]
+fn test_dependency_round_trip() {
+    let dependency = Dependency::Or(vec![
+        DependencyType::AfterOk("123".to_string()),
+        DependencyType::AfterTimeDelay("456".to_string(), "10".to_string()),
+        DependencyType::Singleton,
+    ]);
+
+    let json = serde_json::to_string(&dependency).unwrap();
+    let parsed: Dependency = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, dependency);
+}
+
+#[test]
+fn test_sbatch_round_trip() {
+    let sbatch = Sbatch::new()
+        .add_option(SbatchOption::JobName("test".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::Output("test.out".to_string()))
+        .unwrap()
+        .set_script("test.sh".to_string())
+        .unwrap()
+        .clone();
+
+    let json = serde_json::to_string(&sbatch).unwrap();
+    let parsed: Sbatch = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.build().unwrap(), sbatch.build().unwrap());
+}
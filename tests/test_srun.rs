@@ -0,0 +1,96 @@
+use rstest::rstest;
+use sbatch_rs::{SbatchOption, Srun, SrunError};
+
+#[test]
+fn test_build_shared_options() {
+    let command = Srun::new()
+        .add_option(SbatchOption::Partition("gpu".to_string()))
+        .unwrap()
+        .add_option(SbatchOption::NTasks("4".parse().unwrap()))
+        .unwrap()
+        .add_option(SbatchOption::Mem("4G".parse().unwrap()))
+        .unwrap()
+        .set_command("python train.py".to_string())
+        .unwrap()
+        .build();
+    assert_eq!(
+        command.unwrap(),
+        "srun --mem=4G --ntasks=4 --partition=gpu python train.py"
+    );
+}
+
+#[test]
+fn test_build_srun_flags() {
+    let command = Srun::new()
+        .add_option(SbatchOption::NTasks("1".parse().unwrap()))
+        .unwrap()
+        .set_label(true)
+        .set_pty(true)
+        .set_command("bash".to_string())
+        .unwrap()
+        .build();
+    assert_eq!(command.unwrap(), "srun --ntasks=1 --label --pty bash");
+
+    let command = Srun::new()
+        .set_pty(true)
+        .set_pty(false)
+        .set_command("hostname".to_string())
+        .unwrap()
+        .build();
+    assert_eq!(command.unwrap(), "srun hostname");
+}
+
+#[rstest]
+#[case(SbatchOption::Array("0-3".parse().unwrap()))]
+#[case(SbatchOption::Batch("test".to_string()))]
+#[case(SbatchOption::Begin("now+1hour".parse().unwrap()))]
+#[case(SbatchOption::Parsable)]
+#[case(SbatchOption::Wait)]
+#[case(SbatchOption::Wrap("hostname".to_string()))]
+fn test_add_option_sbatch_only(#[case] option: SbatchOption) {
+    assert!(matches!(
+        Srun::new().add_option(option.clone()),
+        Err(SrunError::UnsupportedOption(unsupported)) if unsupported == option
+    ));
+}
+
+#[test]
+fn test_add_option_invalid() {
+    assert!(matches!(
+        Srun::new().add_option(SbatchOption::JobName("".to_string())),
+        Err(SrunError::SbatchOptionError(_))
+    ));
+}
+
+#[test]
+fn test_add_option_duplicate() {
+    let mut srun = Srun::new();
+    srun.add_option(SbatchOption::NTasks("4".parse().unwrap()))
+        .unwrap()
+        .add_option(SbatchOption::NTasks("4".parse().unwrap()))
+        .unwrap();
+    assert!(matches!(
+        srun.add_option(SbatchOption::NTasks("8".parse().unwrap())),
+        Err(SrunError::DuplicateOption(_, _))
+    ));
+    srun.set_command("hostname".to_string()).unwrap();
+    assert_eq!(srun.build().unwrap(), "srun --ntasks=4 hostname");
+}
+
+#[test]
+fn test_build_without_command() {
+    let mut srun = Srun::new();
+    srun.add_option(SbatchOption::NTasks("4".parse().unwrap()))
+        .unwrap();
+    assert!(matches!(srun.build(), Err(SrunError::NoCommand)));
+}
+
+#[rstest]
+#[case("")]
+#[case("   ")]
+fn test_set_command_empty(#[case] command: &str) {
+    assert!(matches!(
+        Srun::new().set_command(command.to_string()),
+        Err(SrunError::CommandEmpty)
+    ));
+}
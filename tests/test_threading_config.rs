@@ -0,0 +1,85 @@
+use rstest::rstest;
+use sbatch_rs::{Hint, Sbatch, SbatchOption, ThreadingConfig, ThreadingConfigError};
+use std::num::NonZeroU32;
+
+fn n(value: u32) -> NonZeroU32 {
+    NonZeroU32::new(value).unwrap()
+}
+
+#[test]
+fn test_coherent_config() {
+    let options = ThreadingConfig::new()
+        .set_hint(Hint::NoMultithread)
+        .set_threads_per_core(n(1))
+        .set_ntasks_per_core(n(1))
+        .clone()
+        .into_options()
+        .unwrap();
+
+    let mut sbatch = Sbatch::new();
+    for option in options {
+        sbatch.add_option(option).unwrap();
+    }
+    sbatch.set_script("test.sh".to_string()).unwrap();
+
+    assert_eq!(
+        sbatch.build().unwrap(),
+        "sbatch --hint=nomultithread --ntasks-per-core=1 --threads-per-core=1 test.sh"
+    );
+}
+
+#[test]
+fn test_empty_config() {
+    assert_eq!(ThreadingConfig::new().into_options().unwrap(), vec![]);
+}
+
+#[test]
+fn test_hint_display() {
+    assert_eq!(Hint::ComputeBound.to_string(), "compute_bound");
+    assert_eq!(Hint::MemoryBound.to_string(), "memory_bound");
+    assert_eq!(Hint::Multithread.to_string(), "multithread");
+    assert_eq!(Hint::NoMultithread.to_string(), "nomultithread");
+}
+
+#[rstest]
+#[case(Some(Hint::NoMultithread), Some(2), None)]
+#[case(Some(Hint::NoMultithread), None, Some(2))]
+#[case(Some(Hint::Multithread), Some(1), None)]
+fn test_contradictory_config(
+    #[case] hint: Option<Hint>,
+    #[case] threads_per_core: Option<u32>,
+    #[case] ntasks_per_core: Option<u32>,
+) {
+    let mut config = ThreadingConfig::new();
+    if let Some(hint) = hint {
+        config.set_hint(hint);
+    }
+    if let Some(threads) = threads_per_core {
+        config.set_threads_per_core(n(threads));
+    }
+    if let Some(ntasks) = ntasks_per_core {
+        config.set_ntasks_per_core(n(ntasks));
+    }
+
+    assert!(matches!(
+        config.into_options(),
+        Err(ThreadingConfigError::Contradictory(_))
+    ));
+}
+
+#[test]
+fn test_into_options_order() {
+    let options = ThreadingConfig::new()
+        .set_ntasks_per_core(n(2))
+        .set_hint(Hint::ComputeBound)
+        .clone()
+        .into_options()
+        .unwrap();
+    assert_eq!(
+        options,
+        vec![
+            SbatchOption::Hint("compute_bound".to_string()),
+            SbatchOption::NTasksPerCore("2".to_string()),
+        ]
+    );
+}